@@ -65,6 +65,17 @@ pub enum CaseType {
     UpperCamelCase,
 }
 
+impl CaseType {
+    /// Returns the name of the lint that warns about names not following this case.
+    pub fn lint_name(&self) -> &'static str {
+        match self {
+            CaseType::LowerSnakeCase => allow::NON_SNAKE_CASE,
+            CaseType::UpperSnakeCase => allow::NON_UPPER_CASE_GLOBAL,
+            CaseType::UpperCamelCase => allow::NON_CAMEL_CASE_TYPES,
+        }
+    }
+}
+
 impl fmt::Display for CaseType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let repr = match self {
@@ -66,7 +66,7 @@
     traits::FnTrait,
     AliasTy, CallableDefId, CallableSig, Canonical, CanonicalVarKinds, Cast, ClosureId,
     GenericArgData, Interner, ParamKind, QuantifiedWhereClause, Scalar, Substitution,
-    TraitEnvironment, TraitRefExt, Ty, TyBuilder, TyDefId, TyExt, TyKind, WhereClause,
+    TraitEnvironment, TraitRefExt, Ty, TyBuilder, TyDefId, TyExt, TyKind, TypeWalk, WhereClause,
 };
 use itertools::Itertools;
 use nameres::diagnostics::DefDiagnosticKind;
@@ -1853,6 +1853,12 @@ pub fn items_with_supertraits(self, db: &dyn HirDatabase) -> Vec<AssocItem> {
         traits.iter().flat_map(|tr| Trait::from(*tr).items(db)).collect()
     }
 
+    /// The supertraits of this trait, transitively and each only once, not including the trait
+    /// itself.
+    pub fn all_supertraits(self, db: &dyn HirDatabase) -> Vec<Trait> {
+        all_super_traits(db.upcast(), self.into()).into_iter().skip(1).map(Trait::from).collect()
+    }
+
     pub fn is_auto(self, db: &dyn HirDatabase) -> bool {
         db.trait_data(self.id).is_auto
     }
@@ -3154,6 +3160,17 @@ fn go(ty: &Ty) -> bool {
         }
     }
 
+    pub fn type_params(&self, db: &dyn HirDatabase) -> Vec<TypeParam> {
+        let mut res = Vec::new();
+        self.ty.walk(&mut |ty| {
+            if let TyKind::Placeholder(p) = ty.kind(Interner) {
+                let id = TypeParamId::from_unchecked(hir_ty::from_placeholder_idx(db, *p));
+                res.push(TypeParam { id });
+            }
+        });
+        res
+    }
+
     pub fn fields(&self, db: &dyn HirDatabase) -> Vec<(Field, Type)> {
         let (variant_id, substs) = match self.ty.kind(Interner) {
             TyKind::Adt(hir_ty::AdtId(AdtId::StructId(s)), substs) => ((*s).into(), substs),
@@ -15,4 +15,13 @@ pub struct AssistConfig {
     pub insert_use: InsertUseConfig,
     pub prefer_no_std: bool,
     pub assist_emit_must_use: bool,
+    pub placeholder_macro: PlaceholderMacro,
+    pub max_width: usize,
+}
+
+/// The macro used in place of code that is yet to be written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlaceholderMacro {
+    Todo,
+    Unimplemented,
 }
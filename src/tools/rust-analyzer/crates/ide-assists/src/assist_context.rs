@@ -1,5 +1,7 @@
 //! See [`AssistContext`].
 
+use std::cmp::Reverse;
+
 use hir::Semantics;
 use ide_db::base_db::{FileId, FileRange};
 use ide_db::{label::Label, RootDatabase};
@@ -94,6 +96,10 @@ pub(crate) fn file_id(&self) -> FileId {
         self.frange.file_id
     }
 
+    pub(crate) fn source_file(&self) -> &SourceFile {
+        &self.source_file
+    }
+
     pub(crate) fn has_empty_selection(&self) -> bool {
         self.trimmed_range.is_empty()
     }
@@ -131,7 +137,7 @@ pub(crate) fn covering_element(&self) -> SyntaxElement {
 pub(crate) struct Assists {
     file: FileId,
     resolve: AssistResolveStrategy,
-    buf: Vec<Assist>,
+    buf: Vec<(Assist, i32)>,
     allowed: Option<Vec<AssistKind>>,
 }
 
@@ -145,9 +151,12 @@ pub(crate) fn new(ctx: &AssistContext<'_>, resolve: AssistResolveStrategy) -> As
         }
     }
 
+    /// Returns the collected assists, most specific target first. Assists whose targets have the
+    /// same length are ordered by decreasing priority, and otherwise kept in the order they were
+    /// added in.
     pub(crate) fn finish(mut self) -> Vec<Assist> {
-        self.buf.sort_by_key(|assist| assist.target.len());
-        self.buf
+        self.buf.sort_by_key(|(assist, priority)| (assist.target.len(), Reverse(*priority)));
+        self.buf.into_iter().map(|(assist, _)| assist).collect()
     }
 
     pub(crate) fn add(
@@ -158,7 +167,7 @@ pub(crate) fn add(
         f: impl FnOnce(&mut SourceChangeBuilder),
     ) -> Option<()> {
         let mut f = Some(f);
-        self.add_impl(None, id, label.into(), target, &mut |it| f.take().unwrap()(it))
+        self.add_impl(None, 0, id, label.into(), target, &mut |it| f.take().unwrap()(it))
     }
 
     pub(crate) fn add_group(
@@ -168,14 +177,30 @@ pub(crate) fn add_group(
         label: impl Into<String>,
         target: TextRange,
         f: impl FnOnce(&mut SourceChangeBuilder),
+    ) -> Option<()> {
+        self.add_group_with_priority(group, 0, id, label, target, f)
+    }
+
+    /// Like [`Assists::add_group`], but ranks the assist among the others for the same target,
+    /// the ones with a higher `priority` coming first.
+    pub(crate) fn add_group_with_priority(
+        &mut self,
+        group: &GroupLabel,
+        priority: i32,
+        id: AssistId,
+        label: impl Into<String>,
+        target: TextRange,
+        f: impl FnOnce(&mut SourceChangeBuilder),
     ) -> Option<()> {
         let mut f = Some(f);
-        self.add_impl(Some(group), id, label.into(), target, &mut |it| f.take().unwrap()(it))
+        let label = label.into();
+        self.add_impl(Some(group), priority, id, label, target, &mut |it| f.take().unwrap()(it))
     }
 
     fn add_impl(
         &mut self,
         group: Option<&GroupLabel>,
+        priority: i32,
         id: AssistId,
         label: String,
         target: TextRange,
@@ -197,7 +222,8 @@ fn add_impl(
 
         let label = Label::new(label);
         let group = group.cloned();
-        self.buf.push(Assist { id, label, group, target, source_change, trigger_signature_help });
+        let assist = Assist { id, label, group, target, source_change, trigger_signature_help };
+        self.buf.push((assist, priority));
         Some(())
     }
 
@@ -0,0 +1,280 @@
+use ide_db::syntax_helpers::node_ext::for_each_tail_expr;
+use syntax::{
+    ast::{self, AstNode},
+    SyntaxKind,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: add_explicit_return
+//
+// Turns a tail expression of a function into an explicit `return`.
+//
+// ```
+// fn foo(x: u32) -> u32 {
+//     x $0+ 1
+// }
+// ```
+// ->
+// ```
+// fn foo(x: u32) -> u32 {
+//     return x + 1;
+// }
+// ```
+pub(crate) fn add_explicit_return(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let func = enclosing_fn(ctx)?;
+    let body = ast::Expr::BlockExpr(func.body()?);
+
+    let mut tail = None;
+    for_each_tail_expr(&body, &mut |expr| {
+        if !matches!(expr, ast::Expr::BreakExpr(_) | ast::Expr::ReturnExpr(_))
+            && expr.syntax().text_range().contains_range(ctx.selection_trimmed())
+        {
+            tail = Some(expr.clone());
+        }
+    });
+    let tail = tail?;
+
+    let target = tail.syntax().text_range();
+    acc.add(
+        AssistId("add_explicit_return", AssistKind::RefactorRewrite),
+        "Add explicit `return`",
+        target,
+        |builder| {
+            // `=> return x;,` isn't valid syntax.
+            let in_match_arm =
+                tail.syntax().parent().map_or(false, |it| ast::MatchArm::can_cast(it.kind()));
+            let semicolon = if in_match_arm { "" } else { ";" };
+            builder.replace(target, format!("return {tail}{semicolon}"))
+        },
+    )
+}
+
+// Assist: remove_redundant_return
+//
+// Turns a `return` at the end of a function into a tail expression.
+//
+// ```
+// fn foo(x: u32) -> u32 {
+//     if x == 0 {
+//         return 1;
+//     }
+//     $0return x + 1;
+// }
+// ```
+// ->
+// ```
+// fn foo(x: u32) -> u32 {
+//     if x == 0 {
+//         return 1;
+//     }
+//     x + 1
+// }
+// ```
+pub(crate) fn remove_redundant_return(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let return_expr = ctx.find_node_at_offset::<ast::ReturnExpr>()?;
+    let func = enclosing_fn(ctx)?;
+    let body = func.body()?;
+    if !return_expr.syntax().ancestors().any(|it| it == *body.syntax()) {
+        return None;
+    }
+
+    // The last statement of the body, as in `return x;`.
+    let stmt = return_expr.syntax().parent().and_then(ast::ExprStmt::cast);
+    let stmt = stmt.filter(|stmt| {
+        let stmt_list = body.stmt_list();
+        stmt_list.as_ref().map_or(false, |it| {
+            it.tail_expr().is_none()
+                && it.statements().last().map(|it| it.syntax().clone())
+                    == Some(stmt.syntax().clone())
+        })
+    });
+    if stmt.is_none() && !is_return_in_tail_position(&body, &return_expr) {
+        cov_mark::hit!(remove_redundant_return_not_in_tail);
+        return None;
+    }
+
+    let target = stmt
+        .as_ref()
+        .map_or_else(|| return_expr.syntax().text_range(), |it| it.syntax().text_range());
+    acc.add(
+        AssistId("remove_redundant_return", AssistKind::RefactorRewrite),
+        "Remove redundant `return`",
+        target,
+        |builder| match return_expr.expr() {
+            Some(expr) => builder.replace(target, expr.to_string()),
+            // A bare `return` in a match arm still needs an expression.
+            None if return_expr
+                .syntax()
+                .parent()
+                .map_or(false, |it| ast::MatchArm::can_cast(it.kind())) =>
+            {
+                builder.replace(target, "()")
+            }
+            None => {
+                // Drop the indentation of the removed statement along with it.
+                let ws = stmt.as_ref().and_then(|it| it.syntax().prev_sibling_or_token());
+                let ws = ws.filter(|it| it.kind() == SyntaxKind::WHITESPACE);
+                builder.delete(ws.map_or(target, |it| it.text_range().cover(target)))
+            }
+        },
+    )
+}
+
+/// Finds the function the cursor is in, if it isn't in a closure within it.
+fn enclosing_fn(ctx: &AssistContext<'_>) -> Option<ast::Fn> {
+    ctx.token_at_offset()
+        .next()?
+        .parent_ancestors()
+        .find(|it| ast::Fn::can_cast(it.kind()) || ast::ClosureExpr::can_cast(it.kind()))
+        .and_then(ast::Fn::cast)
+}
+
+/// Checks whether `return_expr` is one of the tail expressions of `body`, without being in an
+/// `if` lacking an `else`, whose branch has to evaluate to `()`.
+fn is_return_in_tail_position(body: &ast::BlockExpr, return_expr: &ast::ReturnExpr) -> bool {
+    let mut is_tail = false;
+    for_each_tail_expr(&ast::Expr::BlockExpr(body.clone()), &mut |expr| {
+        is_tail |= expr.syntax() == return_expr.syntax();
+    });
+    is_tail
+        && !return_expr
+            .syntax()
+            .ancestors()
+            .take_while(|it| it != body.syntax())
+            .filter_map(ast::IfExpr::cast)
+            .any(|it| it.else_branch().is_none())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn add_return_in_match_arm_and_block() {
+        check_assist(
+            add_explicit_return,
+            r#"
+fn foo(x: Option<u32>) -> u32 {
+    match x {
+        Some(x) => x$0,
+        None => 0,
+    }
+}
+"#,
+            r#"
+fn foo(x: Option<u32>) -> u32 {
+    match x {
+        Some(x) => return x,
+        None => 0,
+    }
+}
+"#,
+        );
+        check_assist(
+            add_explicit_return,
+            r#"
+fn foo(b: bool) -> u32 {
+    if b {
+        1
+    } else {
+        $02
+    }
+}
+"#,
+            r#"
+fn foo(b: bool) -> u32 {
+    if b {
+        1
+    } else {
+        return 2;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn add_return_not_applicable() {
+        check_assist_not_applicable(
+            add_explicit_return,
+            r#"
+fn foo() -> u32 {
+    let x$0 = 1;
+    x
+}
+"#,
+        );
+        check_assist_not_applicable(add_explicit_return, "fn foo() -> u32 { return $01 }");
+        check_assist_not_applicable(add_explicit_return, "fn foo() { let f = || 1$0; }");
+    }
+
+    #[test]
+    fn remove_return_in_tail_positions() {
+        check_assist(
+            remove_redundant_return,
+            r#"
+fn foo(x: Option<u32>) -> u32 {
+    match x {
+        Some(x) => $0return x,
+        None => 0,
+    }
+}
+"#,
+            r#"
+fn foo(x: Option<u32>) -> u32 {
+    match x {
+        Some(x) => x,
+        None => 0,
+    }
+}
+"#,
+        );
+        check_assist(
+            remove_redundant_return,
+            r#"
+fn foo() {
+    bar();
+    $0return;
+}
+"#,
+            r#"
+fn foo() {
+    bar();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn remove_return_not_in_tail() {
+        cov_mark::check!(remove_redundant_return_not_in_tail);
+        check_assist_not_applicable(
+            remove_redundant_return,
+            r#"
+fn foo(x: u32) -> u32 {
+    if x == 0 {
+        $0return 1;
+    }
+    x
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn remove_return_not_applicable_in_if_without_else() {
+        check_assist_not_applicable(
+            remove_redundant_return,
+            r#"
+fn foo(x: u32) -> u32 {
+    if x == 0 {
+        $0return 1
+    }
+}
+"#,
+        );
+    }
+}
@@ -170,6 +170,39 @@ fn new() -> Self {
         );
     }
 
+    #[test]
+    fn add_explicit_type_uses_type_pinned_down_by_later_usage() {
+        check_assist(
+            add_explicit_type,
+            r#"
+struct Vec<T> { t: T }
+impl<T> Vec<T> {
+    fn new() -> Self {
+        panic!()
+    }
+    fn push(&mut self, t: T) {}
+}
+fn f() {
+    let mut v$0 = Vec::new();
+    v.push(&1u8);
+}
+"#,
+            r#"
+struct Vec<T> { t: T }
+impl<T> Vec<T> {
+    fn new() -> Self {
+        panic!()
+    }
+    fn push(&mut self, t: T) {}
+}
+fn f() {
+    let mut v: Vec<&u8> = Vec::new();
+    v.push(&1u8);
+}
+"#,
+        );
+    }
+
     #[test]
     fn add_explicit_type_not_applicable_closure_expr() {
         check_assist_not_applicable(add_explicit_type, r#"fn f() { let a$0 = || {}; }"#);
@@ -1,6 +1,9 @@
 use syntax::ast::{self, AstNode, HasGenericParams, HasName};
 
-use crate::{AssistContext, AssistId, AssistKind, Assists};
+use crate::{
+    handlers::introduce_named_lifetime::generate_unique_lifetime_param_name, AssistContext,
+    AssistId, AssistKind, Assists,
+};
 
 // Assist: add_lifetime_to_type
 //
@@ -26,38 +29,40 @@ pub(crate) fn add_lifetime_to_type(acc: &mut Assists, ctx: &AssistContext<'_>) -
     }
 
     let node = ctx.find_node_at_offset::<ast::Adt>()?;
-    let has_lifetime = node
-        .generic_param_list()
-        .map_or(false, |gen_list| gen_list.lifetime_params().next().is_some());
-
-    if has_lifetime {
-        return None;
-    }
-
     let ref_types = fetch_borrowed_types(&node)?;
+    let lifetime = generate_unique_lifetime_param_name(node.generic_param_list())?;
     let target = node.syntax().text_range();
 
     acc.add(
         AssistId("add_lifetime_to_type", AssistKind::Generate),
-        "Add lifetime",
+        format!("Add lifetime `{lifetime}`"),
         target,
         |builder| {
             match node.generic_param_list() {
-                Some(gen_param) => {
-                    if let Some(left_angle) = gen_param.l_angle_token() {
-                        builder.insert(left_angle.text_range().end(), "'a, ");
+                Some(gen_param) => match gen_param.lifetime_params().last() {
+                    // Lifetimes have to come before the other parameters.
+                    Some(last_lifetime) => {
+                        builder.insert(
+                            last_lifetime.syntax().text_range().end(),
+                            format!(", {lifetime}"),
+                        );
                     }
-                }
+                    None => {
+                        if let Some(left_angle) = gen_param.l_angle_token() {
+                            builder.insert(left_angle.text_range().end(), format!("{lifetime}, "));
+                        }
+                    }
+                },
                 None => {
                     if let Some(name) = node.name() {
-                        builder.insert(name.syntax().text_range().end(), "<'a>");
+                        builder.insert(name.syntax().text_range().end(), format!("<{lifetime}>"));
                     }
                 }
             }
 
             for ref_type in ref_types {
                 if let Some(amp_token) = ref_type.amp_token() {
-                    builder.insert(amp_token.text_range().end(), "'a ");
+                    builder.insert(amp_token.text_range().end(), format!("{lifetime} "));
                 }
             }
         },
@@ -226,4 +231,18 @@ fn add_lifetime_to_union() {
 
         check_assist_not_applicable(add_lifetime_to_type, r#"struct Foo<'a> { a: &'a $0i32 }"#);
     }
+
+    #[test]
+    fn add_fresh_lifetime_after_existing_ones() {
+        check_assist(
+            add_lifetime_to_type,
+            r#"struct Foo<'a, T> { a: &'a T, b: &$0str, c: Vec<&'a T> }"#,
+            r#"struct Foo<'a, 'b, T> { a: &'a T, b: &'b str, c: Vec<&'a T> }"#,
+        );
+        check_assist(
+            add_lifetime_to_type,
+            r#"enum Foo<'b> { A(&'b u8), B(&$0u8, &u16) }"#,
+            r#"enum Foo<'b, 'a> { A(&'b u8), B(&'a u8, &'a u16) }"#,
+        );
+    }
 }
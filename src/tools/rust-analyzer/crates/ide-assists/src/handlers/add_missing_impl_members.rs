@@ -1,16 +1,23 @@
 use hir::HasSource;
 use ide_db::{
-    syntax_helpers::insert_whitespace_into_node::insert_ws_into, traits::resolve_target_trait,
+    helpers::mod_path_to_ast, syntax_helpers::insert_whitespace_into_node::insert_ws_into,
+    traits::resolve_target_trait,
+};
+use syntax::{
+    ast::{
+        self, edit::IndentLevel, edit_in_place, make, AstNode, HasGenericParams, HasName,
+        PathSegmentKind,
+    },
+    ted,
 };
-use syntax::ast::{self, make, AstNode};
 
 use crate::{
     assist_context::{AssistContext, Assists},
     utils::{
-        add_trait_assoc_items_to_impl, filter_assoc_items, gen_trait_fn_body, render_snippet,
-        Cursor, DefaultMethods,
+        add_trait_assoc_items_to_impl, filter_assoc_items, gen_trait_fn_body, placeholder_expr,
+        render_snippet, Cursor, DefaultMethods,
     },
-    AssistId, AssistKind,
+    AssistConfig, AssistId, AssistKind,
 };
 
 // Assist: add_impl_missing_members
@@ -37,7 +44,7 @@
 // }
 //
 // impl Trait<u32> for () {
-//     $0type X;
+//     type X = ${0:()};
 //
 //     fn foo(&self) -> u32 {
 //         todo!()
@@ -108,13 +115,6 @@ fn add_missing_impl_members_inner(
     let _p = profile::span("add_missing_impl_members_inner");
     let impl_def = ctx.find_node_at_offset::<ast::Impl>()?;
 
-    if ctx.token_at_offset().all(|t| {
-        t.parent_ancestors()
-            .any(|s| ast::BlockExpr::can_cast(s.kind()) || ast::ParamList::can_cast(s.kind()))
-    }) {
-        return None;
-    }
-
     let target_scope = ctx.sema.scope(impl_def.syntax())?;
     let trait_ = resolve_target_trait(&ctx.sema, &impl_def)?;
 
@@ -128,6 +128,11 @@ fn add_missing_impl_members_inner(
         return None;
     }
 
+    let supertrait_impls = match mode {
+        DefaultMethods::No => missing_supertrait_impls(ctx, &impl_def, trait_),
+        DefaultMethods::Only => Vec::new(),
+    };
+
     let target = impl_def.syntax().text_range();
     acc.add(AssistId(assist_id, AssistKind::QuickFix), label, target, |builder| {
         let missing_items = missing_items
@@ -140,43 +145,198 @@ fn add_missing_impl_members_inner(
                 }
                 it.clone_for_update()
             })
+            .collect::<Vec<_>>();
+        let self_types: Vec<_> = missing_items
+            .iter()
+            .filter_map(|it| match it {
+                ast::AssocItem::Fn(func) if func.body().is_none() => Some(func),
+                _ => None,
+            })
+            .flat_map(self_types_in_signature)
             .collect();
         let (new_impl_def, first_new_item) = add_trait_assoc_items_to_impl(
             &ctx.sema,
+            ctx.config,
             missing_items,
             trait_,
             impl_def.clone(),
             target_scope,
         );
+        let indent = IndentLevel::from_node(impl_def.syntax());
+        let supertrait_impls: String = supertrait_impls
+            .iter()
+            .map(|it| {
+                edit_in_place::Indent::indent(it, indent);
+                format!("\n\n{indent}{it}")
+            })
+            .collect();
         match ctx.config.snippet_cap {
-            None => builder.replace(target, new_impl_def.to_string()),
+            None => {
+                replace_stubbed_self_types(ctx.config, &impl_def, &self_types);
+                builder.replace(target, format!("{new_impl_def}{supertrait_impls}"))
+            }
             Some(cap) => {
                 let mut cursor = Cursor::Before(first_new_item.syntax());
                 let placeholder;
                 if let DefaultMethods::No = mode {
-                    if let ast::AssocItem::Fn(func) = &first_new_item {
-                        if try_gen_trait_body(ctx, func, &trait_, &impl_def).is_none() {
-                            if let Some(m) =
-                                func.syntax().descendants().find_map(ast::MacroCall::cast)
-                            {
-                                if m.syntax().text() == "todo!()" {
-                                    placeholder = m;
-                                    cursor = Cursor::Replace(placeholder.syntax());
-                                }
-                            }
+                    placeholder = match &first_new_item {
+                        ast::AssocItem::Fn(func)
+                            if try_gen_trait_body(ctx, func, &trait_, &impl_def).is_none() =>
+                        {
+                            let placeholder_text = placeholder_expr(ctx.config).to_string();
+                            func.syntax()
+                                .descendants()
+                                .find_map(ast::MacroCall::cast)
+                                .filter(|m| m.syntax().text() == placeholder_text.as_str())
+                                .map(|m| m.syntax().clone())
+                        }
+                        ast::AssocItem::TypeAlias(type_alias) => {
+                            type_alias.ty().map(|ty| ty.syntax().clone())
                         }
+                        ast::AssocItem::Const(const_) => {
+                            const_.body().map(|body| body.syntax().clone())
+                        }
+                        _ => None,
+                    };
+                    if let Some(placeholder) = &placeholder {
+                        cursor = Cursor::Replace(placeholder);
                     }
                 }
-                builder.replace_snippet(
-                    cap,
-                    target,
-                    render_snippet(cap, new_impl_def.syntax(), cursor),
-                )
+                replace_stubbed_self_types(ctx.config, &impl_def, &self_types);
+                let snippet = render_snippet(cap, new_impl_def.syntax(), cursor);
+                builder.replace_snippet(cap, target, format!("{snippet}{supertrait_impls}"))
             }
         };
     })
 }
 
+/// Impls, with their required items stubbed out, of the supertraits of `trait_` that the type
+/// `impl_def` is for doesn't implement yet. Their items can't be defined in `impl_def` itself.
+///
+/// Each supertrait is only visited once, so traits that are reached through several paths of a
+/// diamond-shaped hierarchy only get one impl.
+fn missing_supertrait_impls(
+    ctx: &AssistContext<'_>,
+    impl_def: &ast::Impl,
+    trait_: hir::Trait,
+) -> Vec<ast::Impl> {
+    let db = ctx.db();
+    let (Some(self_ty), Some(scope)) = (impl_def.self_ty(), ctx.sema.scope(impl_def.syntax()))
+    else {
+        return Vec::new();
+    };
+    let Some(hir_self_ty) = ctx.sema.resolve_type(&self_ty) else { return Vec::new() };
+    let generic_params = impl_def.generic_param_list().map(|it| it.to_string()).unwrap_or_default();
+    let where_clause = impl_def.where_clause().map(|it| format!(" {it}")).unwrap_or_default();
+
+    trait_
+        .all_supertraits(db)
+        .into_iter()
+        // The arguments of generic supertraits would have to be worked out from the bounds.
+        .filter(|supertrait| supertrait.type_or_const_param_count(db, false) == 0)
+        .filter(|&supertrait| !hir_self_ty.impls_trait(db, supertrait, &[]))
+        .filter_map(|supertrait| {
+            let path = scope.module().find_use_path(
+                db,
+                hir::ModuleDef::Trait(supertrait),
+                ctx.config.prefer_no_std,
+            )?;
+            let unsafe_ = if supertrait.is_unsafe(db) { "unsafe " } else { "" };
+            let text = format!(
+                "{unsafe_}impl{generic_params} {} for {self_ty}{where_clause} {{}}",
+                mod_path_to_ast(&path)
+            );
+            let supertrait_impl = ast::SourceFile::parse(&text)
+                .tree()
+                .syntax()
+                .descendants()
+                .find_map(ast::Impl::cast)?
+                .clone_subtree();
+            let items = filter_assoc_items(&ctx.sema, &supertrait.items(db), DefaultMethods::No)
+                .into_iter()
+                .map(|it| it.clone_for_update())
+                .collect::<Vec<_>>();
+            if items.is_empty() {
+                return Some(supertrait_impl.clone_for_update());
+            }
+            let (supertrait_impl, _) = add_trait_assoc_items_to_impl(
+                &ctx.sema,
+                ctx.config,
+                items,
+                supertrait,
+                supertrait_impl,
+                ctx.sema.scope(impl_def.syntax())?,
+            );
+            Some(supertrait_impl)
+        })
+        .collect()
+}
+
+/// The `Self` types written in the parameters and return type of `func`,
+/// including `Self::Assoc` projections.
+fn self_types_in_signature(func: &ast::Fn) -> Vec<ast::PathType> {
+    let is_self = |path: &ast::Path| {
+        path.qualifier().is_none()
+            && path.segment().map_or(false, |it| it.kind() == Some(PathSegmentKind::SelfTypeKw))
+    };
+    func.param_list()
+        .into_iter()
+        .flat_map(|it| it.params())
+        .filter_map(|it| it.ty())
+        .chain(func.ret_type().and_then(|it| it.ty()))
+        .flat_map(|ty| {
+            ty.syntax().descendants().filter_map(ast::PathType::cast).collect::<Vec<_>>()
+        })
+        .filter(|path_ty| {
+            path_ty.path().map_or(false, |path| match path.qualifier() {
+                Some(qualifier) => is_self(&qualifier),
+                None => is_self(&path),
+            })
+        })
+        .collect()
+}
+
+/// Spells out the `Self` types in the signatures of the functions that were
+/// stubbed out as the type the impl is for, and `Self::Assoc` as the type the
+/// impl binds it to, if it does. Functions with a generated body keep `Self`.
+fn replace_stubbed_self_types(
+    config: &AssistConfig,
+    impl_def: &ast::Impl,
+    self_types: &[ast::PathType],
+) {
+    let Some(self_ty) = impl_def.self_ty() else { return };
+    let placeholder_text = placeholder_expr(config).to_string();
+    let is_stub = |func: ast::Fn| {
+        func.body().and_then(|it| it.stmt_list()).map_or(false, |it| {
+            it.statements().next().is_none()
+                && it
+                    .tail_expr()
+                    .map_or(false, |it| it.syntax().text() == placeholder_text.as_str())
+        })
+    };
+    let bound_type = |name: &ast::NameRef| {
+        impl_def.assoc_item_list()?.assoc_items().find_map(|item| match item {
+            ast::AssocItem::TypeAlias(it) if it.name()?.text() == name.text() => it.ty(),
+            _ => None,
+        })
+    };
+
+    for path_ty in self_types {
+        let in_stub = path_ty.syntax().ancestors().find_map(ast::Fn::cast).map_or(false, is_stub);
+        if !in_stub {
+            continue;
+        }
+        let Some(path) = path_ty.path() else { continue };
+        let replacement = match path.qualifier() {
+            None => Some(self_ty.clone()),
+            Some(_) => path.segment().and_then(|it| it.name_ref()).and_then(|it| bound_type(&it)),
+        };
+        if let Some(ty) = replacement {
+            ted::replace(path_ty.syntax(), ty.clone_for_update().syntax());
+        }
+    }
+}
+
 fn try_gen_trait_body(
     ctx: &AssistContext<'_>,
     func: &ast::Fn,
@@ -191,10 +351,58 @@ fn try_gen_trait_body(
 
 #[cfg(test)]
 mod tests {
-    use crate::tests::{check_assist, check_assist_not_applicable};
+    use hir::Semantics;
+    use ide_db::base_db::FileRange;
+    use stdx::format_to;
+    use syntax::TextRange;
+
+    use crate::{
+        tests::{
+            check_assist, check_assist_not_applicable, check_assist_with_config, with_single_file,
+            TEST_CONFIG,
+        },
+        AssistResolveStrategy, PlaceholderMacro,
+    };
 
     use super::*;
 
+    #[test]
+    fn benchmark_big_trait() {
+        if test_utils::skip_slow_tests() {
+            return;
+        }
+
+        let mut fixture = String::from("trait Big {\n");
+        for i in 0..500 {
+            format_to!(fixture, "    fn method_{i}(&self);\n");
+        }
+        fixture.push_str("}\nstruct S;\nimpl Big for S {$0}\n");
+        let (offset, text) = test_utils::extract_offset(&fixture);
+        let (db, file_id) = with_single_file(&text);
+        let frange = FileRange { file_id, range: TextRange::empty(offset) };
+        let config = TEST_CONFIG;
+        let ctx = AssistContext::new(Semantics::new(&db), &config, frange);
+        let generated_methods = || {
+            let mut acc = Assists::new(&ctx, AssistResolveStrategy::All);
+            add_missing_impl_members(&mut acc, &ctx);
+            acc.finish()
+                .into_iter()
+                .flat_map(|assist| assist.source_change)
+                .flat_map(|change| change.source_file_edits.into_values())
+                .flat_map(|edit| edit.into_iter())
+                .map(|indel| indel.insert.matches("fn method_").count())
+                .sum::<usize>()
+        };
+
+        // Warm up the caches, so that only repeated evaluation over the same impl is measured.
+        generated_methods();
+        let hash = {
+            let _pt = test_utils::bench("add missing impl members on a big trait");
+            generated_methods()
+        };
+        assert_eq!(hash, 500);
+    }
+
     #[test]
     fn test_add_missing_impl_members() {
         check_assist(
@@ -234,9 +442,9 @@ trait Foo {
 impl Foo for S {
     fn bar(&self) {}
 
-    $0type Output;
+    type Output = ${0:()};
 
-    const CONST_2: i32;
+    const CONST_2: i32 = todo!();
 
     fn foo(&self) {
         todo!()
@@ -250,6 +458,114 @@ fn baz(&self) {
         );
     }
 
+    #[test]
+    fn test_custom_placeholder_macro() {
+        check_assist_with_config(
+            add_missing_impl_members,
+            AssistConfig { placeholder_macro: PlaceholderMacro::Unimplemented, ..TEST_CONFIG },
+            r#"
+trait Foo {
+    const CONST: i32;
+    fn foo<T>(&self, t: T) -> T
+    where
+        T: Copy;
+}
+
+struct S;
+
+impl Foo for S {$0}"#,
+            r#"
+trait Foo {
+    const CONST: i32;
+    fn foo<T>(&self, t: T) -> T
+    where
+        T: Copy;
+}
+
+struct S;
+
+impl Foo for S {
+    const CONST: i32 = ${0:unimplemented!()};
+
+    fn foo<T>(&self, t: T) -> T
+    where
+        T: Copy {
+        unimplemented!()
+    }
+}"#,
+        );
+    }
+
+    #[test]
+    fn test_wraps_long_signature() {
+        check_assist(
+            add_missing_impl_members,
+            r#"
+trait Foo {
+    fn very_long_method_name(&self, first_argument: u32, second_argument: u32, third: u32, fourth: u32) -> u32;
+}
+
+struct S;
+
+impl Foo for S {$0}"#,
+            r#"
+trait Foo {
+    fn very_long_method_name(&self, first_argument: u32, second_argument: u32, third: u32, fourth: u32) -> u32;
+}
+
+struct S;
+
+impl Foo for S {
+    fn very_long_method_name(
+        &self,
+        first_argument: u32,
+        second_argument: u32,
+        third: u32,
+        fourth: u32,
+    ) -> u32 {
+        ${0:todo!()}
+    }
+}"#,
+        );
+    }
+
+    #[test]
+    fn test_custom_max_width() {
+        check_assist_with_config(
+            add_missing_impl_members,
+            AssistConfig { max_width: 24, ..TEST_CONFIG },
+            r#"
+trait Foo {
+    fn foo(&self, a: u32);
+    fn bar();
+}
+
+struct S;
+
+impl Foo for S {$0}"#,
+            r#"
+trait Foo {
+    fn foo(&self, a: u32);
+    fn bar();
+}
+
+struct S;
+
+impl Foo for S {
+    fn foo(
+        &self,
+        a: u32,
+    ) {
+        ${0:todo!()}
+    }
+
+    fn bar() {
+        todo!()
+    }
+}"#,
+        );
+    }
+
     #[test]
     fn test_copied_overriden_members() {
         check_assist(
@@ -306,6 +622,43 @@ fn foo(&self) {
         );
     }
 
+    #[test]
+    fn test_not_applicable_through_type_alias() {
+        check_assist_not_applicable(
+            add_missing_impl_members,
+            r#"
+trait Foo { fn foo(&self); }
+type Alias = dyn Foo;
+struct S;
+impl Alias for S { $0 }"#,
+        );
+    }
+
+    #[test]
+    fn test_trait_through_renamed_import() {
+        check_assist(
+            add_missing_impl_members,
+            r#"
+mod m {
+    pub trait Foo { fn foo(&self); }
+}
+use m::Foo as Alias;
+struct S;
+impl Alias for S { $0 }"#,
+            r#"
+mod m {
+    pub trait Foo { fn foo(&self); }
+}
+use m::Foo as Alias;
+struct S;
+impl Alias for S {
+    fn foo(&self) {
+        ${0:todo!()}
+    }
+}"#,
+        );
+    }
+
     #[test]
     fn test_impl_def_without_braces() {
         check_assist(
@@ -382,6 +735,64 @@ fn foo(&self) {
         )
     }
 
+    #[test]
+    fn test_method_generics_are_preserved() {
+        check_assist(
+            add_missing_impl_members,
+            r#"
+trait Functor<A> {
+    fn map<B, F: Fn(A) -> B>(self, f: F) -> B;
+}
+struct S;
+impl Functor<u32> for S {$0}"#,
+            r#"
+trait Functor<A> {
+    fn map<B, F: Fn(A) -> B>(self, f: F) -> B;
+}
+struct S;
+impl Functor<u32> for S {
+    fn map<B, F: Fn(u32) -> B>(self, f: F) -> B {
+        ${0:todo!()}
+    }
+}"#,
+        );
+    }
+
+    #[test]
+    fn test_self_types_are_replaced() {
+        check_assist(
+            add_missing_impl_members,
+            r#"
+trait Make {
+    type Item;
+    fn make() -> Self;
+    fn item(&self) -> Self::Item;
+}
+struct S;
+impl Make for S {
+    type Item = u32;$0
+}"#,
+            r#"
+trait Make {
+    type Item;
+    fn make() -> Self;
+    fn item(&self) -> Self::Item;
+}
+struct S;
+impl Make for S {
+    type Item = u32;
+
+    fn make() -> S {
+        ${0:todo!()}
+    }
+
+    fn item(&self) -> u32 {
+        todo!()
+    }
+}"#,
+        );
+    }
+
     #[test]
     fn test_qualify_path_1() {
         check_assist(
@@ -650,7 +1061,7 @@ trait Foo {
 }
 struct S;
 impl Foo for S {
-    $0type Output;
+    type Output = ${0:()};
 
     fn foo(&self) {
         todo!()
@@ -694,6 +1105,47 @@ fn valid(some: u32) -> bool { false }
         )
     }
 
+    #[test]
+    fn test_defaulted_assoc_type_is_not_required() {
+        check_assist_not_applicable(
+            add_missing_impl_members,
+            r#"
+trait Foo {
+    type Output = u32;
+    fn valid(some: u32) -> bool { false }
+}
+struct S;
+impl Foo for S { $0 }"#,
+        )
+    }
+
+    #[test]
+    fn test_default_assoc_type() {
+        check_assist(
+            add_missing_default_members,
+            r#"
+trait Foo {
+    type Output = u32;
+    type Required;
+}
+struct S;
+impl Foo for S {
+    type Required = ();$0
+}"#,
+            r#"
+trait Foo {
+    type Output = u32;
+    type Required;
+}
+struct S;
+impl Foo for S {
+    type Required = ();
+
+    $0type Output = u32;
+}"#,
+        )
+    }
+
     #[test]
     fn test_generic_single_default_parameter() {
         check_assist(
@@ -761,7 +1213,42 @@ trait Tr {
 }
 
 impl Tr for () {
-    $0type Ty;
+    type Ty = ${0:()};
+}"#,
+        )
+    }
+
+    #[test]
+    fn test_assoc_items_follow_trait_order() {
+        check_assist(
+            add_missing_impl_members,
+            r#"
+trait Tr {
+    const FIRST: u32;
+    type Item;
+    fn next(&mut self) -> Option<u32>;
+    const LAST: bool;
+}
+
+impl Tr for () {$0}"#,
+            r#"
+trait Tr {
+    const FIRST: u32;
+    type Item;
+    fn next(&mut self) -> Option<u32>;
+    const LAST: bool;
+}
+
+impl Tr for () {
+    const FIRST: u32 = ${0:todo!()};
+
+    type Item = ();
+
+    fn next(&mut self) -> Option<u32> {
+        todo!()
+    }
+
+    const LAST: bool = todo!();
 }"#,
         )
     }
@@ -1353,8 +1840,8 @@ impl PartialEq for SomeStruct {
     }
 
     #[test]
-    fn test_ignore_function_body() {
-        check_assist_not_applicable(
+    fn test_apply_inside_function_body() {
+        check_assist(
             add_missing_default_members,
             r#"
 trait Trait {
@@ -1368,13 +1855,28 @@ impl Trait for () {
     fn foo(&self) {$0
         let x = 5;
     }
+}"#,
+            r#"
+trait Trait {
+    type X;
+    fn foo(&self);
+    fn bar(&self) {}
+}
+
+impl Trait for () {
+    type X = u8;
+    fn foo(&self) {
+        let x = 5;
+    }
+
+    $0fn bar(&self) {}
 }"#,
         )
     }
 
     #[test]
-    fn test_ignore_param_list() {
-        check_assist_not_applicable(
+    fn test_apply_inside_param_list() {
+        check_assist(
             add_missing_impl_members,
             r#"
 trait Trait {
@@ -1388,13 +1890,30 @@ impl Trait for () {
     fn foo(&self$0) {
         let x = 5;
     }
+}"#,
+            r#"
+trait Trait {
+    type X;
+    fn foo(&self);
+    fn bar(&self);
+}
+
+impl Trait for () {
+    type X = u8;
+    fn foo(&self) {
+        let x = 5;
+    }
+
+    fn bar(&self) {
+        ${0:todo!()}
+    }
 }"#,
         )
     }
 
     #[test]
-    fn test_ignore_scope_inside_function() {
-        check_assist_not_applicable(
+    fn test_apply_inside_nested_scope() {
+        check_assist(
             add_missing_impl_members,
             r#"
 trait Trait {
@@ -1408,10 +1927,41 @@ impl Trait for () {
     fn foo(&self) {
         let x = async {$0 5 };
     }
+}"#,
+            r#"
+trait Trait {
+    type X;
+    fn foo(&self);
+    fn bar(&self);
+}
+
+impl Trait for () {
+    type X = u8;
+    fn foo(&self) {
+        let x = async { 5 };
+    }
+
+    fn bar(&self) {
+        ${0:todo!()}
+    }
 }"#,
         )
     }
 
+    #[test]
+    fn test_not_applicable_after_impl() {
+        check_assist_not_applicable(
+            add_missing_impl_members,
+            r#"
+trait Trait {
+    fn foo(&self);
+}
+
+impl Trait for () {}
+$0"#,
+        )
+    }
+
     #[test]
     fn test_apply_outside_function() {
         check_assist(
@@ -1442,4 +1992,305 @@ fn foo(&self) {}
 }"#,
         )
     }
+
+    #[test]
+    fn test_tab_indentation() {
+        check_assist(
+            add_missing_impl_members,
+            &r#"
+trait Foo {
+    fn foo(&self);
+    fn bar(&self);
+}
+
+struct S;
+
+impl Foo for S {
+    fn bar(&self) {}
+$0
+}"#
+            .replace("    ", "\t"),
+            &r#"
+trait Foo {
+    fn foo(&self);
+    fn bar(&self);
+}
+
+struct S;
+
+impl Foo for S {
+    fn bar(&self) {}
+
+    fn foo(&self) {
+        ${0:todo!()}
+    }
+
+}"#
+            .replace("    ", "\t"),
+        );
+    }
+
+    #[test]
+    fn test_tab_indentation_empty_impl() {
+        check_assist(
+            add_missing_impl_members,
+            &r#"
+trait Foo {
+    fn foo(&self);
+}
+struct S;
+impl Foo for S {}$0"#
+                .replace("    ", "\t"),
+            &r#"
+trait Foo {
+    fn foo(&self);
+}
+struct S;
+impl Foo for S {
+    fn foo(&self) {
+        ${0:todo!()}
+    }
+}"#
+            .replace("    ", "\t"),
+        );
+    }
+
+    #[test]
+    fn test_tab_indentation_leaves_alignment_alone() {
+        // `~` stands for a space that is used for alignment rather than indentation.
+        check_assist(
+            add_missing_impl_members,
+            &r#"
+trait Foo {
+    fn foo(&self);
+    fn bar(&self);
+}
+struct S;
+mod m {
+    impl super::Foo for super::S {
+        fn bar(&self) {
+            let _ = (1,
+            ~~~~~~~~~2);
+        }$0
+    }
+}"#
+            .replace("    ", "\t")
+            .replace('~', " "),
+            &r#"
+trait Foo {
+    fn foo(&self);
+    fn bar(&self);
+}
+struct S;
+mod m {
+    impl super::Foo for super::S {
+        fn bar(&self) {
+            let _ = (1,
+            ~~~~~~~~~2);
+        }
+
+        fn foo(&self) {
+            ${0:todo!()}
+        }
+    }
+}"#
+            .replace("    ", "\t")
+            .replace('~', " "),
+        );
+    }
+
+    #[test]
+    fn test_mixed_indentation_prefers_dominant_style() {
+        check_assist(
+            add_missing_impl_members,
+            &r#"
+trait Foo {
+    fn foo(&self);
+    fn bar(&self);
+    fn baz(&self);
+}
+struct S {
+  field: (),
+}
+impl Foo for S {$0}"#
+                .replace("    ", "\t"),
+            &r#"
+trait Foo {
+    fn foo(&self);
+    fn bar(&self);
+    fn baz(&self);
+}
+struct S {
+  field: (),
+}
+impl Foo for S {
+    fn foo(&self) {
+        ${0:todo!()}
+    }
+
+    fn bar(&self) {
+        todo!()
+    }
+
+    fn baz(&self) {
+        todo!()
+    }
+}"#
+            .replace("    ", "\t"),
+        );
+    }
+
+    #[test]
+    fn test_two_space_indentation() {
+        check_assist(
+            add_missing_impl_members,
+            r#"
+trait Foo {
+  fn foo(&self);
+  fn bar(&self);
+}
+
+struct S;
+
+impl Foo for S {
+  fn bar(&self) {}$0
+}"#,
+            r#"
+trait Foo {
+  fn foo(&self);
+  fn bar(&self);
+}
+
+struct S;
+
+impl Foo for S {
+  fn bar(&self) {}
+
+  fn foo(&self) {
+    ${0:todo!()}
+  }
+}"#,
+        );
+    }
+
+    #[test]
+    fn test_max_width_with_two_space_indentation() {
+        check_assist_with_config(
+            add_missing_impl_members,
+            AssistConfig { max_width: 26, ..TEST_CONFIG },
+            r#"
+trait Foo {
+  fn foo(&self, a: u32);
+}
+
+struct S;
+
+impl Foo for S {$0}"#,
+            r#"
+trait Foo {
+  fn foo(&self, a: u32);
+}
+
+struct S;
+
+impl Foo for S {
+  fn foo(&self, a: u32) {
+    ${0:todo!()}
+  }
+}"#,
+        );
+    }
+
+    #[test]
+    fn test_add_missing_supertrait_impls() {
+        check_assist(
+            add_missing_impl_members,
+            r#"
+trait Base { fn base(&self); }
+trait Left: Base { fn left(&self); }
+unsafe trait Right: Base {}
+trait Foo: Left + Right { fn foo(&self); }
+struct S;
+impl Foo for S {$0}"#,
+            r#"
+trait Base { fn base(&self); }
+trait Left: Base { fn left(&self); }
+unsafe trait Right: Base {}
+trait Foo: Left + Right { fn foo(&self); }
+struct S;
+impl Foo for S {
+    fn foo(&self) {
+        ${0:todo!()}
+    }
+}
+
+impl Left for S {
+    fn left(&self) {
+        todo!()
+    }
+}
+
+unsafe impl Right for S {}
+
+impl Base for S {
+    fn base(&self) {
+        todo!()
+    }
+}"#,
+        );
+    }
+
+    #[test]
+    fn test_skip_implemented_supertraits() {
+        check_assist(
+            add_missing_impl_members,
+            r#"
+mod m {
+    pub trait Base { fn base(&self); }
+    pub trait Other {}
+}
+trait Foo: m::Base + m::Other { fn foo(&self); }
+struct S<T>(T);
+impl<T> m::Base for S<T> { fn base(&self) {} }
+mod inner {
+    impl<T: Clone> crate::Foo for crate::S<T> where T: Copy {$0}
+}"#,
+            r#"
+mod m {
+    pub trait Base { fn base(&self); }
+    pub trait Other {}
+}
+trait Foo: m::Base + m::Other { fn foo(&self); }
+struct S<T>(T);
+impl<T> m::Base for S<T> { fn base(&self) {} }
+mod inner {
+    impl<T: Clone> crate::Foo for crate::S<T> where T: Copy {
+        fn foo(&self) {
+            ${0:todo!()}
+        }
+    }
+
+    impl<T: Clone> crate::m::Other for crate::S<T> where T: Copy {}
+}"#,
+        );
+    }
+
+    #[test]
+    fn test_default_members_skip_supertraits() {
+        check_assist(
+            add_missing_default_members,
+            r#"
+trait Base {}
+trait Foo: Base { fn foo(&self) {} }
+struct S;
+impl Foo for S {$0}"#,
+            r#"
+trait Base {}
+trait Foo: Base { fn foo(&self) {} }
+struct S;
+impl Foo for S {
+    $0fn foo(&self) {}
+}"#,
+        );
+    }
 }
@@ -9,7 +9,7 @@
 use syntax::ast::{self, make, AstNode, HasName, MatchArmList, MatchExpr, Pat};
 
 use crate::{
-    utils::{self, render_snippet, Cursor},
+    utils::{self, placeholder_expr, render_snippet, Cursor},
     AssistContext, AssistId, AssistKind, Assists,
 };
 
@@ -158,7 +158,7 @@ pub(crate) fn add_missing_match_arms(acc: &mut Assists, ctx: &AssistContext<'_>)
             let new_match_arm_list = match_arm_list.clone_for_update();
             let missing_arms = missing_pats
                 .map(|(pat, hidden)| {
-                    (make::match_arm(iter::once(pat), None, make::ext::expr_todo()), hidden)
+                    (make::match_arm(iter::once(pat), None, placeholder_expr(ctx.config)), hidden)
                 })
                 .map(|(it, hidden)| (it.clone_for_update(), hidden));
 
@@ -193,7 +193,7 @@ pub(crate) fn add_missing_match_arms(acc: &mut Assists, ctx: &AssistContext<'_>)
                 let arm = make::match_arm(
                     iter::once(make::wildcard_pat().into()),
                     None,
-                    make::ext::expr_todo(),
+                    placeholder_expr(ctx.config),
                 )
                 .clone_for_update();
                 first_new_arm.get_or_insert_with(|| arm.clone());
@@ -386,8 +386,12 @@ fn build_pat(
 
 #[cfg(test)]
 mod tests {
-    use crate::tests::{
-        check_assist, check_assist_not_applicable, check_assist_target, check_assist_unresolved,
+    use crate::{
+        tests::{
+            check_assist, check_assist_not_applicable, check_assist_target,
+            check_assist_unresolved, check_assist_with_config, TEST_CONFIG,
+        },
+        AssistConfig, PlaceholderMacro,
     };
 
     use super::add_missing_match_arms;
@@ -1714,4 +1718,29 @@ fn foo(t: E) {
 }"#,
         );
     }
+
+    #[test]
+    fn uses_configured_placeholder_macro() {
+        check_assist_with_config(
+            add_missing_match_arms,
+            AssistConfig { placeholder_macro: PlaceholderMacro::Unimplemented, ..TEST_CONFIG },
+            r#"
+enum A { One, Two }
+fn foo(a: A) {
+    match a$0 {
+        A::One => {}
+    }
+}
+"#,
+            r#"
+enum A { One, Two }
+fn foo(a: A) {
+    match a {
+        A::One => {}
+        $0A::Two => unimplemented!(),
+    }
+}
+"#,
+        );
+    }
 }
@@ -0,0 +1,148 @@
+use syntax::ast::{self, edit::IndentLevel, AstNode, HasAttrs};
+
+use crate::{utils::attr_insertion_offset, AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: add_must_use
+//
+// Adds `#[must_use]` to a function that returns a value.
+//
+// ```
+// struct Point { x: u32 }
+// impl Point {
+//     /// Returns the x coordinate.
+//     fn $0x(&self) -> u32 {
+//         self.x
+//     }
+// }
+// ```
+// ->
+// ```
+// struct Point { x: u32 }
+// impl Point {
+//     /// Returns the x coordinate.
+//     #[must_use]
+//     fn x(&self) -> u32 {
+//         self.x
+//     }
+// }
+// ```
+pub(crate) fn add_must_use(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let fn_ = ctx.find_node_at_offset::<ast::Fn>()?;
+    if let Some(body) = fn_.body() {
+        if body.syntax().text_range().contains_inclusive(ctx.offset()) {
+            return None;
+        }
+    }
+    if fn_.attrs().any(|attr| attr.simple_name().as_deref() == Some("must_use")) {
+        cov_mark::hit!(add_must_use_already_present);
+        return None;
+    }
+    let ret_ty = ctx.sema.to_def(&fn_)?.ret_type(ctx.db());
+    if ret_ty.is_unit() || ret_ty.is_never() {
+        return None;
+    }
+
+    let offset = attr_insertion_offset(&fn_)?;
+    acc.add(
+        AssistId("add_must_use", AssistKind::Generate),
+        "Add `#[must_use]`",
+        fn_.syntax().text_range(),
+        |builder| {
+            let indent = IndentLevel::from_node(fn_.syntax());
+            builder.insert(offset, format!("#[must_use]\n{indent}"));
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn add_to_free_function() {
+        check_assist(
+            add_must_use,
+            r#"
+fn $0foo() -> (u32, u32) {
+    (0, 0)
+}
+"#,
+            r#"
+#[must_use]
+fn foo() -> (u32, u32) {
+    (0, 0)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn add_below_other_attributes() {
+        check_assist(
+            add_must_use,
+            r#"
+/// Docs.
+#[inline]
+pub fn foo$0() -> u32 {
+    0
+}
+"#,
+            r#"
+/// Docs.
+#[inline]
+#[must_use]
+pub fn foo() -> u32 {
+    0
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn add_for_aliased_return_type() {
+        check_assist(
+            add_must_use,
+            r#"
+type Id = u32;
+fn fo$0o() -> Id {
+    0
+}
+"#,
+            r#"
+type Id = u32;
+#[must_use]
+fn foo() -> Id {
+    0
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_present() {
+        cov_mark::check!(add_must_use_already_present);
+        check_assist_not_applicable(
+            add_must_use,
+            r#"
+#[must_use]
+fn $0foo() -> u32 {
+    0
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_for_unit() {
+        check_assist_not_applicable(add_must_use, "fn $0foo() {}");
+        check_assist_not_applicable(add_must_use, "type Unit = (); fn $0foo() -> Unit {}");
+        check_assist_not_applicable(add_must_use, "fn $0foo() -> () {}");
+    }
+
+    #[test]
+    fn not_applicable_in_body() {
+        check_assist_not_applicable(add_must_use, "fn foo() -> u32 { $00 }");
+    }
+}
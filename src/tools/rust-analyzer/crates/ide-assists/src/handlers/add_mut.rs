@@ -0,0 +1,107 @@
+use syntax::ast::{self, AstNode, HasName};
+
+use crate::{
+    handlers::remove_mut::is_in_trait_or_trait_impl, AssistContext, AssistId, AssistKind, Assists,
+};
+
+// Assist: add_mut
+//
+// Makes a binding or a `&self` parameter mutable.
+//
+// ```
+// fn main() {
+//     let $0x = Vec::new();
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let mut x = Vec::new();
+// }
+// ```
+pub(crate) fn add_mut(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    if let Some(self_param) = ctx.find_node_at_offset::<ast::SelfParam>() {
+        let amp = self_param.amp_token()?;
+        // Traits dictate the receivers of their methods.
+        if self_param.mut_token().is_some() || is_in_trait_or_trait_impl(&self_param) {
+            return None;
+        }
+        // `&'a self` becomes `&'a mut self`.
+        let (offset, text) = match self_param.lifetime() {
+            Some(lifetime) => (lifetime.syntax().text_range().end(), " mut"),
+            None => (amp.text_range().end(), "mut "),
+        };
+        return acc.add(
+            AssistId("add_mut", AssistKind::Refactor),
+            "Make `self` a mutable reference",
+            self_param.syntax().text_range(),
+            |builder| builder.insert(offset, text),
+        );
+    }
+
+    let ident_pat = ctx.find_node_at_offset::<ast::IdentPat>()?;
+    // `ref x` would become `ref mut x`, which changes what the binding is.
+    if ident_pat.mut_token().is_some() || ident_pat.ref_token().is_some() {
+        return None;
+    }
+    let name = ident_pat.name()?;
+    // Parameters of functions without a body can't have patterns like `mut x`.
+    let param = ident_pat.syntax().parent().and_then(ast::Param::cast);
+    if let Some(fn_) = param.and_then(|it| it.syntax().ancestors().find_map(ast::Fn::cast)) {
+        fn_.body()?;
+    }
+    // Unit structs and variants parse as bindings too.
+    ctx.sema.to_def(&ident_pat)?;
+
+    acc.add(
+        AssistId("add_mut", AssistKind::Refactor),
+        format!("Make `{name}` mutable"),
+        name.syntax().text_range(),
+        |builder| builder.insert(name.syntax().text_range().start(), "mut "),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn add_to_param_and_pattern() {
+        check_assist(add_mut, "fn foo($0x: u32) {}", "fn foo(mut x: u32) {}");
+        check_assist(
+            add_mut,
+            "fn foo(p: (u32, u32)) { let (a, $0b) = p; }",
+            "fn foo(p: (u32, u32)) { let (a, mut b) = p; }",
+        );
+    }
+
+    #[test]
+    fn add_to_self_param() {
+        check_assist(
+            add_mut,
+            "struct S; impl S { fn foo(&$0self) {} }",
+            "struct S; impl S { fn foo(&mut self) {} }",
+        );
+        check_assist(
+            add_mut,
+            "struct S; impl S { fn foo<'a>(&'a $0self) {} }",
+            "struct S; impl S { fn foo<'a>(&'a mut self) {} }",
+        );
+    }
+
+    #[test]
+    fn not_applicable() {
+        check_assist_not_applicable(add_mut, "fn main() { let mut $0x = 1; }");
+        check_assist_not_applicable(add_mut, "fn main() { let ref $0x = 1; }");
+        check_assist_not_applicable(add_mut, "struct S; impl S { fn foo(&mut $0self) {} }");
+        check_assist_not_applicable(add_mut, "struct U; fn foo(u: U) { let $0U = u; }");
+        check_assist_not_applicable(add_mut, "trait T { fn foo($0x: u32); }");
+        check_assist_not_applicable(add_mut, "trait T { fn foo(&$0self) {} }");
+        check_assist_not_applicable(
+            add_mut,
+            "trait T { fn foo(&self); } struct S; impl T for S { fn foo(&$0self) {} }",
+        );
+    }
+}
@@ -78,12 +78,19 @@ pub(crate) fn add_turbo_fish(acc: &mut Assists, ctx: &AssistContext<'_>) -> Opti
         }
     }
 
+    // Parameters introduced by `impl Trait` arguments can't be given explicitly.
     let number_of_arguments = generics
         .iter()
-        .filter(|param| {
-            matches!(param, hir::GenericParam::TypeParam(_) | hir::GenericParam::ConstParam(_))
+        .filter(|param| match param {
+            hir::GenericParam::TypeParam(it) => !it.is_implicit(ctx.db()),
+            hir::GenericParam::ConstParam(_) => true,
+            hir::GenericParam::LifetimeParam(_) => false,
         })
         .count();
+    if number_of_arguments == 0 {
+        cov_mark::hit!(add_turbo_fish_only_implicit_params);
+        return None;
+    }
 
     acc.add(
         AssistId("add_turbo_fish", AssistKind::RefactorRewrite),
@@ -395,6 +402,39 @@ fn make<T, const N: usize>(t: T) {}
 fn main() {
     make::<${1:_}, ${0:_}>(3);
 }
+"#,
+        );
+    }
+
+    #[test]
+    fn add_turbo_fish_skips_impl_trait_params() {
+        check_assist(
+            add_turbo_fish,
+            r#"
+fn make<T>(a: impl Copy) -> T {}
+fn main() {
+    make$0(3);
+}
+"#,
+            r#"
+fn make<T>(a: impl Copy) -> T {}
+fn main() {
+    make::<${0:_}>(3);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn add_turbo_fish_only_impl_trait_params() {
+        cov_mark::check!(add_turbo_fish_only_implicit_params);
+        check_assist_not_applicable(
+            add_turbo_fish,
+            r#"
+fn show(a: impl Copy) {}
+fn main() {
+    show$0(3);
+}
 "#,
         );
     }
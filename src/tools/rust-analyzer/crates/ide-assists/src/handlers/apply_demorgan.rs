@@ -1,6 +1,9 @@
 use std::collections::VecDeque;
 
-use syntax::ast::{self, AstNode};
+use syntax::{
+    ast::{self, make, AstNode},
+    ted,
+};
 
 use crate::{utils::invert_boolean_expression, AssistContext, AssistId, AssistKind, Assists};
 
@@ -143,6 +146,69 @@ pub(crate) fn apply_demorgan(acc: &mut Assists, ctx: &AssistContext<'_>) -> Opti
     )
 }
 
+// Assist: apply_demorgan_to_negation
+//
+// Apply https://en.wikipedia.org/wiki/De_Morgan%27s_laws[De Morgan's law] to a negated
+// logical expression, distributing the negation over its operands.
+// This transforms expressions of the form `!(l && r)` into `!l || !r`.
+// This also works with `||`.
+//
+// ```
+// fn main() {
+//     if $0!(x == 4 && y < 3.14) {}
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     if x != 4 || y >= 3.14 {}
+// }
+// ```
+pub(crate) fn apply_demorgan_to_negation(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let prefix_expr = ctx.find_node_at_offset::<ast::PrefixExpr>()?;
+    if prefix_expr.op_kind()? != ast::UnaryOp::Not {
+        return None;
+    }
+    let bin_expr = match prefix_expr.expr()? {
+        ast::Expr::ParenExpr(paren_expr) => match paren_expr.expr()? {
+            ast::Expr::BinExpr(it) => it,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let opposite_op = match bin_expr.op_kind()? {
+        ast::BinaryOp::LogicOp(ast::LogicOp::And) => ast::LogicOp::Or,
+        ast::BinaryOp::LogicOp(ast::LogicOp::Or) => ast::LogicOp::And,
+        _ => return None,
+    };
+    // Within the operands, the cursor is on some other expression.
+    if bin_expr.syntax().text_range().contains_range(ctx.selection_trimmed()) {
+        return None;
+    }
+    let (lhs, rhs) = (bin_expr.lhs()?, bin_expr.rhs()?);
+
+    acc.add(
+        AssistId("apply_demorgan_to_negation", AssistKind::RefactorRewrite),
+        "Apply De Morgan's law",
+        prefix_expr.syntax().text_range(),
+        |edit| {
+            let new_expr = make::expr_bin_op(
+                invert_boolean_expression(lhs),
+                ast::BinaryOp::LogicOp(opposite_op),
+                invert_boolean_expression(rhs),
+            )
+            .clone_for_update();
+            let prefix_expr = edit.make_mut(prefix_expr);
+            ted::replace(prefix_expr.syntax(), new_expr.syntax());
+            // `a && !(b || c)` is fine as `a && !b && !c`, but `a && !(b && c)` isn't `a && !b || !c`.
+            if new_expr.syntax().parent().map_or(false, |it| new_expr.needs_parens_in(it)) {
+                let paren_expr = make::expr_paren(new_expr.clone()).clone_for_update();
+                ted::replace(new_expr.syntax(), paren_expr.syntax());
+            }
+        },
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tests::{check_assist, check_assist_not_applicable};
@@ -231,4 +297,63 @@ fn demorgan_doesnt_hang() {
             "fn f() { !(!1 || !3 || !4) || 5 }",
         )
     }
+
+    #[test]
+    fn demorgan_distributes_negation() {
+        check_assist(
+            apply_demorgan_to_negation,
+            "fn f() { let v = $0!(a && !b); }",
+            "fn f() { let v = !a || b; }",
+        );
+        check_assist(
+            apply_demorgan_to_negation,
+            "fn f() { !$0(a || b > c) }",
+            "fn f() { !a && b <= c }",
+        );
+    }
+
+    #[test]
+    fn demorgan_distributes_negation_one_level() {
+        check_assist(
+            apply_demorgan_to_negation,
+            "fn f() { $0!(a && (b || c)) }",
+            "fn f() { !a || !(b || c) }",
+        );
+        check_assist(
+            apply_demorgan_to_negation,
+            "fn f() { $0!(a || b && c) }",
+            "fn f() { !a && !(b && c) }",
+        );
+    }
+
+    #[test]
+    fn demorgan_negation_keeps_precedence() {
+        check_assist(
+            apply_demorgan_to_negation,
+            "fn f() { x && $0!(a && b) }",
+            "fn f() { x && (!a || !b) }",
+        );
+        check_assist(
+            apply_demorgan_to_negation,
+            "fn f() { $0!(a || b) && x }",
+            "fn f() { !a && !b && x }",
+        );
+        check_assist(
+            apply_demorgan_to_negation,
+            "fn f() { x == $0!(a || b) }",
+            "fn f() { x == (!a && !b) }",
+        );
+        check_assist(
+            apply_demorgan_to_negation,
+            "fn f() { !$0!(a || b) }",
+            "fn f() { !(!a && !b) }",
+        );
+    }
+
+    #[test]
+    fn demorgan_negation_not_applicable() {
+        check_assist_not_applicable(apply_demorgan_to_negation, "fn f() { $0!a }");
+        check_assist_not_applicable(apply_demorgan_to_negation, "fn f() { $0!(a + b) }");
+        check_assist_not_applicable(apply_demorgan_to_negation, "fn f() { !(a $0&& b) }");
+    }
 }
@@ -1,5 +1,3 @@
-use std::cmp::Reverse;
-
 use hir::{db::HirDatabase, Module};
 use ide_db::{
     helpers::mod_path_to_ast,
@@ -122,15 +120,22 @@ pub(crate) fn auto_import(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<
     let current_module =
         current_node.as_ref().and_then(|node| ctx.sema.scope(node)).map(|scope| scope.module());
 
-    // prioritize more relevant imports
-    proposed_imports
-        .sort_by_key(|import| Reverse(relevance_score(ctx, import, current_module.as_ref())));
-
-    for import in proposed_imports {
+    let scored_imports = proposed_imports
+        .into_iter()
+        .map(|import| (relevance_score(ctx, &import, current_module.as_ref()), import))
+        .collect::<Vec<_>>();
+    // Relevance scores are never positive, so offset them to rank every import above the other
+    // assists for the same target, such as `qualify_path`.
+    let least_relevant = scored_imports.iter().map(|(score, _)| *score).min().unwrap_or(0);
+
+    for (relevance, import) in scored_imports {
+        // prioritize more relevant imports
+        let priority = relevance - least_relevant + 1;
         let import_path = import.import_path;
 
-        acc.add_group(
+        acc.add_group_with_priority(
             &group_label,
+            priority,
             AssistId("auto_import", AssistKind::QuickFix),
             format!("Import `{import_path}`"),
             range,
@@ -1194,6 +1199,80 @@ fn bar() {
         );
     }
 
+    #[test]
+    fn merges_into_existing_use_in_inner_module() {
+        check_assist(
+            auto_import,
+            r#"
+mod baz {
+    pub struct Foo;
+}
+mod qux {
+    pub struct Bar;
+}
+
+mod bar {
+    use crate::qux::Bar;
+
+    fn bar() {
+        Foo$0;
+    }
+}
+"#,
+            r#"
+mod baz {
+    pub struct Foo;
+}
+mod qux {
+    pub struct Bar;
+}
+
+mod bar {
+    use crate::{qux::Bar, baz::Foo};
+
+    fn bar() {
+        Foo;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn inserts_new_group_before_later_groups_in_inner_module() {
+        check_assist(
+            auto_import,
+            r#"
+mod baz {
+    pub struct Foo;
+}
+
+mod bar {
+    use super::qux::Bar;
+
+    fn bar() {
+        Foo$0;
+    }
+}
+"#,
+            r#"
+mod baz {
+    pub struct Foo;
+}
+
+mod bar {
+    use crate::baz::Foo;
+
+    use super::qux::Bar;
+
+    fn bar() {
+        Foo;
+    }
+}
+"#,
+        );
+    }
+
     #[test]
     fn uses_abs_path_with_extern_crate_clash() {
         cov_mark::check!(ambiguous_crate_start);
@@ -11,14 +11,15 @@
 
 // Assist: change_visibility
 //
-// Adds or changes existing visibility specifier.
+// Adds or changes existing visibility specifier: private items become `pub`, and `pub` and
+// `pub(crate)` are cycled between.
 //
 // ```
 // $0fn frobnicate() {}
 // ```
 // ->
 // ```
-// pub(crate) fn frobnicate() {}
+// pub fn frobnicate() {}
 // ```
 pub(crate) fn change_visibility(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
     if let Some(vis) = ctx.find_node_at_offset::<ast::Visibility>() {
@@ -56,6 +57,20 @@ fn add_vis(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
         if parent.children().any(|child| child.kind() == VISIBILITY) {
             return None;
         }
+        // Members of traits and trait impls take the visibility of the trait
+        if let Some(assoc_item_list) = parent.parent().and_then(ast::AssocItemList::cast) {
+            let in_trait = match assoc_item_list.syntax().parent() {
+                Some(it) => {
+                    ast::Trait::can_cast(it.kind())
+                        || ast::Impl::cast(it).map_or(false, |imp| imp.trait_().is_some())
+                }
+                None => false,
+            };
+            if in_trait {
+                cov_mark::hit!(change_visibility_trait_member);
+                return None;
+            }
+        }
         (vis_offset(&parent), keyword.text_range())
     } else if let Some(field_name) = ctx.find_node_at_offset::<ast::Name>() {
         let field = field_name.syntax().ancestors().find_map(ast::RecordField::cast)?;
@@ -78,10 +93,10 @@ fn add_vis(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
 
     acc.add(
         AssistId("change_visibility", AssistKind::RefactorRewrite),
-        "Change visibility to pub(crate)",
+        "Change visibility to pub",
         target,
         |edit| {
-            edit.insert(offset, "pub(crate) ");
+            edit.insert(offset, "pub ");
         },
     )
 }
@@ -91,7 +106,7 @@ fn change_vis(acc: &mut Assists, vis: ast::Visibility) -> Option<()> {
         let target = vis.syntax().text_range();
         return acc.add(
             AssistId("change_visibility", AssistKind::RefactorRewrite),
-            "Change Visibility to pub(crate)",
+            "Change visibility to pub(crate)",
             target,
             |edit| {
                 edit.replace(vis.syntax().text_range(), "pub(crate)");
@@ -119,16 +134,16 @@ mod tests {
     use super::*;
 
     #[test]
-    fn change_visibility_adds_pub_crate_to_items() {
-        check_assist(change_visibility, "$0fn foo() {}", "pub(crate) fn foo() {}");
-        check_assist(change_visibility, "f$0n foo() {}", "pub(crate) fn foo() {}");
-        check_assist(change_visibility, "$0struct Foo {}", "pub(crate) struct Foo {}");
-        check_assist(change_visibility, "$0mod foo {}", "pub(crate) mod foo {}");
-        check_assist(change_visibility, "$0trait Foo {}", "pub(crate) trait Foo {}");
-        check_assist(change_visibility, "m$0od {}", "pub(crate) mod {}");
-        check_assist(change_visibility, "unsafe f$0n foo() {}", "pub(crate) unsafe fn foo() {}");
-        check_assist(change_visibility, "$0macro foo() {}", "pub(crate) macro foo() {}");
-        check_assist(change_visibility, "$0use foo;", "pub(crate) use foo;");
+    fn change_visibility_adds_pub_to_items() {
+        check_assist(change_visibility, "$0fn foo() {}", "pub fn foo() {}");
+        check_assist(change_visibility, "f$0n foo() {}", "pub fn foo() {}");
+        check_assist(change_visibility, "$0struct Foo {}", "pub struct Foo {}");
+        check_assist(change_visibility, "$0mod foo {}", "pub mod foo {}");
+        check_assist(change_visibility, "$0trait Foo {}", "pub trait Foo {}");
+        check_assist(change_visibility, "m$0od {}", "pub mod {}");
+        check_assist(change_visibility, "unsafe f$0n foo() {}", "pub unsafe fn foo() {}");
+        check_assist(change_visibility, "$0macro foo() {}", "pub macro foo() {}");
+        check_assist(change_visibility, "$0use foo;", "pub use foo;");
     }
 
     #[test]
@@ -136,9 +151,9 @@ fn change_visibility_works_with_struct_fields() {
         check_assist(
             change_visibility,
             r"struct S { $0field: u32 }",
-            r"struct S { pub(crate) field: u32 }",
+            r"struct S { pub field: u32 }",
         );
-        check_assist(change_visibility, r"struct S ( $0u32 )", r"struct S ( pub(crate) u32 )");
+        check_assist(change_visibility, r"struct S ( $0u32 )", r"struct S ( pub u32 )");
     }
 
     #[test]
@@ -160,19 +175,53 @@ fn change_visibility_pub_crate_to_pub() {
         check_assist(change_visibility, "$0pub(crate) fn foo() {}", "pub fn foo() {}")
     }
 
+    #[test]
+    fn change_visibility_inherent_impl_items() {
+        check_assist(
+            change_visibility,
+            "struct S; impl S { $0fn foo() {} }",
+            "struct S; impl S { pub fn foo() {} }",
+        );
+        check_assist(
+            change_visibility,
+            "struct S; impl S { $0const C: u8 = 0; }",
+            "struct S; impl S { pub const C: u8 = 0; }",
+        );
+        check_assist(
+            change_visibility,
+            "struct S; impl S { $0pub fn foo() {} }",
+            "struct S; impl S { pub(crate) fn foo() {} }",
+        );
+    }
+
+    #[test]
+    fn not_applicable_for_trait_impl_items() {
+        cov_mark::check!(change_visibility_trait_member);
+        check_assist_not_applicable(
+            change_visibility,
+            "trait Foo { fn foo(); } struct S; impl Foo for S { $0fn foo() {} }",
+        );
+    }
+
+    #[test]
+    fn not_applicable_for_trait_items() {
+        cov_mark::check!(change_visibility_trait_member);
+        check_assist_not_applicable(change_visibility, "trait Foo { $0fn foo(); }");
+    }
+
     #[test]
     fn change_visibility_const() {
-        check_assist(change_visibility, "$0const FOO = 3u8;", "pub(crate) const FOO = 3u8;");
+        check_assist(change_visibility, "$0const FOO = 3u8;", "pub const FOO = 3u8;");
     }
 
     #[test]
     fn change_visibility_static() {
-        check_assist(change_visibility, "$0static FOO = 3u8;", "pub(crate) static FOO = 3u8;");
+        check_assist(change_visibility, "$0static FOO = 3u8;", "pub static FOO = 3u8;");
     }
 
     #[test]
     fn change_visibility_type_alias() {
-        check_assist(change_visibility, "$0type T = ();", "pub(crate) type T = ();");
+        check_assist(change_visibility, "$0type T = ();", "pub type T = ();");
     }
 
     #[test]
@@ -193,7 +242,7 @@ fn change_visibility_handles_comment_attrs() {
             // comments
 
             #[derive(Debug)]
-            pub(crate) struct Foo;
+            pub struct Foo;
             ",
         )
     }
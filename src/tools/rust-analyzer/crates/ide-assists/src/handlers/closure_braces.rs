@@ -0,0 +1,211 @@
+use syntax::{
+    ast::{
+        self,
+        edit::{AstNodeEdit, IndentLevel},
+        AstNode,
+    },
+    SyntaxKind::COMMENT,
+    TextRange,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: add_closure_braces
+//
+// Wraps the body of a closure in a block.
+//
+// ```
+// fn main() {
+//     let f = |x$0| x + 1;
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let f = |x| { $0x + 1 };
+// }
+// ```
+pub(crate) fn add_closure_braces(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let (_, body) = closure_at_cursor(ctx)?;
+    if braced_body(&body).is_some() {
+        cov_mark::hit!(add_closure_braces_already_braced);
+        return None;
+    }
+
+    let target = body.syntax().text_range();
+    acc.add(
+        AssistId("add_closure_braces", AssistKind::RefactorRewrite),
+        "Add braces to closure body",
+        target,
+        |builder| match ctx.config.snippet_cap {
+            Some(cap) => builder.replace_snippet(cap, target, format!("{{ $0{body} }}")),
+            None => builder.replace(target, format!("{{ {body} }}")),
+        },
+    )
+}
+
+// Assist: remove_closure_braces
+//
+// Removes the braces around the body of a closure when it is a single expression.
+//
+// ```
+// fn main() {
+//     let f = |x$0| { x + 1 };
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let f = |x| x + 1;
+// }
+// ```
+pub(crate) fn remove_closure_braces(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let (closure, body) = closure_at_cursor(ctx)?;
+    // The body of a closure with an explicit return type has to be a block.
+    if closure.ret_type().is_some() {
+        return None;
+    }
+    let block = match braced_body(&body) {
+        Some(it) => it,
+        None => {
+            cov_mark::hit!(remove_closure_braces_not_braced);
+            return None;
+        }
+    };
+    let stmt_list = block.stmt_list()?;
+    if stmt_list.statements().next().is_some()
+        || stmt_list.syntax().children_with_tokens().any(|it| it.kind() == COMMENT)
+    {
+        return None;
+    }
+    let tail_expr = stmt_list.tail_expr()?;
+
+    let target = block.syntax().text_range();
+    acc.add(
+        AssistId("remove_closure_braces", AssistKind::RefactorRewrite),
+        "Remove braces from closure body",
+        target,
+        |builder| {
+            builder.replace(target, tail_expr.dedent(IndentLevel(1)).syntax().text());
+        },
+    )
+}
+
+/// Finds the closure whose head, that is everything up to the body, is under the cursor.
+fn closure_at_cursor(ctx: &AssistContext<'_>) -> Option<(ast::ClosureExpr, ast::Expr)> {
+    let closure = ctx.find_node_at_offset::<ast::ClosureExpr>()?;
+    let body = closure.body()?;
+    let head_range =
+        TextRange::new(closure.syntax().text_range().start(), body.syntax().text_range().start());
+    if !head_range.contains_range(ctx.selection_trimmed()) {
+        return None;
+    }
+    Some((closure, body))
+}
+
+fn braced_body(body: &ast::Expr) -> Option<ast::BlockExpr> {
+    match body {
+        ast::Expr::BlockExpr(block) if block.modifier().is_none() && block.label().is_none() => {
+            Some(block.clone())
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    #[test]
+    fn add_braces() {
+        check_assist(
+            add_closure_braces,
+            "fn f() { let f = |x$0| x + 1; }",
+            "fn f() { let f = |x| { $0x + 1 }; }",
+        );
+    }
+
+    #[test]
+    fn add_braces_to_move_closure() {
+        check_assist(
+            add_closure_braces,
+            "fn f() { foo(mo$0ve || bar()); }",
+            "fn f() { foo(move || { $0bar() }); }",
+        );
+    }
+
+    #[test]
+    fn add_braces_not_applicable_in_body() {
+        check_assist_not_applicable(add_closure_braces, "fn f() { let f = |x| x $0+ 1; }");
+    }
+
+    #[test]
+    fn add_braces_not_applicable_when_braced() {
+        cov_mark::check!(add_closure_braces_already_braced);
+        check_assist_not_applicable(add_closure_braces, "fn f() { let f = |x$0| { x + 1 }; }");
+    }
+
+    #[test]
+    fn remove_braces() {
+        check_assist(
+            remove_closure_braces,
+            "fn f() { let f = |x$0| { x + 1 }; }",
+            "fn f() { let f = |x| x + 1; }",
+        );
+    }
+
+    #[test]
+    fn remove_braces_dedents_multiline_expr() {
+        check_assist(
+            remove_closure_braces,
+            r#"
+fn f() {
+    let f = |x$0| {
+        foo(
+            x,
+        )
+    };
+}
+"#,
+            r#"
+fn f() {
+    let f = |x| foo(
+        x,
+    );
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn remove_braces_not_applicable_when_not_braced() {
+        cov_mark::check!(remove_closure_braces_not_braced);
+        check_assist_not_applicable(remove_closure_braces, "fn f() { let f = |x$0| x + 1; }");
+    }
+
+    #[test]
+    fn remove_braces_not_applicable_with_statements() {
+        check_assist_not_applicable(
+            remove_closure_braces,
+            "fn f() { let f = |x$0| { foo(); x + 1 }; }",
+        );
+    }
+
+    #[test]
+    fn remove_braces_not_applicable_with_return_type() {
+        check_assist_not_applicable(
+            remove_closure_braces,
+            "fn f() { let f = |x$0| -> i32 { x + 1 }; }",
+        );
+    }
+
+    #[test]
+    fn remove_braces_not_applicable_for_unsafe_block() {
+        check_assist_not_applicable(
+            remove_closure_braces,
+            "fn f() { let f = |x$0| unsafe { x + 1 }; }",
+        );
+    }
+}
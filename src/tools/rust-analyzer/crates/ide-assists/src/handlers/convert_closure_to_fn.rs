@@ -0,0 +1,327 @@
+use hir::{HirDisplay, PathResolution};
+use syntax::{
+    ast::{
+        self,
+        edit::{AstNodeEdit, IndentLevel},
+        HasName,
+    },
+    AstNode, SyntaxKind,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_closure_to_fn
+//
+// Converts a closure bound to a variable into a free function of the same name.
+//
+// ```
+// fn main() {
+//     let add$0 = |a: i32, b: i32| a + b;
+//     add(1, 2);
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     add(1, 2);
+// }
+//
+// fn add(a: i32, b: i32) -> i32 {
+//     a + b
+// }
+// ```
+pub(crate) fn convert_closure_to_fn(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let let_stmt = ctx.find_node_at_offset::<ast::LetStmt>()?;
+    let closure = match let_stmt.initializer()? {
+        ast::Expr::ClosureExpr(it) => it,
+        _ => return None,
+    };
+    let body = closure.body()?;
+    if ctx.offset() > body.syntax().text_range().start()
+        || closure.async_token().is_some()
+        || closure.static_token().is_some()
+    {
+        return None;
+    }
+    let name = match let_stmt.pat()? {
+        ast::Pat::IdentPat(it) if it.ref_token().is_none() && it.pat().is_none() => it.name()?,
+        _ => return None,
+    };
+    if captures_environment(ctx, &closure) {
+        cov_mark::hit!(convert_closure_to_fn_captures);
+        return None;
+    }
+    // The function would clash with an item of the same name, and a local of the same name
+    // would take over the uses of the closure.
+    let scope = ctx.sema.scope(closure.syntax())?;
+    let mut name_taken = false;
+    scope.process_all_names(&mut |it, _| name_taken |= it.to_smol_str() == name.text().as_str());
+    if name_taken {
+        cov_mark::hit!(convert_closure_to_fn_name_taken);
+        return None;
+    }
+
+    let module = scope.module();
+    let render = |ty: hir::Type| {
+        if !ty.type_params(ctx.db()).is_empty() || ty.contains_unknown() || ty.is_closure() {
+            cov_mark::hit!(convert_closure_to_fn_unnameable_type);
+            return None;
+        }
+        ty.display_source_code(ctx.db(), module.into()).ok()
+    };
+    let mut params = Vec::new();
+    for param in closure.param_list()?.params() {
+        let pat = param.pat()?;
+        let ty = render(ctx.sema.type_of_pat(&pat)?.original)?;
+        let ty = param.ty().map_or(ty, |it| it.to_string());
+        params.push(format!("{pat}: {ty}"));
+    }
+    let ret_ty = ctx.sema.type_of_expr(&body)?.original;
+    let ret = match closure.ret_type() {
+        Some(ret) => format!(" {ret}"),
+        None if ret_ty.is_unit() => String::new(),
+        None => format!(" -> {}", render(ret_ty)?),
+    };
+
+    // Nested functions can't be put into impls or traits, so the function goes after whatever
+    // item the closure is defined in.
+    let item = let_stmt.syntax().ancestors().filter_map(ast::Item::cast).find(|it| {
+        it.syntax().parent().map_or(false, |parent| {
+            ast::SourceFile::can_cast(parent.kind()) || ast::ItemList::can_cast(parent.kind())
+        })
+    })?;
+
+    let target = let_stmt.syntax().text_range();
+    acc.add(
+        AssistId("convert_closure_to_fn", AssistKind::RefactorRewrite),
+        "Convert closure to fn",
+        target,
+        |builder| {
+            let delete = match let_stmt.syntax().prev_sibling_or_token() {
+                Some(ws) if ws.kind() == SyntaxKind::WHITESPACE => ws.text_range().cover(target),
+                _ => target,
+            };
+            builder.delete(delete);
+
+            let indent = IndentLevel::from_node(item.syntax());
+            let body = match body {
+                ast::Expr::BlockExpr(block) if block.modifier().is_none() => {
+                    block.reset_indent().indent(indent).to_string()
+                }
+                expr => {
+                    let expr = expr.reset_indent().indent(indent + 1);
+                    format!("{{\n{}{expr}\n{indent}}}", indent + 1)
+                }
+            };
+            builder.insert(
+                item.syntax().text_range().end(),
+                format!("\n\n{indent}fn {name}({}){ret} {body}", params.join(", ")),
+            );
+        },
+    )
+}
+
+/// Checks whether `closure` refers to anything that a free function couldn't: local variables
+/// defined outside of it, or generic parameters and `Self` of the enclosing items.
+fn captures_environment(ctx: &AssistContext<'_>, closure: &ast::ClosureExpr) -> bool {
+    let range = closure.syntax().text_range();
+    closure.syntax().descendants().any(|node| {
+        // Uses of variables can't be told apart from other tokens in macro calls.
+        if ast::MacroCall::can_cast(node.kind()) {
+            return true;
+        }
+        let path = match ast::Path::cast(node) {
+            Some(it) => it,
+            None => return false,
+        };
+        match ctx.sema.resolve_path(&path) {
+            Some(PathResolution::Local(local)) => {
+                let decl = local.source(ctx.db()).value;
+                let decl =
+                    decl.either(|it| it.syntax().text_range(), |it| it.syntax().text_range());
+                !range.contains_range(decl)
+            }
+            Some(
+                PathResolution::TypeParam(_)
+                | PathResolution::ConstParam(_)
+                | PathResolution::SelfType(_),
+            ) => true,
+            _ => false,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn convert_with_written_types() {
+        check_assist(
+            convert_closure_to_fn,
+            r#"
+fn main() {
+    let $0f = |a: i32, b: i32| -> i32 { a + b };
+    let x = f(1, 2);
+}
+"#,
+            r#"
+fn main() {
+    let x = f(1, 2);
+}
+
+fn f(a: i32, b: i32) -> i32 { a + b }
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_with_inferred_types() {
+        check_assist(
+            convert_closure_to_fn,
+            r#"
+fn twice(x: u32) -> u32 { x }
+fn main() {
+    let twice_twice = $0|x| {
+        let y = twice(x);
+        twice(y)
+    };
+    twice_twice(1);
+}
+"#,
+            r#"
+fn twice(x: u32) -> u32 { x }
+fn main() {
+    twice_twice(1);
+}
+
+fn twice_twice(x: u32) -> u32 {
+    let y = twice(x);
+    twice(y)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_closure_in_method() {
+        check_assist(
+            convert_closure_to_fn,
+            r#"
+struct S;
+impl S {
+    fn foo(&self) {
+        let $0log = |msg: &str| {};
+        log("foo");
+    }
+}
+"#,
+            r#"
+struct S;
+impl S {
+    fn foo(&self) {
+        log("foo");
+    }
+}
+
+fn log(msg: &str) {}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_captures() {
+        cov_mark::check!(convert_closure_to_fn_captures);
+        check_assist_not_applicable(
+            convert_closure_to_fn,
+            r#"
+fn main() {
+    let y = 1;
+    let $0f = |x: i32| x + y;
+    f(1);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_self() {
+        check_assist_not_applicable(
+            convert_closure_to_fn,
+            r#"
+struct S(u32);
+impl S {
+    fn foo(&self) {
+        let $0f = || self.0;
+        f();
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_generic_types() {
+        cov_mark::check!(convert_closure_to_fn_unnameable_type);
+        check_assist_not_applicable(
+            convert_closure_to_fn,
+            r#"
+fn foo<T>(t: T) {
+    let $0f = |x| x;
+    f(t);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_unknown_types() {
+        check_assist_not_applicable(
+            convert_closure_to_fn,
+            r#"
+fn main() {
+    let $0f = |x| x;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_in_closure_body() {
+        check_assist_not_applicable(
+            convert_closure_to_fn,
+            r#"
+fn main() {
+    let f = |x: i32| x $0+ 1;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_name_is_taken() {
+        cov_mark::check_count!(convert_closure_to_fn_name_taken, 2);
+        check_assist_not_applicable(
+            convert_closure_to_fn,
+            r#"
+fn main() {
+    let $0f = |x: i32| x + 1;
+    f(1);
+}
+fn f() {}
+"#,
+        );
+        check_assist_not_applicable(
+            convert_closure_to_fn,
+            r#"
+fn main() {
+    let f = 1;
+    let $0f = |x: i32| x + 1;
+    f(1);
+}
+"#,
+        );
+    }
+}
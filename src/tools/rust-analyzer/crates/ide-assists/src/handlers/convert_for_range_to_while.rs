@@ -0,0 +1,440 @@
+use ide_db::{syntax_helpers::node_ext::for_each_break_and_continue_expr, FxHashSet};
+use stdx::format_to;
+use syntax::{
+    ast::{self, edit::IndentLevel, HasLoopBody, HasName, RangeOp},
+    AstNode, SyntaxKind, T,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_for_range_to_while
+//
+// Converts a `for` loop over an integer range into a `while` loop with an explicit counter.
+//
+// ```
+// fn main() {
+//     $0for i in 0..10 {
+//         foo(i);
+//     }
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let mut i = 0;
+//     while i < 10 {
+//         foo(i);
+//         i += 1;
+//     }
+// }
+// ```
+pub(crate) fn convert_for_range_to_while(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let for_kw = ctx.find_token_syntax_at_offset(T![for])?;
+    let for_loop = for_kw.parent().and_then(ast::ForExpr::cast)?;
+    // The counter is declared in a statement in front of the loop.
+    let parent = for_loop.syntax().parent()?;
+    if !ast::ExprStmt::can_cast(parent.kind()) && !ast::StmtList::can_cast(parent.kind()) {
+        return None;
+    }
+
+    // The counter of `for mut i` could be changed by the body, which would alter the iteration.
+    let name = match for_loop.pat()? {
+        ast::Pat::IdentPat(it)
+            if it.ref_token().is_none() && it.mut_token().is_none() && it.pat().is_none() =>
+        {
+            it.name()?
+        }
+        _ => return None,
+    };
+    let range = match for_loop.iterable()? {
+        ast::Expr::RangeExpr(it) => it,
+        _ => return None,
+    };
+    let (start, end) = (range.start()?, range.end()?);
+    let ty = ctx.sema.type_of_expr(&start)?.original;
+    if !ty.is_int_or_uint() {
+        return None;
+    }
+    let cmp = match range.op_kind()? {
+        RangeOp::Exclusive => "<",
+        // Incrementing past the end would overflow if it's the maximum of the type.
+        RangeOp::Inclusive if is_below_max(&end, &ty) => "<=",
+        RangeOp::Inclusive => {
+            cov_mark::hit!(convert_for_range_to_while_inclusive_max);
+            return None;
+        }
+    };
+
+    let body = for_loop.loop_body()?;
+    let stmt_list = body.stmt_list()?;
+    // `continue` would skip the increment at the end of the body.
+    let mut has_continue = false;
+    for_each_break_and_continue_expr(for_loop.label(), Some(stmt_list.clone()), &mut |expr| {
+        has_continue |= matches!(expr, ast::Expr::ContinueExpr(_));
+    });
+    if has_continue {
+        cov_mark::hit!(convert_for_range_to_while_continue);
+        return None;
+    }
+    // A binding of the same name in the body would shadow the counter the increment is for.
+    let rebinds_counter = stmt_list
+        .syntax()
+        .descendants()
+        .filter_map(ast::IdentPat::cast)
+        .any(|it| it.name().map_or(false, |it| it.text() == name.text()));
+    if rebinds_counter {
+        cov_mark::hit!(convert_for_range_to_while_rebinds_counter);
+        return None;
+    }
+    let r_curly = stmt_list.r_curly_token()?;
+
+    let target = for_loop.syntax().text_range();
+    acc.add(
+        AssistId("convert_for_range_to_while", AssistKind::RefactorRewrite),
+        "Convert for loop over range to while",
+        target,
+        |builder| {
+            let indent = IndentLevel::from_node(for_loop.syntax());
+            let body_indent = indent + 1;
+
+            let body_start = body.syntax().text_range().start();
+            let before_curly = r_curly.text_range().start() - body_start;
+            let body_text = body.syntax().text().to_string();
+            let mut body_text = body_text[..usize::from(before_curly)].trim_end().to_owned();
+            let needs_semi = stmt_list.tail_expr().map_or(false, |tail| {
+                !matches!(
+                    tail,
+                    ast::Expr::BlockExpr(_)
+                        | ast::Expr::IfExpr(_)
+                        | ast::Expr::MatchExpr(_)
+                        | ast::Expr::LoopExpr(_)
+                        | ast::Expr::WhileExpr(_)
+                        | ast::Expr::ForExpr(_)
+                )
+            });
+            if needs_semi {
+                body_text.push(';');
+            }
+
+            // The end of a `for` range is evaluated once, so anything but a literal is hoisted.
+            let mut buf = format!("let mut {name} = {start};\n{indent}");
+            let end = match end {
+                ast::Expr::Literal(it) => it.to_string(),
+                _ => {
+                    let end_name = fresh_end_name(&for_loop);
+                    format_to!(buf, "let {end_name} = {end};\n{indent}");
+                    end_name
+                }
+            };
+            let label = for_loop.label().map(|it| format!("{it} ")).unwrap_or_default();
+            format_to!(
+                buf,
+                "{label}while {name} {cmp} {end} {body_text}\n{body_indent}{name} += 1;\n{indent}}}"
+            );
+            builder.replace(target, buf);
+        },
+    )
+}
+
+/// Whether `end` is an integer literal below the maximum of `ty`.
+fn is_below_max(end: &ast::Expr, ty: &hir::Type) -> bool {
+    let value = match end {
+        ast::Expr::Literal(it) => match it.kind() {
+            ast::LiteralKind::IntNumber(it) => it.value(),
+            _ => None,
+        },
+        _ => None,
+    };
+    let max = match ty.as_builtin().map(|it| it.name().to_smol_str()) {
+        Some(name) => match name.as_str() {
+            "u8" => u8::MAX as u128,
+            "i8" => i8::MAX as u128,
+            // `usize` and `isize` are only guaranteed to be 16 bits wide.
+            "u16" | "usize" => u16::MAX as u128,
+            "i16" | "isize" => i16::MAX as u128,
+            "u32" => u32::MAX as u128,
+            "i32" => i32::MAX as u128,
+            "u64" => u64::MAX as u128,
+            "i64" => i64::MAX as u128,
+            "u128" => u128::MAX,
+            "i128" => i128::MAX as u128,
+            _ => return false,
+        },
+        None => return false,
+    };
+    value.map_or(false, |it| it < max)
+}
+
+/// Picks a name for the hoisted end of the range that the loop doesn't mention.
+fn fresh_end_name(for_loop: &ast::ForExpr) -> String {
+    let names: FxHashSet<_> = for_loop
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|it| it.kind() == SyntaxKind::IDENT)
+        .map(|it| it.text().to_owned())
+        .collect();
+    let mut name = "end".to_owned();
+    let mut counter = 0;
+    while names.contains(&name) {
+        counter += 1;
+        name = format!("end{counter}");
+    }
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn convert_exclusive_range() {
+        check_assist(
+            convert_for_range_to_while,
+            r#"
+fn main() {
+    let n = 10;
+    f$0or i in 0..n {
+        foo(i);
+    }
+}
+"#,
+            r#"
+fn main() {
+    let n = 10;
+    let mut i = 0;
+    let end = n;
+    while i < end {
+        foo(i);
+        i += 1;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_inclusive_range() {
+        check_assist(
+            convert_for_range_to_while,
+            r#"
+fn main() {
+    for$0 i in 1..=5 {
+        foo(i);
+    }
+}
+"#,
+            r#"
+fn main() {
+    let mut i = 1;
+    while i <= 5 {
+        foo(i);
+        i += 1;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_adds_semicolon_after_tail() {
+        check_assist(
+            convert_for_range_to_while,
+            r#"
+fn main() {
+    $0for i in 0..3 {
+        let x = i * 2;
+        foo(x)
+    }
+}
+"#,
+            r#"
+fn main() {
+    let mut i = 0;
+    while i < 3 {
+        let x = i * 2;
+        foo(x);
+        i += 1;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_empty_body_and_label() {
+        check_assist(
+            convert_for_range_to_while,
+            r#"
+fn main() {
+    'outer: $0for i in 0..3 {}
+}
+"#,
+            r#"
+fn main() {
+    let mut i = 0;
+    'outer: while i < 3 {
+        i += 1;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_continue() {
+        cov_mark::check!(convert_for_range_to_while_continue);
+        check_assist_not_applicable(
+            convert_for_range_to_while,
+            r#"
+fn main() {
+    $0for i in 0..3 {
+        if i == 1 {
+            continue;
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn applicable_with_continue_of_inner_loop() {
+        check_assist(
+            convert_for_range_to_while,
+            r#"
+fn main() {
+    $0for i in 0..3 {
+        loop {
+            continue;
+        }
+    }
+}
+"#,
+            r#"
+fn main() {
+    let mut i = 0;
+    while i < 3 {
+        loop {
+            continue;
+        }
+        i += 1;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_non_range() {
+        check_assist_not_applicable(
+            convert_for_range_to_while,
+            r#"
+fn main() {
+    let v = [1, 2, 3];
+    $0for i in v {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_open_range() {
+        check_assist_not_applicable(
+            convert_for_range_to_while,
+            r#"
+fn main() {
+    $0for i in 0.. {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_picks_fresh_name_for_end() {
+        check_assist(
+            convert_for_range_to_while,
+            r#"
+fn main() {
+    let v = [1, 2];
+    $0for i in 0..v.len() {
+        let end = v[i];
+    }
+}
+"#,
+            r#"
+fn main() {
+    let v = [1, 2];
+    let mut i = 0;
+    let end1 = v.len();
+    while i < end1 {
+        let end = v[i];
+        i += 1;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_inclusive_range_up_to_max() {
+        cov_mark::check_count!(convert_for_range_to_while_inclusive_max, 3);
+        check_assist_not_applicable(
+            convert_for_range_to_while,
+            r#"
+fn main() {
+    $0for i in 0..=255u8 {}
+}
+"#,
+        );
+        check_assist_not_applicable(
+            convert_for_range_to_while,
+            r#"
+fn main() {
+    $0for i in 0u8..=u8::MAX {}
+}
+"#,
+        );
+        check_assist_not_applicable(
+            convert_for_range_to_while,
+            r#"
+fn main() {
+    let n = 5;
+    $0for i in 0..=n {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_body_rebinds_counter() {
+        cov_mark::check!(convert_for_range_to_while_rebinds_counter);
+        check_assist_not_applicable(
+            convert_for_range_to_while,
+            r#"
+fn main() {
+    $0for i in 0..3 {
+        let i = i * 2;
+        foo(i);
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_mutable_counter() {
+        check_assist_not_applicable(
+            convert_for_range_to_while,
+            r#"
+fn main() {
+    $0for mut i in 0..3 {
+        i += 1;
+    }
+}
+"#,
+        );
+    }
+}
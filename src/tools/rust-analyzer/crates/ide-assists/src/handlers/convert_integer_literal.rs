@@ -265,4 +265,33 @@ fn convert_overflowing_literal() {
             111111111111111111111111111111111111111111111111111111111111111111111111$0;";
         check_assist_not_applicable(convert_integer_literal, before);
     }
+
+    #[test]
+    fn convert_hexadecimal_integer_with_suffix() {
+        let before = "const _: u8 = 0xffu8$0;";
+
+        check_assist_by_label(
+            convert_integer_literal,
+            before,
+            "const _: u8 = 255u8;",
+            "Convert 0xffu8 to 255u8",
+        );
+
+        check_assist_by_label(
+            convert_integer_literal,
+            before,
+            "const _: u8 = 0b11111111u8;",
+            "Convert 0xffu8 to 0b11111111u8",
+        );
+    }
+
+    #[test]
+    fn convert_hexadecimal_digits_are_not_a_suffix() {
+        check_assist_by_label(
+            convert_integer_literal,
+            "const _: i32 = 0x1E$0;",
+            "const _: i32 = 30;",
+            "Convert 0x1E to 30",
+        );
+    }
 }
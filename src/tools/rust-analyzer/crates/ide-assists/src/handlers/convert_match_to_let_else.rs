@@ -1,6 +1,10 @@
-use ide_db::defs::{Definition, NameRefClass};
+use ide_db::{
+    defs::{Definition, NameRefClass},
+    rename::source_edit_from_references,
+    syntax_helpers::node_ext::block_as_lone_tail,
+};
 use syntax::{
-    ast::{self, HasName},
+    ast::{self, make, HasName},
     ted, AstNode, SyntaxNode,
 };
 
@@ -49,7 +53,7 @@ pub(crate) fn convert_match_to_let_else(acc: &mut Assists, ctx: &AssistContext<'
 
     let diverging_arm_expr = diverging_arm.expr()?;
     let extracting_arm_pat = extracting_arm.pat()?;
-    let extracted_variable = find_extracted_variable(ctx, &extracting_arm)?;
+    let extracted_variable = find_extracted_variable(ctx, &extracting_arm.expr()?)?;
 
     acc.add(
         AssistId("convert_match_to_let_else", AssistKind::RefactorRewrite),
@@ -65,6 +69,136 @@ pub(crate) fn convert_match_to_let_else(acc: &mut Assists, ctx: &AssistContext<'
     )
 }
 
+// Assist: convert_if_let_to_let_else
+//
+// Converts let statement with `if let` initializer to let-else statement, using the binding of
+// the `if let` in the place of the variable.
+//
+// ```
+// # //- minicore: option
+// fn foo(opt: Option<i32>) -> i32 {
+//     let val = $0if let Some(it) = opt { it } else { return 0 };
+//     val + 1
+// }
+// ```
+// ->
+// ```
+// fn foo(opt: Option<i32>) -> i32 {
+//     let Some(it) = opt else { return 0 };
+//     it + 1
+// }
+// ```
+pub(crate) fn convert_if_let_to_let_else(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let let_stmt: ast::LetStmt = ctx.find_node_at_offset()?;
+    let binding = find_binding(let_stmt.pat()?)?;
+    if binding.pat().is_some() {
+        return None;
+    }
+
+    let initializer = match let_stmt.initializer() {
+        Some(ast::Expr::IfExpr(it)) => it,
+        _ => return None,
+    };
+    let let_expr = match initializer.condition()? {
+        ast::Expr::LetExpr(it) => it,
+        _ => return None,
+    };
+    let else_branch = match initializer.else_branch()? {
+        ast::ElseBranch::Block(it) => it,
+        ast::ElseBranch::IfExpr(_) => return None,
+    };
+    if !is_diverging_block(ctx, &else_branch) {
+        cov_mark::hit!(non_diverging_else_branch);
+        return None;
+    }
+
+    let pat = let_expr.pat()?;
+    let scrutinee = let_expr.expr()?;
+    let extracted_variable =
+        find_extracted_variable(ctx, &ast::Expr::BlockExpr(initializer.then_branch()?))?;
+    // The then branch may just as well evaluate to a variable from outside of the pattern.
+    if !pat.syntax().text_range().contains_range(extracted_variable.syntax().text_range()) {
+        return None;
+    }
+    let extracted_pat = extracted_variable.syntax().parent().and_then(ast::IdentPat::cast)?;
+    let has_modifiers = |it: &ast::IdentPat| it.ref_token().is_some() || it.mut_token().is_some();
+    if has_modifiers(&binding) && has_modifiers(&extracted_pat) {
+        return None;
+    }
+
+    // The uses of the variable now refer to the binding of the pattern, so nothing else may go by
+    // its name where they are.
+    let new_name = extracted_variable.text().to_string();
+    let local = ctx.sema.to_def(&binding)?;
+    let usages = Definition::Local(local).usages(&ctx.sema).all();
+    if binding.name()?.text() != new_name.as_str() {
+        let new_path = make::ext::ident_path(&new_name);
+        let name_is_taken = usages.iter().flat_map(|(_, refs)| refs).any(|reference| {
+            ctx.sema
+                .scope(reference.name.syntax())
+                .map_or(true, |scope| scope.speculative_resolve(&new_path).is_some())
+        });
+        if name_is_taken {
+            cov_mark::hit!(let_else_binding_name_taken);
+            return None;
+        }
+    }
+
+    acc.add(
+        AssistId("convert_if_let_to_let_else", AssistKind::RefactorRewrite),
+        "Convert if let to let-else",
+        let_stmt.syntax().text_range(),
+        |builder| {
+            let pat = pat.syntax().clone_for_update();
+            // The modifiers of the variable go over to the binding it is replaced with.
+            if has_modifiers(&binding) {
+                let new_pat = make::ident_pat(
+                    binding.ref_token().is_some(),
+                    binding.mut_token().is_some(),
+                    extracted_variable.clone(),
+                );
+                let range = extracted_pat.syntax().text_range();
+                if let Some(old_pat) = pat
+                    .descendants()
+                    .filter_map(ast::IdentPat::cast)
+                    .find(|it| it.syntax().text_range() == range)
+                {
+                    ted::replace(old_pat.syntax(), new_pat.syntax().clone_for_update());
+                }
+            }
+            builder.replace(
+                let_stmt.syntax().text_range(),
+                format!("let {pat} = {scrutinee} else {else_branch};"),
+            );
+            for (_, references) in usages {
+                let edit =
+                    source_edit_from_references(&references, Definition::Local(local), &new_name);
+                for indel in edit {
+                    builder.replace(indel.delete, indel.insert);
+                }
+            }
+        },
+    )
+}
+
+// Checks whether `block` never completes. A block ending with a diverging statement, like
+// `{ return; }`, has the unit type, so its statements have to be looked at as well.
+fn is_diverging_block(ctx: &AssistContext<'_>, block: &ast::BlockExpr) -> bool {
+    let is_never =
+        |expr: ast::Expr| ctx.sema.type_of_expr(&expr).map_or(false, |it| it.original().is_never());
+    if is_never(ast::Expr::BlockExpr(block.clone())) {
+        return true;
+    }
+    let stmt_list = match block.stmt_list() {
+        Some(it) if it.tail_expr().is_none() => it,
+        _ => return false,
+    };
+    match stmt_list.statements().last() {
+        Some(ast::Stmt::ExprStmt(it)) => it.expr().map_or(false, is_never),
+        _ => false,
+    }
+}
+
 // Given a pattern, find the name introduced to the surrounding scope.
 fn find_binding(pat: ast::Pat) -> Option<ast::IdentPat> {
     if let ast::Pat::IdentPat(ident) = pat {
@@ -103,9 +237,13 @@ fn find_arms(
     }
 }
 
-// Given an extracting arm, find the extracted variable.
-fn find_extracted_variable(ctx: &AssistContext<'_>, arm: &ast::MatchArm) -> Option<ast::Name> {
-    match arm.expr()? {
+// Given the expression of an extracting arm, find the extracted variable.
+fn find_extracted_variable(ctx: &AssistContext<'_>, expr: &ast::Expr) -> Option<ast::Name> {
+    let expr = match expr {
+        ast::Expr::BlockExpr(block) => block_as_lone_tail(block).unwrap_or_else(|| expr.clone()),
+        _ => expr.clone(),
+    };
+    match expr {
         ast::Expr::PathExpr(path) => {
             let name_ref = path.syntax().descendants().find_map(ast::NameRef::cast)?;
             match NameRefClass::classify(&ctx.sema, &name_ref)? {
@@ -410,4 +548,128 @@ fn foo(opt: Option<i32>) -> Option<i32> {
     "#,
         );
     }
+
+    #[test]
+    fn if_let_basic() {
+        check_assist(
+            convert_if_let_to_let_else,
+            r#"
+//- minicore: option
+fn foo(opt: Option<i32>) -> i32 {
+    let $0x = if let Some(v) = opt { v } else { return 0 };
+    x + 1
+}
+"#,
+            r#"
+fn foo(opt: Option<i32>) -> i32 {
+    let Some(v) = opt else { return 0 };
+    v + 1
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn if_let_keeps_modifiers_and_else_block() {
+        check_assist(
+            convert_if_let_to_let_else,
+            r#"
+//- minicore: option
+fn foo(opt: Option<i32>) {
+    let mut x = $0if let Some(v) = opt {
+        v
+    } else {
+        return;
+    };
+    x += 1;
+}
+"#,
+            r#"
+fn foo(opt: Option<i32>) {
+    let Some(mut v) = opt else {
+        return;
+    };
+    v += 1;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn if_let_renames_into_field_shorthand() {
+        check_assist(
+            convert_if_let_to_let_else,
+            r#"
+//- minicore: option
+struct Point { x: i32, y: i32 }
+fn foo(opt: Option<Point>) -> Point {
+    let x = $0if let Some(Point { y, .. }) = opt { y } else { return Point { x: 0, y: 0 } };
+    Point { x, y: 0 }
+}
+"#,
+            r#"
+struct Point { x: i32, y: i32 }
+fn foo(opt: Option<Point>) -> Point {
+    let Some(Point { y, .. }) = opt else { return Point { x: 0, y: 0 } };
+    Point { x: y, y: 0 }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn if_let_not_applicable_when_binding_name_is_taken() {
+        cov_mark::check!(let_else_binding_name_taken);
+        check_assist_not_applicable(
+            convert_if_let_to_let_else,
+            r#"
+//- minicore: option
+fn foo(opt: Option<i32>, v: i32) -> i32 {
+    let $0x = if let Some(v) = opt { v } else { return 0 };
+    x + v
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn if_let_not_applicable_for_non_diverging_else() {
+        cov_mark::check!(non_diverging_else_branch);
+        check_assist_not_applicable(
+            convert_if_let_to_let_else,
+            r#"
+//- minicore: option
+fn foo(opt: Option<i32>) {
+    let x = $0if let Some(v) = opt { v } else { 0 };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn if_let_not_applicable_if_then_branch_is_not_a_binding() {
+        cov_mark::check!(extracting_arm_is_not_an_identity_expr);
+        check_assist_not_applicable(
+            convert_if_let_to_let_else,
+            r#"
+//- minicore: option
+fn foo(opt: Option<i32>) {
+    let x = $0if let Some(v) = opt { v + 1 } else { return };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn if_let_not_applicable_for_outer_variable() {
+        check_assist_not_applicable(
+            convert_if_let_to_let_else,
+            r#"
+//- minicore: option
+fn foo(opt: Option<i32>, y: i32) {
+    let x = $0if let Some(_) = opt { y } else { return };
+}
+"#,
+        );
+    }
 }
@@ -6,7 +6,10 @@
     match_ast, SyntaxKind, SyntaxNode,
 };
 
-use crate::{assist_context::SourceChangeBuilder, AssistContext, AssistId, AssistKind, Assists};
+use crate::{
+    assist_context::SourceChangeBuilder, utils::copy_attrs, AssistContext, AssistId, AssistKind,
+    Assists,
+};
 
 // Assist: convert_named_struct_to_tuple_struct
 //
@@ -85,9 +88,11 @@ fn edit_struct_def(
     strukt: &Either<ast::Struct, ast::Variant>,
     record_fields: ast::RecordFieldList,
 ) {
-    let tuple_fields = record_fields
-        .fields()
-        .filter_map(|f| Some(ast::make::tuple_field(f.visibility(), f.ty()?)));
+    let tuple_fields = record_fields.fields().filter_map(|f| {
+        let field = ast::make::tuple_field(f.visibility(), f.ty()?).clone_for_update();
+        copy_attrs(&f, field.syntax());
+        Some(field)
+    });
     let tuple_fields = ast::make::tuple_field_list(tuple_fields);
     let record_fields_text_range = record_fields.syntax().text_range();
 
@@ -530,6 +535,27 @@ fn not_applicable_other_than_record_variant() {
         );
     }
 
+    #[test]
+    fn convert_variant_keeps_attributes() {
+        check_assist(
+            convert_named_struct_to_tuple_struct,
+            r#"
+enum Enum {
+    /// Docs.
+    #[deprecated]
+    Variant$0 { #[allow(dead_code)] field1: usize, field2: String },
+}
+"#,
+            r#"
+enum Enum {
+    /// Docs.
+    #[deprecated]
+    Variant(#[allow(dead_code)] usize, String),
+}
+"#,
+        );
+    }
+
     #[test]
     fn convert_simple_variant() {
         check_assist(
@@ -0,0 +1,536 @@
+use hir::AsAssocItem;
+use ide_db::{defs::Definition, famous_defs::FamousDefs};
+use syntax::{
+    ast::{
+        self,
+        edit::{AstNodeEdit, IndentLevel},
+        AstNode, HasArgList, HasLoopBody, HasName,
+    },
+    SyntaxNode, TextRange,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_push_loop_to_collect
+//
+// Converts a loop pushing into a new `Vec` into a call to `collect()`.
+//
+// ```
+// # //- minicore: vec
+// fn main() {
+//     let mut v = Vec::new();
+//     $0for x in 0..10 {
+//         v.push(x * 2);
+//     }
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let v: Vec<_> = (0..10).map(|x| x * 2).collect();
+// }
+// ```
+pub(crate) fn convert_push_loop_to_collect(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let for_loop = ctx.find_node_at_offset::<ast::ForExpr>()?;
+    let body = for_loop.loop_body()?;
+    if body.syntax().text_range().contains_inclusive(ctx.offset()) {
+        return None;
+    }
+    let pat = for_loop.pat()?;
+    let iterable = for_loop.iterable()?;
+    let push = single_method_call(&body)?;
+    let pushed = match push.arg_list()?.args().collect::<Vec<_>>().as_slice() {
+        [arg] => arg.clone(),
+        _ => return None,
+    };
+    // Control flow can't leave the loop from within a closure.
+    if pushed.syntax().descendants().any(|it| {
+        matches!(
+            ast::Expr::cast(it),
+            Some(
+                ast::Expr::ReturnExpr(_)
+                    | ast::Expr::BreakExpr(_)
+                    | ast::Expr::ContinueExpr(_)
+                    | ast::Expr::TryExpr(_)
+                    | ast::Expr::AwaitExpr(_)
+            )
+        )
+    }) {
+        cov_mark::hit!(convert_push_loop_to_collect_control_flow);
+        return None;
+    }
+
+    let let_stmt = for_loop
+        .syntax()
+        .parent()
+        .filter(|it| ast::ExprStmt::can_cast(it.kind()))
+        .unwrap_or_else(|| for_loop.syntax().clone())
+        .prev_sibling()
+        .and_then(ast::LetStmt::cast)?;
+    let ident_pat = match let_stmt.pat()? {
+        ast::Pat::IdentPat(it) if it.mut_token().is_some() && it.ref_token().is_none() => it,
+        _ => return None,
+    };
+    match let_stmt.initializer()? {
+        ast::Expr::CallExpr(call) if call.arg_list()?.args().next().is_none() => {
+            match call.expr()? {
+                ast::Expr::PathExpr(path) if path.path()?.segment()?.to_string() == "new" => (),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    }
+
+    let local = ctx.sema.to_def(&ident_pat)?;
+    let receiver = match push.receiver()? {
+        ast::Expr::PathExpr(it) => it,
+        _ => return None,
+    };
+    match ctx.sema.resolve_path(&receiver.path()?)? {
+        hir::PathResolution::Local(it) if it == local => (),
+        _ => return None,
+    }
+    if !is_vec_push(ctx, &push, &let_stmt)? {
+        return None;
+    }
+
+    let loop_range = for_loop.syntax().text_range();
+    let usages = Definition::Local(local).usages(&ctx.sema).all();
+    let refs: Vec<_> = usages.iter().flat_map(|(_, refs)| refs).collect();
+    if refs.iter().filter(|it| loop_range.contains_range(it.range)).count() != 1 {
+        cov_mark::hit!(convert_push_loop_to_collect_used_in_loop);
+        return None;
+    }
+    let mutated_after = refs.iter().any(|it| {
+        !loop_range.contains_range(it.range)
+            && it.name.as_name_ref().map_or(true, |it| is_mutable_use(ctx, it.syntax()))
+    });
+
+    let target = TextRange::new(let_stmt.syntax().text_range().start(), loop_range.end());
+    acc.add(
+        AssistId("convert_push_loop_to_collect", AssistKind::RefactorRewrite),
+        "Convert loop into `collect()`",
+        target,
+        |builder| {
+            let mut_ = if mutated_after { "mut " } else { "" };
+            let name = ident_pat.name().map_or_else(String::new, |it| it.to_string());
+            let ty = let_stmt.ty().map_or_else(|| "Vec<_>".to_owned(), |it| it.to_string());
+            let iter = iterable_text(ctx, &iterable);
+            let is_identity = matches!(
+                (&pat, &pushed),
+                (ast::Pat::IdentPat(pat), ast::Expr::PathExpr(path))
+                    if pat.name().map(|it| it.to_string()) == path.path().map(|it| it.to_string())
+            );
+            let map = if is_identity { String::new() } else { format!(".map(|{pat}| {pushed})") };
+            builder.replace(target, format!("let {mut_}{name}: {ty} = {iter}{map}.collect();"));
+        },
+    )
+}
+
+// Assist: convert_collect_to_push_loop
+//
+// Converts a `collect()` of a mapped iterator into a `Vec` into a loop pushing into it.
+//
+// ```
+// # //- minicore: iterators, vec
+// fn foo(xs: impl Iterator<Item = u32>) {
+//     let v: Vec<_> = $0xs.map(|x| x * 2).collect();
+// }
+// ```
+// ->
+// ```
+// fn foo(xs: impl Iterator<Item = u32>) {
+//     let mut v = Vec::new();
+//     for x in xs {
+//         v.push(x * 2);
+//     }
+// }
+// ```
+pub(crate) fn convert_collect_to_push_loop(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let let_stmt = ctx.find_node_at_offset::<ast::LetStmt>()?;
+    let name = match let_stmt.pat()? {
+        ast::Pat::IdentPat(it) if it.ref_token().is_none() && it.pat().is_none() => it.name()?,
+        _ => return None,
+    };
+    let collect = match let_stmt.initializer()? {
+        ast::Expr::MethodCallExpr(it) if it.name_ref()?.text() == "collect" => it,
+        _ => return None,
+    };
+    let map = match collect.receiver()? {
+        ast::Expr::MethodCallExpr(it) if it.name_ref()?.text() == "map" => it,
+        _ => return None,
+    };
+    let closure = match map.arg_list()?.args().collect::<Vec<_>>().as_slice() {
+        [ast::Expr::ClosureExpr(it)] => it.clone(),
+        _ => return None,
+    };
+    let pat = match closure.param_list()?.params().collect::<Vec<_>>().as_slice() {
+        [param] if param.ty().is_none() => param.pat()?,
+        _ => return None,
+    };
+    let pushed = closure.body()?;
+    // Returning from the closure would return from the enclosing function once in a loop.
+    if pushed.syntax().descendants().any(|it| {
+        matches!(ast::Expr::cast(it), Some(ast::Expr::ReturnExpr(_) | ast::Expr::TryExpr(_)))
+    }) {
+        cov_mark::hit!(convert_collect_to_push_loop_control_flow);
+        return None;
+    }
+    let iterable = match map.receiver()? {
+        ast::Expr::ParenExpr(it) => it.expr()?,
+        it => it,
+    };
+
+    if !is_iterator_method(ctx, &map)? || !is_iterator_method(ctx, &collect)? {
+        return None;
+    }
+    let collected = ctx.sema.type_of_expr(&ast::Expr::MethodCallExpr(collect))?.original;
+    if !is_std_vec(ctx, &collected, let_stmt.syntax())? {
+        return None;
+    }
+
+    let target = let_stmt.syntax().text_range();
+    acc.add(
+        AssistId("convert_collect_to_push_loop", AssistKind::RefactorRewrite),
+        "Convert `collect()` into a loop",
+        target,
+        |builder| {
+            let indent = IndentLevel::from_node(let_stmt.syntax());
+            // `Vec<_>` is only there for `collect()`.
+            let ty = match let_stmt.ty() {
+                Some(it) if it.to_string() != "Vec<_>" => format!(": {it}"),
+                _ => String::new(),
+            };
+            let pushed = pushed.reset_indent().indent(indent + 1);
+            let push = format!("{}{name}.push({pushed});", indent + 1);
+            let for_loop = format!("for {pat} in {iterable} {{\n{push}\n{indent}}}");
+            builder
+                .replace(target, format!("let mut {name}{ty} = Vec::new();\n{indent}{for_loop}"));
+        },
+    )
+}
+
+/// Returns the method call that makes up the whole body of a loop.
+fn single_method_call(body: &ast::BlockExpr) -> Option<ast::MethodCallExpr> {
+    let stmt_list = body.stmt_list()?;
+    let expr = match (stmt_list.statements().collect::<Vec<_>>().as_slice(), stmt_list.tail_expr())
+    {
+        ([ast::Stmt::ExprStmt(stmt)], None) => stmt.expr()?,
+        ([], Some(expr)) => expr,
+        _ => return None,
+    };
+    match expr {
+        ast::Expr::MethodCallExpr(it) if it.name_ref()?.text() == "push" => Some(it),
+        _ => None,
+    }
+}
+
+/// Checks that `push` calls `Vec::push` of the standard library, on a vector created by
+/// `let_stmt`.
+fn is_vec_push(
+    ctx: &AssistContext<'_>,
+    push: &ast::MethodCallExpr,
+    let_stmt: &ast::LetStmt,
+) -> Option<bool> {
+    let func = ctx.sema.resolve_method_call(push)?;
+    let ty = ctx.sema.type_of_expr(&let_stmt.initializer()?)?.original;
+    if !is_std_vec(ctx, &ty, push.syntax())? {
+        return Some(false);
+    }
+    let is_inherent = match func.as_assoc_item(ctx.db())?.container(ctx.db()) {
+        hir::AssocItemContainer::Impl(it) => it.trait_(ctx.db()).is_none(),
+        hir::AssocItemContainer::Trait(_) => false,
+    };
+    Some(is_inherent)
+}
+
+/// Checks whether `ty` is the standard library's `Vec`, as seen from `node`.
+fn is_std_vec(ctx: &AssistContext<'_>, ty: &hir::Type, node: &SyntaxNode) -> Option<bool> {
+    let vec = match ty.as_adt()? {
+        hir::Adt::Struct(it) => it,
+        _ => return Some(false),
+    };
+    let krate = ctx.sema.scope(node)?.krate();
+    let is_std =
+        FamousDefs(&ctx.sema, krate).builtin_crates().any(|it| it == vec.module(ctx.db()).krate());
+    Some(is_std && vec.name(ctx.db()).to_smol_str() == "Vec")
+}
+
+/// Checks that `call` calls a method of the standard library's `Iterator` trait.
+fn is_iterator_method(ctx: &AssistContext<'_>, call: &ast::MethodCallExpr) -> Option<bool> {
+    let func = ctx.sema.resolve_method_call(call)?;
+    let trait_ = match func.as_assoc_item(ctx.db())?.container(ctx.db()) {
+        hir::AssocItemContainer::Trait(it) => it,
+        hir::AssocItemContainer::Impl(_) => return Some(false),
+    };
+    let krate = ctx.sema.scope(call.syntax())?.krate();
+    let is_std = FamousDefs(&ctx.sema, krate)
+        .builtin_crates()
+        .any(|it| it == trait_.module(ctx.db()).krate());
+    Some(is_std && trait_.name(ctx.db()).to_smol_str() == "Iterator")
+}
+
+fn is_mutable_use(ctx: &AssistContext<'_>, name_ref: &SyntaxNode) -> bool {
+    let expr = match name_ref.ancestors().find_map(ast::PathExpr::cast) {
+        Some(it) => ast::Expr::PathExpr(it),
+        None => return true,
+    };
+    match expr.syntax().parent() {
+        Some(parent) => {
+            ast::MethodCallExpr::cast(parent.clone()).map_or(false, |call| {
+                let self_param =
+                    ctx.sema.resolve_method_call(&call).and_then(|it| it.self_param(ctx.db()));
+                self_param.map_or(true, |it| matches!(it.access(ctx.db()), hir::Access::Exclusive))
+            }) || ast::FieldExpr::can_cast(parent.kind())
+                || ast::IndexExpr::can_cast(parent.kind())
+                || ast::RefExpr::cast(parent.clone()).map_or(false, |it| it.mut_token().is_some())
+                || ast::BinExpr::cast(parent).map_or(false, |it| {
+                    matches!(it.op_kind(), Some(ast::BinaryOp::Assignment { .. }))
+                        && it.lhs().as_ref() == Some(&expr)
+                })
+        }
+        None => false,
+    }
+}
+
+fn iterable_text(ctx: &AssistContext<'_>, iterable: &ast::Expr) -> String {
+    // Ranges that can be looped over are iterators.
+    let is_iterator = matches!(iterable, ast::Expr::RangeExpr(_))
+        || (|| {
+            let ty = ctx.sema.type_of_expr(iterable)?.adjusted();
+            let krate = ctx.sema.scope(iterable.syntax())?.krate();
+            let iter_trait = FamousDefs(&ctx.sema, krate).core_iter_Iterator()?;
+            Some(ty.impls_trait(ctx.db(), iter_trait, &[]))
+        })()
+        .unwrap_or(false);
+    let needs_parens = !matches!(
+        iterable,
+        ast::Expr::PathExpr(_)
+            | ast::Expr::CallExpr(_)
+            | ast::Expr::MethodCallExpr(_)
+            | ast::Expr::FieldExpr(_)
+            | ast::Expr::IndexExpr(_)
+            | ast::Expr::ParenExpr(_)
+            | ast::Expr::MacroExpr(_)
+            | ast::Expr::ArrayExpr(_)
+            | ast::Expr::TupleExpr(_)
+    );
+    match (is_iterator, needs_parens) {
+        (true, false) => iterable.to_string(),
+        (true, true) => format!("({iterable})"),
+        (false, false) => format!("{iterable}.into_iter()"),
+        (false, true) => format!("({iterable}).into_iter()"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn convert_iterator_loop() {
+        check_assist(
+            convert_push_loop_to_collect,
+            r#"
+//- minicore: iterators, vec
+fn main() {
+    let it = core::iter::repeat(1);
+    let mut v: Vec<u32> = Vec::new();
+    f$0or x in it {
+        v.push(x)
+    }
+    v.push(0);
+}
+"#,
+            r#"
+fn main() {
+    let it = core::iter::repeat(1);
+    let mut v: Vec<u32> = it.collect();
+    v.push(0);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_loop_over_reference() {
+        check_assist(
+            convert_push_loop_to_collect,
+            r#"
+//- minicore: iterators, vec
+fn foo(xs: &[(u32, u32)]) {
+    let mut v = Vec::new();
+    $0for (a, b) in xs {
+        v.push(a + b);
+    }
+}
+"#,
+            r#"
+fn foo(xs: &[(u32, u32)]) {
+    let v: Vec<_> = xs.into_iter().map(|(a, b)| a + b).collect();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_push_methods() {
+        check_assist_not_applicable(
+            convert_push_loop_to_collect,
+            r#"
+struct Vec<T>(T);
+impl<T> Vec<T> {
+    fn new() -> Self { loop {} }
+    fn push(&mut self, value: T) {}
+}
+fn main() {
+    let mut v = Vec::new();
+    $0for x in 0..10 {
+        v.push(x);
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_used_in_loop() {
+        cov_mark::check!(convert_push_loop_to_collect_used_in_loop);
+        check_assist_not_applicable(
+            convert_push_loop_to_collect,
+            r#"
+//- minicore: iterators, vec
+fn main() {
+    let mut v = Vec::new();
+    $0for x in 0..10 {
+        v.push(v.len());
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_control_flow() {
+        cov_mark::check!(convert_push_loop_to_collect_control_flow);
+        check_assist_not_applicable(
+            convert_push_loop_to_collect,
+            r#"
+//- minicore: iterators, vec
+fn main() -> Option<()> {
+    let mut v = Vec::new();
+    $0for x in 0..10 {
+        v.push(foo(x)?);
+    }
+    None
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_collect_with_annotation() {
+        check_assist(
+            convert_collect_to_push_loop,
+            r#"
+//- minicore: iterators, vec
+fn foo(xs: impl Iterator<Item = (u8, u8)>) {
+    if true {
+        let v: Vec<u8> = (xs).map(|(a, b)| {
+            let c = a + b;
+            c * 2
+        }).coll$0ect::<Vec<u8>>();
+    }
+}
+"#,
+            r#"
+fn foo(xs: impl Iterator<Item = (u8, u8)>) {
+    if true {
+        let mut v: Vec<u8> = Vec::new();
+        for (a, b) in xs {
+            v.push({
+                let c = a + b;
+                c * 2
+            });
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn collect_not_applicable() {
+        // Not mapped.
+        check_assist_not_applicable(
+            convert_collect_to_push_loop,
+            r#"
+//- minicore: iterators, vec
+fn foo(xs: impl Iterator<Item = u8>) {
+    let v: Vec<_> = $0xs.collect();
+}
+"#,
+        );
+        // Not the standard `Iterator`.
+        check_assist_not_applicable(
+            convert_collect_to_push_loop,
+            r#"
+//- minicore: iterators, vec
+struct S;
+impl S {
+    fn map<F>(self, f: F) -> S { S }
+    fn collect(self) -> Vec<u8> { loop {} }
+}
+fn foo(s: S) {
+    let v = $0s.map(|x| x).collect();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn collect_not_applicable_with_control_flow() {
+        cov_mark::check!(convert_collect_to_push_loop_control_flow);
+        check_assist_not_applicable(
+            convert_collect_to_push_loop,
+            r#"
+//- minicore: iterators, vec
+fn foo(xs: impl Iterator<Item = u8>) -> Option<()> {
+    let v: Vec<_> = $0xs.map(|x| bar(x)?).collect();
+    None
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_loop_only_read_after() {
+        check_assist(
+            convert_push_loop_to_collect,
+            r#"
+//- minicore: iterators, vec
+fn main() -> usize {
+    let mut v = Vec::new();
+    $0for x in 0..10 {
+        v.push(x);
+    }
+    v.len()
+}
+"#,
+            r#"
+fn main() -> usize {
+    let v: Vec<_> = (0..10).collect();
+    v.len()
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use ide_db::famous_defs::FamousDefs;
+use syntax::ast::{self, AstNode, AstToken, IsString};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_string_concat_to_format
+//
+// Converts a chain of string additions into a `format!` call.
+//
+// ```
+// # //- minicore: add, string
+// fn greet(greeting: String, name: &str) -> String {
+//     greeting +$0 ", " + name + "!"
+// }
+// ```
+// ->
+// ```
+// fn greet(greeting: String, name: &str) -> String {
+//     format!("{}, {}!", greeting, name)
+// }
+// ```
+pub(crate) fn convert_string_concat_to_format(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let mut chain = ctx.find_node_at_offset::<ast::BinExpr>()?;
+    if !is_add(&chain) || !chain.op_token()?.text_range().contains_range(ctx.selection_trimmed()) {
+        return None;
+    }
+    while let Some(parent) = chain.syntax().parent().and_then(ast::BinExpr::cast) {
+        if !is_add(&parent) || parent.lhs()?.syntax() != chain.syntax() {
+            break;
+        }
+        chain = parent;
+    }
+
+    let mut operands = Vec::new();
+    let mut lhs = ast::Expr::BinExpr(chain.clone());
+    loop {
+        match lhs {
+            ast::Expr::BinExpr(bin_expr) if is_add(&bin_expr) => {
+                operands.push(bin_expr.rhs()?);
+                lhs = bin_expr.lhs()?;
+            }
+            _ => {
+                operands.push(lhs);
+                break;
+            }
+        }
+    }
+    operands.reverse();
+
+    // Only `String + &str` chains are rewritten, numbers and other types with an `Add` impl
+    // could well mean something else.
+    let krate = ctx.sema.scope(chain.syntax())?.krate();
+    let string = FamousDefs(&ctx.sema, krate).alloc_string_String().map(hir::Adt::Struct);
+    let is_string = |expr: &ast::Expr| {
+        ctx.sema.type_of_expr(expr).map_or(false, |it| {
+            string.is_some() && it.original.strip_references().as_adt() == string
+        })
+    };
+    let is_str = |expr: &ast::Expr| {
+        ctx.sema.type_of_expr(expr).map_or(false, |it| {
+            it.original.strip_references().as_builtin().map_or(false, |it| it.is_str())
+        })
+    };
+    if !is_string(&operands[0]) || !operands[1..].iter().all(|it| is_str(it) || is_string(it)) {
+        cov_mark::hit!(convert_string_concat_to_format_not_strings);
+        return None;
+    }
+
+    let target = chain.syntax().text_range();
+    acc.add(
+        AssistId("convert_string_concat_to_format", AssistKind::RefactorRewrite),
+        "Convert to `format!`",
+        target,
+        |builder| {
+            let mut format_string = String::new();
+            let mut args = String::new();
+            for operand in &operands {
+                match string_literal_contents(operand) {
+                    Some(text) => {
+                        format_string.push_str(&text.replace('{', "{{").replace('}', "}}"))
+                    }
+                    None => {
+                        format_string.push_str("{}");
+                        args.push_str(&format!(", {operand}"));
+                    }
+                }
+            }
+            builder.replace(target, format!("format!(\"{format_string}\"{args})"));
+        },
+    )
+}
+
+fn is_add(expr: &ast::BinExpr) -> bool {
+    expr.op_kind() == Some(ast::BinaryOp::ArithOp(ast::ArithOp::Add))
+}
+
+/// Returns the escaped text of a string literal, if it can be put into a format string as is.
+fn string_literal_contents(expr: &ast::Expr) -> Option<String> {
+    let literal = match expr {
+        ast::Expr::Literal(it) => it,
+        _ => return None,
+    };
+    let string = match literal.kind() {
+        ast::LiteralKind::String(it) if !it.is_raw() => it,
+        _ => return None,
+    };
+    let range = string.text_range_between_quotes()? - string.syntax().text_range().start();
+    let text = &string.text()[range];
+    // The braces of unicode escapes would be escaped as well.
+    if text.contains("\\u{") {
+        cov_mark::hit!(convert_string_concat_to_format_unicode_escape);
+        return None;
+    }
+    Some(text.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn convert_chain() {
+        check_assist(
+            convert_string_concat_to_format,
+            r#"
+//- minicore: add, string
+fn f(a: String, b: &str) -> String {
+    a + "x" +$0 b
+}
+"#,
+            r#"
+fn f(a: String, b: &str) -> String {
+    format!("{}x{}", a, b)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_escapes_braces() {
+        check_assist(
+            convert_string_concat_to_format,
+            r#"
+//- minicore: add, string
+fn f(a: String, b: &String) -> String {
+    a $0+ "{\"b\":" + &b + "}"
+}
+"#,
+            r#"
+fn f(a: String, b: &String) -> String {
+    format!("{}{{\"b\":{}}}", a, &b)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_keeps_raw_strings_as_args() {
+        check_assist(
+            convert_string_concat_to_format,
+            r#"
+//- minicore: add, string
+fn f(a: String) -> String {
+    a +$0 r"\n"
+}
+"#,
+            r#"
+fn f(a: String) -> String {
+    format!("{}{}", a, r"\n")
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_only_the_chain() {
+        check_assist(
+            convert_string_concat_to_format,
+            r#"
+//- minicore: add, string
+fn f(a: String, b: &str) -> usize {
+    g(a +$0 b)
+}
+fn g(s: String) -> usize { 0 }
+"#,
+            r#"
+fn f(a: String, b: &str) -> usize {
+    g(format!("{}{}", a, b))
+}
+fn g(s: String) -> usize { 0 }
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_numbers() {
+        cov_mark::check!(convert_string_concat_to_format_not_strings);
+        check_assist_not_applicable(
+            convert_string_concat_to_format,
+            r#"
+fn f(a: u32) -> u32 {
+    a +$0 1 + 2
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_string_types() {
+        cov_mark::check!(convert_string_concat_to_format_not_strings);
+        check_assist_not_applicable(
+            convert_string_concat_to_format,
+            r#"
+//- minicore: add
+struct String;
+impl core::ops::Add<&str> for String {
+    type Output = String;
+    fn add(self, _: &str) -> String { self }
+}
+fn f(a: String, b: &str) -> String {
+    a +$0 b
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_outside_operator() {
+        check_assist_not_applicable(
+            convert_string_concat_to_format,
+            r#"
+//- minicore: add, string
+fn f(a: String, b: &str) -> String {
+    a$0 + b
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_keeps_unicode_escapes_as_args() {
+        cov_mark::check!(convert_string_concat_to_format_unicode_escape);
+        check_assist(
+            convert_string_concat_to_format,
+            r#"
+//- minicore: add, string
+fn f(a: String) -> String {
+    a +$0 "\u{7b}" + "}"
+}
+"#,
+            r#"
+fn f(a: String) -> String {
+    format!("{}{}}}", a, "\u{7b}")
+}
+"#,
+        );
+    }
+}
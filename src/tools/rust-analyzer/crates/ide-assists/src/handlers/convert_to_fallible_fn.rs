@@ -0,0 +1,394 @@
+use hir::HirDisplay;
+use ide_db::{
+    famous_defs::FamousDefs,
+    syntax_helpers::node_ext::{for_each_tail_expr, preorder_expr},
+};
+use syntax::{
+    ast::{self, AstNode, HasName},
+    TextRange, WalkEvent,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_to_fallible_fn
+//
+// Makes a function return `Result` and propagates the errors of its `unwrap()` and `expect()`
+// calls with `?`. When all of them have the same error type, it becomes the error type of the
+// function, otherwise `Box<dyn std::error::Error>` is used.
+//
+// ```
+// # //- minicore: result
+// fn parse(s: &str) -> Result<u32, ()> { Ok(0) }
+// fn $0sum(a: &str, b: &str) -> u32 {
+//     parse(a).unwrap() + parse(b).expect("not a number")
+// }
+// ```
+// ->
+// ```
+// fn parse(s: &str) -> Result<u32, ()> { Ok(0) }
+// fn sum(a: &str, b: &str) -> Result<u32, ()> {
+//     Ok(parse(a)? + parse(b)?)
+// }
+// ```
+pub(crate) fn convert_to_fallible_fn(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let fn_ = ctx.find_node_at_offset::<ast::Fn>()?;
+    let body = fn_.body()?;
+    if body.syntax().text_range().contains_inclusive(ctx.offset()) {
+        return None;
+    }
+    let krate = ctx.sema.scope(fn_.syntax())?.krate();
+    let result_enum = FamousDefs(&ctx.sema, krate).core_result_Result()?;
+    let is_result = |ty: &hir::Type| ty.as_adt() == Some(hir::Adt::Enum(result_enum));
+
+    let ret_ty = ctx.sema.to_def(&fn_)?.ret_type(ctx.db());
+    if is_result(&ret_ty) {
+        cov_mark::hit!(convert_to_fallible_fn_already_result);
+        return None;
+    }
+
+    let mut unwraps = Vec::new();
+    let mut returns = Vec::new();
+    let body_expr = ast::Expr::BlockExpr(body.clone());
+    preorder_expr(&body_expr, &mut |ev| {
+        let expr = match ev {
+            WalkEvent::Enter(it) => it,
+            WalkEvent::Leave(_) => return false,
+        };
+        match expr {
+            // `?` in closures and async, const or try blocks doesn't return from the function.
+            ast::Expr::ClosureExpr(_) => true,
+            ast::Expr::BlockExpr(block) => matches!(
+                block.modifier(),
+                Some(
+                    ast::BlockModifier::Async(_)
+                        | ast::BlockModifier::Const(_)
+                        | ast::BlockModifier::Try(_)
+                )
+            ),
+            ast::Expr::MethodCallExpr(call) => {
+                let is_unwrap = call
+                    .name_ref()
+                    .map_or(false, |it| matches!(it.text().as_str(), "unwrap" | "expect"));
+                let receiver_ty =
+                    call.receiver().and_then(|it| ctx.sema.type_of_expr(&it)).map(|it| it.original);
+                if let Some(ty) = receiver_ty.filter(|ty| is_unwrap && is_result(ty)) {
+                    unwraps.push((call, ty));
+                }
+                false
+            }
+            ast::Expr::ReturnExpr(it) => {
+                returns.push(it);
+                false
+            }
+            _ => false,
+        }
+    });
+    if unwraps.is_empty() {
+        return None;
+    }
+
+    let module = ctx.sema.scope(fn_.syntax())?.module();
+    let err_tys: Vec<_> = unwraps.iter().map(|(_, ty)| ty.type_arguments().nth(1)).collect();
+    let err_ty = match err_tys[0]
+        .clone()
+        .filter(|it| !it.contains_unknown() && err_tys.iter().all(|ty| ty.as_ref() == Some(it)))
+        .and_then(|it| it.display_source_code(ctx.db(), module.into()).ok())
+    {
+        Some(it) => it,
+        None => {
+            // `?` can only convert the errors into a `Box<dyn Error>` if they implement `Error`.
+            let error_trait = FamousDefs(&ctx.sema, krate).core_error_Error()?;
+            let all_impl_error = err_tys.iter().all(|ty| {
+                ty.as_ref().map_or(false, |ty| ty.impls_trait(ctx.db(), error_trait, &[]))
+            });
+            if !all_impl_error {
+                cov_mark::hit!(convert_to_fallible_fn_no_error_impl);
+                return None;
+            }
+            cov_mark::hit!(convert_to_fallible_fn_boxed_error);
+            "Box<dyn std::error::Error>".to_owned()
+        }
+    };
+
+    let target = fn_.name().map_or(fn_.syntax().text_range(), |it| it.syntax().text_range());
+    acc.add(
+        AssistId("convert_to_fallible_fn", AssistKind::RefactorRewrite),
+        "Convert to fallible function",
+        target,
+        |builder| {
+            for (call, _) in &unwraps {
+                if let Some(receiver) = call.receiver() {
+                    let range = TextRange::new(
+                        receiver.syntax().text_range().end(),
+                        call.syntax().text_range().end(),
+                    );
+                    builder.replace(range, "?");
+                }
+            }
+
+            let mut tails = Vec::new();
+            let tail_cb = &mut |e: &ast::Expr| tail_cb_impl(&mut tails, e);
+            for ret in &returns {
+                match ret.expr() {
+                    Some(expr) => for_each_tail_expr(&expr, tail_cb),
+                    None => builder.insert(ret.syntax().text_range().end(), " Ok(())"),
+                }
+            }
+            // A tail whose value isn't the function's (e.g. one that diverges) is left alone.
+            let returns_value = |expr: &ast::Expr| {
+                ctx.sema.type_of_expr(expr).map_or(false, |it| it.original == ret_ty)
+            };
+            match body.tail_expr() {
+                Some(tail) if returns_value(&tail) => for_each_tail_expr(&body_expr, tail_cb),
+                Some(tail) if ret_ty.is_unit() => {
+                    // The value of the tail is discarded, so it becomes a statement.
+                    let indent = ast::edit::IndentLevel::from_node(tail.syntax());
+                    builder.insert(tail.syntax().text_range().end(), format!(";\n{indent}Ok(())"));
+                }
+                Some(_) => {}
+                None if ret_ty.is_unit() => {
+                    let stmt_list = body.stmt_list();
+                    let indent = stmt_list
+                        .as_ref()
+                        .and_then(|it| it.statements().last())
+                        .map(|it| ast::edit::IndentLevel::from_node(it.syntax()));
+                    let r_curly = stmt_list.and_then(|it| it.r_curly_token());
+                    if let (Some(indent), Some(r_curly)) = (indent, r_curly) {
+                        let offset = r_curly
+                            .prev_token()
+                            .filter(|it| it.kind().is_trivia())
+                            .map_or(r_curly.text_range().start(), |it| it.text_range().start());
+                        builder.insert(offset, format!("\n{indent}Ok(())"));
+                    }
+                }
+                None => {}
+            }
+            // The tails may contain unwraps themselves, so they're wrapped around instead of
+            // being replaced.
+            for tail in tails.into_iter().filter(returns_value) {
+                builder.insert(tail.syntax().text_range().start(), "Ok(");
+                builder.insert(tail.syntax().text_range().end(), ")");
+            }
+
+            match fn_.ret_type().and_then(|it| it.ty()) {
+                Some(ty) => {
+                    builder.replace(ty.syntax().text_range(), format!("Result<{ty}, {err_ty}>"))
+                }
+                None => builder.insert(
+                    fn_.param_list().map_or(body.syntax().text_range().start(), |it| {
+                        it.syntax().text_range().end()
+                    }),
+                    format!(" -> Result<(), {err_ty}>"),
+                ),
+            }
+        },
+    )
+}
+
+fn tail_cb_impl(acc: &mut Vec<ast::Expr>, e: &ast::Expr) {
+    match e {
+        ast::Expr::BreakExpr(break_expr) => {
+            if let Some(break_expr_arg) = break_expr.expr() {
+                for_each_tail_expr(&break_expr_arg, &mut |e| tail_cb_impl(acc, e))
+            }
+        }
+        // Returns are handled separately.
+        ast::Expr::ReturnExpr(_) => {}
+        e => acc.push(e.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn convert_with_common_error_type() {
+        check_assist(
+            convert_to_fallible_fn,
+            r#"
+//- minicore: result
+struct ParseError;
+fn parse(s: &str) -> Result<u32, ParseError> { Ok(0) }
+fn $0sum(a: &str, b: &str) -> u32 {
+    if a.is_empty() {
+        return parse(b).unwrap();
+    }
+    let a = parse(a).unwrap();
+    a + parse(b).expect("not a number")
+}
+"#,
+            r#"
+struct ParseError;
+fn parse(s: &str) -> Result<u32, ParseError> { Ok(0) }
+fn sum(a: &str, b: &str) -> Result<u32, ParseError> {
+    if a.is_empty() {
+        return Ok(parse(b)?);
+    }
+    let a = parse(a)?;
+    Ok(a + parse(b)?)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_with_boxed_error() {
+        cov_mark::check!(convert_to_fallible_fn_boxed_error);
+        check_assist(
+            convert_to_fallible_fn,
+            r#"
+//- minicore: result, error
+#[derive(Debug)]
+struct E1;
+#[derive(Debug)]
+struct E2;
+impl core::fmt::Display for E1 {}
+impl core::fmt::Display for E2 {}
+impl core::error::Error for E1 {}
+impl core::error::Error for E2 {}
+fn foo() -> Result<u32, E1> { Ok(0) }
+fn bar() -> Result<u32, E2> { Ok(0) }
+fn main$0() {
+    if foo().unwrap() == 0 {
+        return;
+    }
+    bar().unwrap();
+}
+"#,
+            r#"
+#[derive(Debug)]
+struct E1;
+#[derive(Debug)]
+struct E2;
+impl core::fmt::Display for E1 {}
+impl core::fmt::Display for E2 {}
+impl core::error::Error for E1 {}
+impl core::error::Error for E2 {}
+fn foo() -> Result<u32, E1> { Ok(0) }
+fn bar() -> Result<u32, E2> { Ok(0) }
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    if foo()? == 0 {
+        return Ok(());
+    }
+    bar()?;
+    Ok(())
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_skips_closures_and_options() {
+        check_assist(
+            convert_to_fallible_fn,
+            r#"
+//- minicore: result, option
+fn foo() -> Result<u32, ()> { Ok(0) }
+fn $0bar(o: Option<u32>) -> u32 {
+    let f = || foo().unwrap();
+    o.unwrap() + foo().unwrap()
+}
+"#,
+            r#"
+fn foo() -> Result<u32, ()> { Ok(0) }
+fn bar(o: Option<u32>) -> Result<u32, ()> {
+    let f = || foo().unwrap();
+    Ok(o.unwrap() + foo()?)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_result_fn() {
+        cov_mark::check!(convert_to_fallible_fn_already_result);
+        check_assist_not_applicable(
+            convert_to_fallible_fn,
+            r#"
+//- minicore: result
+fn foo() -> Result<u32, ()> { Ok(0) }
+fn $0bar() -> Result<u32, ()> {
+    Ok(foo().unwrap())
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_without_unwraps() {
+        check_assist_not_applicable(
+            convert_to_fallible_fn,
+            r#"
+//- minicore: result, option
+fn $0bar(o: Option<u32>) -> u32 {
+    o.unwrap()
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_errors_without_error_impl() {
+        cov_mark::check!(convert_to_fallible_fn_no_error_impl);
+        check_assist_not_applicable(
+            convert_to_fallible_fn,
+            r#"
+//- minicore: result, error
+struct E1;
+struct E2;
+fn foo() -> Result<u32, E1> { Ok(0) }
+fn bar() -> Result<u32, E2> { Ok(0) }
+fn main$0() {
+    foo().unwrap();
+    bar().unwrap();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_discards_tail_of_unit_fn() {
+        check_assist(
+            convert_to_fallible_fn,
+            r#"
+//- minicore: result
+fn foo() -> Result<u32, ()> { Ok(0) }
+fn $0bar() {
+    foo().unwrap()
+}
+"#,
+            r#"
+fn foo() -> Result<u32, ()> { Ok(0) }
+fn bar() -> Result<(), ()> {
+    foo()?;
+    Ok(())
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_leaves_diverging_tail() {
+        check_assist(
+            convert_to_fallible_fn,
+            r#"
+//- minicore: result
+fn foo() -> Result<u32, ()> { Ok(0) }
+fn $0bar() -> u32 {
+    let a = foo().unwrap();
+    loop {}
+}
+"#,
+            r#"
+fn foo() -> Result<u32, ()> { Ok(0) }
+fn bar() -> Result<u32, ()> {
+    let a = foo()?;
+    loop {}
+}
+"#,
+        );
+    }
+}
@@ -51,22 +51,7 @@ pub(crate) fn convert_to_guarded_return(acc: &mut Assists, ctx: &AssistContext<'
     // Check if there is an IfLet that we can handle.
     let (if_let_pat, cond_expr) = if is_pattern_cond(cond.clone()) {
         let let_ = single_let(cond)?;
-        match let_.pat() {
-            Some(ast::Pat::TupleStructPat(pat)) if pat.fields().count() == 1 => {
-                let path = pat.path()?;
-                if path.qualifier().is_some() {
-                    return None;
-                }
-
-                let bound_ident = pat.fields().next().unwrap();
-                if !ast::IdentPat::can_cast(bound_ident.syntax().kind()) {
-                    return None;
-                }
-
-                (Some((path, bound_ident)), let_.expr()?)
-            }
-            _ => return None, // Unsupported IfLet.
-        }
+        (Some(let_.pat()?), let_.expr()?)
     } else {
         (None, cond)
     };
@@ -127,11 +112,10 @@ pub(crate) fn convert_to_guarded_return(acc: &mut Assists, ctx: &AssistContext<'
                     };
                     new_expr.syntax().clone_for_update()
                 }
-                Some((path, bound_ident)) => {
+                Some(pat) => {
                     // If-let.
-                    let pat = make::tuple_struct_pat(path, once(bound_ident));
                     let let_else_stmt = make::let_else_stmt(
-                        pat.into(),
+                        pat,
                         None,
                         cond_expr,
                         ast::make::tail_only_block_expr(early_expression),
@@ -335,6 +319,50 @@ fn main(n: Option<&str>) {
         );
     }
 
+    #[test]
+    fn convert_let_qualified_multi_field_pat_inside_fn() {
+        check_assist(
+            convert_to_guarded_return,
+            r#"
+enum E { A(i32, i32), B }
+fn main(e: E) {
+    if$0 let E::A(x, y) = e {
+        foo(x, y);
+    }
+}
+"#,
+            r#"
+enum E { A(i32, i32), B }
+fn main(e: E) {
+    let E::A(x, y) = e else { return };
+    foo(x, y);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_let_record_pat_inside_fn() {
+        check_assist(
+            convert_to_guarded_return,
+            r#"
+enum E { A { x: i32 }, B }
+fn main(e: E) {
+    if$0 let E::A { x } = e {
+        foo(x);
+    }
+}
+"#,
+            r#"
+enum E { A { x: i32 }, B }
+fn main(e: E) {
+    let E::A { x } = e else { return };
+    foo(x);
+}
+"#,
+        );
+    }
+
     #[test]
     fn convert_inside_while() {
         check_assist(
@@ -5,7 +5,10 @@
     match_ast, SyntaxNode,
 };
 
-use crate::{assist_context::SourceChangeBuilder, AssistContext, AssistId, AssistKind, Assists};
+use crate::{
+    assist_context::SourceChangeBuilder, utils::copy_attrs, AssistContext, AssistId, AssistKind,
+    Assists,
+};
 
 // Assist: convert_tuple_struct_to_named_struct
 //
@@ -85,10 +88,11 @@ fn edit_struct_def(
     tuple_fields: ast::TupleFieldList,
     names: Vec<ast::Name>,
 ) {
-    let record_fields = tuple_fields
-        .fields()
-        .zip(names)
-        .filter_map(|(f, name)| Some(ast::make::record_field(f.visibility(), name, f.ty()?)));
+    let record_fields = tuple_fields.fields().zip(names).filter_map(|(f, name)| {
+        let field = ast::make::record_field(f.visibility(), name, f.ty()?).clone_for_update();
+        copy_attrs(&f, field.syntax());
+        Some(field)
+    });
     let record_fields = ast::make::record_field_list(record_fields);
     let tuple_fields_text_range = tuple_fields.syntax().text_range();
 
@@ -426,6 +430,40 @@ fn into_second(self) -> u64 {
         );
     }
 
+    #[test]
+    fn convert_generic_struct() {
+        check_assist(
+            convert_tuple_struct_to_named_struct,
+            r#"
+struct Pair$0<T, U>(T, U);
+
+fn pair<T, U>(t: T, u: U) -> Pair<T, U> {
+    Pair(t, u)
+}
+"#,
+            r#"
+struct Pair<T, U> { field1: T, field2: U }
+
+fn pair<T, U>(t: T, u: U) -> Pair<T, U> {
+    Pair { field1: t, field2: u }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_struct_keeps_field_attributes() {
+        check_assist(
+            convert_tuple_struct_to_named_struct,
+            r#"
+struct A$0(#[allow(dead_code)] pub u32, u64);
+"#,
+            r#"
+struct A { #[allow(dead_code)] pub field1: u32, field2: u64 }
+"#,
+        );
+    }
+
     #[test]
     fn convert_struct_with_wrapped_references() {
         check_assist(
@@ -595,6 +633,27 @@ fn test() {
         );
     }
 
+    #[test]
+    fn convert_variant_keeps_attributes() {
+        check_assist(
+            convert_tuple_struct_to_named_struct,
+            r#"
+enum Enum {
+    /// Docs.
+    #[deprecated]
+    Variant$0(#[allow(dead_code)] usize, String),
+}
+"#,
+            r#"
+enum Enum {
+    /// Docs.
+    #[deprecated]
+    Variant { #[allow(dead_code)] field1: usize, field2: String },
+}
+"#,
+        );
+    }
+
     #[test]
     fn convert_simple_variant() {
         check_assist(
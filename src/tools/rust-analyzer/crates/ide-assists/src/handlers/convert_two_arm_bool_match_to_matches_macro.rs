@@ -1,4 +1,7 @@
-use syntax::ast::{self, AstNode};
+use syntax::{
+    ast::{self, AstNode},
+    TextRange,
+};
 
 use crate::{AssistContext, AssistId, AssistKind, Assists};
 
@@ -33,6 +36,12 @@ pub(crate) fn convert_two_arm_bool_match_to_matches_macro(
         cov_mark::hit!(non_two_arm_match);
         return None;
     }
+    // A guarded fallback arm makes the match non-exhaustive when the first arm is guarded as well,
+    // and changes its meaning otherwise.
+    if second_arm.guard().is_some() {
+        cov_mark::hit!(guarded_second_arm);
+        return None;
+    }
     let first_arm_expr = first_arm.expr();
     let second_arm_expr = second_arm.expr();
 
@@ -73,21 +82,98 @@ pub(crate) fn convert_two_arm_bool_match_to_matches_macro(
     )
 }
 
+// Assist: convert_if_let_bool_to_matches_macro
+//
+// Convert an `if let` that evaluates to a boolean into the equivalent matches! invocation.
+//
+// ```
+// fn main() {
+//     if let Some(val) = scrutinee$0 { true } else { false }
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     matches!(scrutinee, Some(val))
+// }
+// ```
+pub(crate) fn convert_if_let_bool_to_matches_macro(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let if_expr = ctx.find_node_at_offset::<ast::IfExpr>()?;
+    let then_branch = if_expr.then_branch()?;
+    let cursor_in_range = TextRange::new(
+        if_expr.syntax().text_range().start(),
+        then_branch.syntax().text_range().start(),
+    )
+    .contains_range(ctx.selection_trimmed());
+    if !cursor_in_range {
+        return None;
+    }
+    // `if let` chains can't be expressed with a single pattern.
+    let let_expr = match if_expr.condition()? {
+        ast::Expr::LetExpr(it) => it,
+        _ => return None,
+    };
+    let else_branch = match if_expr.else_branch()? {
+        ast::ElseBranch::Block(it) => it,
+        ast::ElseBranch::IfExpr(_) => return None,
+    };
+
+    let then_expr = Some(ast::Expr::BlockExpr(then_branch));
+    let else_expr = Some(ast::Expr::BlockExpr(else_branch));
+    let invert_matches = if is_bool_literal_expr(&then_expr, true)
+        && is_bool_literal_expr(&else_expr, false)
+    {
+        false
+    } else if is_bool_literal_expr(&then_expr, false) && is_bool_literal_expr(&else_expr, true) {
+        true
+    } else {
+        cov_mark::hit!(if_let_non_bool_literal_branches);
+        return None;
+    };
+
+    let target_range = ctx.sema.original_range(if_expr.syntax()).range;
+    let pat = let_expr.pat()?;
+    let expr = let_expr.expr()?;
+
+    acc.add(
+        AssistId("convert_if_let_bool_to_matches_macro", AssistKind::RefactorRewrite),
+        "Convert to matches!",
+        target_range,
+        |builder| {
+            let bang = if invert_matches { "!" } else { "" };
+            builder.replace(target_range, format!("{bang}matches!({expr}, {pat})"));
+        },
+    )
+}
+
+/// Checks that `expr` is the given bool literal, possibly wrapped in a block with no statements.
 fn is_bool_literal_expr(expr: &Option<ast::Expr>, expect_bool: bool) -> bool {
-    if let Some(ast::Expr::Literal(lit)) = expr {
-        if let ast::LiteralKind::Bool(b) = lit.kind() {
-            return b == expect_bool;
+    match expr {
+        Some(ast::Expr::Literal(lit)) => {
+            matches!(lit.kind(), ast::LiteralKind::Bool(b) if b == expect_bool)
+        }
+        Some(ast::Expr::BlockExpr(block)) if block.modifier().is_none() => {
+            match block.stmt_list() {
+                Some(stmt_list) if stmt_list.statements().next().is_none() => {
+                    is_bool_literal_expr(&stmt_list.tail_expr(), expect_bool)
+                }
+                _ => false,
+            }
         }
+        _ => false,
     }
-
-    return false;
 }
 
 #[cfg(test)]
 mod tests {
     use crate::tests::{check_assist, check_assist_not_applicable, check_assist_target};
 
-    use super::convert_two_arm_bool_match_to_matches_macro;
+    use super::{
+        convert_if_let_bool_to_matches_macro, convert_two_arm_bool_match_to_matches_macro,
+    };
 
     #[test]
     fn not_applicable_outside_of_range_left() {
@@ -291,4 +377,131 @@ fn main() {
     }",
         );
     }
+
+    #[test]
+    fn not_applicable_guarded_second_arm() {
+        cov_mark::check!(guarded_second_arm);
+        check_assist_not_applicable(
+            convert_two_arm_bool_match_to_matches_macro,
+            r#"
+fn foo(a: Option<u32>, b: bool) -> bool {
+    match a$0 {
+        Some(_) => true,
+        _ if b => false,
+    }
+}
+        "#,
+        );
+    }
+
+    #[test]
+    fn convert_block_arm_bodies() {
+        check_assist(
+            convert_two_arm_bool_match_to_matches_macro,
+            r#"
+fn foo(a: Option<u32>) -> bool {
+    match a$0 {
+        Some(_) => { true }
+        _ => { false }
+    }
+}
+"#,
+            r#"
+fn foo(a: Option<u32>) -> bool {
+    matches!(a, Some(_))
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_block_with_statements() {
+        cov_mark::check!(non_invert_bool_literal_arms);
+        check_assist_not_applicable(
+            convert_two_arm_bool_match_to_matches_macro,
+            r#"
+fn foo(a: Option<u32>) -> bool {
+    match a$0 {
+        Some(_) => { bar(); true }
+        _ => false,
+    }
+}
+        "#,
+        );
+    }
+
+    #[test]
+    fn convert_if_let() {
+        check_assist(
+            convert_if_let_bool_to_matches_macro,
+            r#"
+fn foo(a: Option<u32>) -> bool {
+    if let Some(3) = a$0 {
+        true
+    } else {
+        false
+    }
+}
+"#,
+            r#"
+fn foo(a: Option<u32>) -> bool {
+    matches!(a, Some(3))
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_if_let_inverted() {
+        check_assist(
+            convert_if_let_bool_to_matches_macro,
+            r#"
+fn foo(a: Option<u32>) -> bool {
+    i$0f let None = a { false } else { true }
+}
+"#,
+            r#"
+fn foo(a: Option<u32>) -> bool {
+    !matches!(a, None)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_if_let_non_bool_branches() {
+        cov_mark::check!(if_let_non_bool_literal_branches);
+        check_assist_not_applicable(
+            convert_if_let_bool_to_matches_macro,
+            r#"
+fn foo(a: Option<u32>) -> bool {
+    if let Some(v) = a$0 { v > 3 } else { false }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_if_without_let() {
+        check_assist_not_applicable(
+            convert_if_let_bool_to_matches_macro,
+            r#"
+fn foo(a: bool) -> bool {
+    if a$0 { true } else { false }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_if_let_in_branch() {
+        check_assist_not_applicable(
+            convert_if_let_bool_to_matches_macro,
+            r#"
+fn foo(a: Option<u32>) -> bool {
+    if let Some(_) = a { tr$0ue } else { false }
+}
+"#,
+        );
+    }
 }
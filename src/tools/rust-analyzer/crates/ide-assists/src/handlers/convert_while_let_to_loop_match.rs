@@ -0,0 +1,301 @@
+use std::iter::once;
+
+use syntax::{
+    ast::{
+        self,
+        edit::{AstNodeEdit, IndentLevel},
+        make, HasLoopBody,
+    },
+    AstNode, T,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_while_let_to_loop_match
+//
+// Replaces a `while let` with a `loop` around a `match`.
+//
+// ```
+// fn main() {
+//     $0while let Some(x) = it.next() {
+//         foo(x);
+//     }
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     loop {
+//         match it.next() {
+//             Some(x) => {
+//                 foo(x);
+//             }
+//             None => break,
+//         }
+//     }
+// }
+// ```
+pub(crate) fn convert_while_let_to_loop_match(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let while_kw = ctx.find_token_syntax_at_offset(T![while])?;
+    let while_expr = while_kw.parent().and_then(ast::WhileExpr::cast)?;
+    let let_expr = match while_expr.condition()? {
+        ast::Expr::LetExpr(it) => it,
+        _ => return None,
+    };
+    let pat = let_expr.pat()?;
+    let scrutinee = let_expr.expr()?;
+    let body = while_expr.loop_body()?;
+
+    let target = while_expr.syntax().text_range();
+    acc.add(
+        AssistId("convert_while_let_to_loop_match", AssistKind::RefactorRewrite),
+        "Convert to loop and match",
+        target,
+        |edit| {
+            let indent = IndentLevel::from_node(while_expr.syntax());
+            let break_arm =
+                make::match_arm(once(break_pat(&pat)), None, make::expr_break(None, None));
+            let body = body.reset_indent().indent(IndentLevel(1));
+            let body_arm = make::match_arm(once(pat), None, body.into());
+            let match_expr =
+                make::expr_match(scrutinee, make::match_arm_list([body_arm, break_arm]));
+            let block = make::block_expr(None, Some(match_expr.indent(IndentLevel(1))));
+            let loop_expr = make::expr_loop(block.indent(indent));
+            let replacement = match while_expr.label() {
+                Some(label) => format!("{label} {loop_expr}"),
+                None => loop_expr.to_string(),
+            };
+            edit.replace(target, replacement)
+        },
+    )
+}
+
+// Assist: convert_loop_match_to_while_let
+//
+// Replaces a `loop` around a `match` that breaks on its last arm with a `while let`.
+//
+// ```
+// fn main() {
+//     $0loop {
+//         match it.next() {
+//             Some(x) => foo(x),
+//             None => break,
+//         }
+//     }
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     while let Some(x) = it.next() {
+//         foo(x)
+//     }
+// }
+// ```
+pub(crate) fn convert_loop_match_to_while_let(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let loop_kw = ctx.find_token_syntax_at_offset(T![loop])?;
+    let loop_expr = loop_kw.parent().and_then(ast::LoopExpr::cast)?;
+    let stmt_list = loop_expr.loop_body()?.stmt_list()?;
+    let mut stmts = stmt_list.statements();
+    let match_expr = match (stmts.next(), stmts.next(), stmt_list.tail_expr()) {
+        (None, None, Some(ast::Expr::MatchExpr(it))) => it,
+        (Some(ast::Stmt::ExprStmt(stmt)), None, None) => match stmt.expr()? {
+            ast::Expr::MatchExpr(it) => it,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let scrutinee = match_expr.expr()?;
+
+    let mut arms = match_expr.match_arm_list()?.arms();
+    let (arm, break_arm) = match (arms.next(), arms.next(), arms.next()) {
+        (Some(arm), Some(break_arm), None) => (arm, break_arm),
+        _ => return None,
+    };
+    if arm.guard().is_some() || break_arm.guard().is_some() || !is_plain_break(&break_arm) {
+        return None;
+    }
+    let pat = arm.pat()?;
+    let body = match arm.expr()? {
+        ast::Expr::BlockExpr(it)
+            if it.syntax().first_token().map_or(false, |it| it.kind() == T!['{']) =>
+        {
+            it
+        }
+        it => make::block_expr(None, Some(it.reset_indent())),
+    };
+
+    let target = loop_expr.syntax().text_range();
+    acc.add(
+        AssistId("convert_loop_match_to_while_let", AssistKind::RefactorRewrite),
+        "Convert to while let",
+        target,
+        |edit| {
+            let indent = IndentLevel::from_node(loop_expr.syntax());
+            let condition = make::expr_let(pat, scrutinee);
+            let body = body.reset_indent().indent(indent);
+            let while_expr = make::expr_while_loop(condition.into(), body);
+            let replacement = match loop_expr.label() {
+                Some(label) => format!("{label} {while_expr}"),
+                None => while_expr.to_string(),
+            };
+            edit.replace(target, replacement)
+        },
+    )
+}
+
+/// Picks the pattern for the arm that ends the loop: `None` when matching on `Some`, and a
+/// wildcard otherwise.
+fn break_pat(pat: &ast::Pat) -> ast::Pat {
+    let is_some = match pat {
+        ast::Pat::TupleStructPat(it) => it.path().map_or(false, |it| it.to_string() == "Some"),
+        _ => false,
+    };
+    if is_some {
+        make::path_pat(make::ext::ident_path("None"))
+    } else {
+        make::wildcard_pat().into()
+    }
+}
+
+/// Checks whether `arm` is `_ => break` or `None => break`.
+fn is_plain_break(arm: &ast::MatchArm) -> bool {
+    let breaks = match arm.expr() {
+        Some(ast::Expr::BreakExpr(it)) => it.lifetime().is_none() && it.expr().is_none(),
+        _ => false,
+    };
+    let matches_rest = match arm.pat() {
+        Some(ast::Pat::WildcardPat(_)) => true,
+        Some(it) => it.syntax().text() == "None",
+        None => false,
+    };
+    breaks && matches_rest
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn convert_labeled_while_let() {
+        check_assist(
+            convert_while_let_to_loop_match,
+            r#"
+fn main() {
+    if cond {
+        'outer: while$0 let Ok(line) = read() {
+            if line.is_empty() {
+                continue;
+            }
+            process(line);
+        }
+    }
+}
+"#,
+            r#"
+fn main() {
+    if cond {
+        'outer: loop {
+            match read() {
+                Ok(line) => {
+                    if line.is_empty() {
+                        continue;
+                    }
+                    process(line);
+                }
+                _ => break,
+            }
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_plain_while() {
+        check_assist_not_applicable(convert_while_let_to_loop_match, "fn f() { while$0 cond {} }");
+        check_assist_not_applicable(
+            convert_while_let_to_loop_match,
+            "fn f() { while let Some(x) = it.next() { $0foo(x); } }",
+        );
+    }
+
+    #[test]
+    fn convert_loop_match_with_block_arm() {
+        check_assist(
+            convert_loop_match_to_while_let,
+            r#"
+fn main() {
+    'outer: loop$0 {
+        match stack.pop() {
+            Node { children, .. } => {
+                stack.extend(children);
+            }
+            _ => break,
+        };
+    }
+}
+"#,
+            r#"
+fn main() {
+    'outer: while let Node { children, .. } = stack.pop() {
+        stack.extend(children);
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_loops() {
+        check_assist_not_applicable(
+            convert_loop_match_to_while_let,
+            r#"
+fn main() {
+    loop$0 {
+        match it.next() {
+            Some(x) => foo(x),
+            None => break 'outer,
+        }
+    }
+}
+"#,
+        );
+        check_assist_not_applicable(
+            convert_loop_match_to_while_let,
+            r#"
+fn main() {
+    loop$0 {
+        match it.next() {
+            None => break,
+            Some(x) => foo(x),
+        }
+    }
+}
+"#,
+        );
+        check_assist_not_applicable(
+            convert_loop_match_to_while_let,
+            r#"
+fn main() {
+    loop$0 {
+        match it.next() {
+            Some(x) => foo(x),
+            None => break,
+        }
+        bar();
+    }
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use syntax::{
+    ast::{self, AstNode},
+    TextRange,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: expand_field_shorthand
+//
+// Expands field init shorthand into an explicit `field: value` pair. Collapsing it again is
+// the fix of the `use-field-shorthand` diagnostic.
+//
+// ```
+// struct S { x: i32, y: i32 }
+// fn main() {
+//     let (x, y) = (1, 2);
+//     let s = S$0 { x, y };
+// }
+// ```
+// ->
+// ```
+// struct S { x: i32, y: i32 }
+// fn main() {
+//     let (x, y) = (1, 2);
+//     let s = S { x: x, y: y };
+// }
+// ```
+pub(crate) fn expand_field_shorthand(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let (target, fields) = shorthand_fields_at_cursor(ctx)?;
+    acc.add(
+        AssistId("expand_field_shorthand", AssistKind::RefactorRewrite),
+        "Expand field shorthand",
+        target,
+        |builder| {
+            for field in fields {
+                if let (Some(name), Some(expr)) = (field.field_name(), field.expr()) {
+                    builder.insert(expr.syntax().text_range().start(), format!("{name}: "));
+                }
+            }
+        },
+    )
+}
+
+/// Returns the field under the cursor if it uses shorthand, or all fields of the literal under the
+/// cursor that do.
+fn shorthand_fields_at_cursor(
+    ctx: &AssistContext<'_>,
+) -> Option<(TextRange, Vec<ast::RecordExprField>)> {
+    let is_shorthand = |field: &ast::RecordExprField| field.name_ref().is_none();
+    if let Some(field) = ctx.find_node_at_offset::<ast::RecordExprField>() {
+        if !is_shorthand(&field) {
+            return None;
+        }
+        return Some((field.syntax().text_range(), vec![field]));
+    }
+
+    let record = ctx.find_node_at_offset::<ast::RecordExpr>()?;
+    let fields: Vec<_> = record.record_expr_field_list()?.fields().filter(is_shorthand).collect();
+    if fields.is_empty() {
+        cov_mark::hit!(field_shorthand_no_fields);
+        return None;
+    }
+    Some((record.syntax().text_range(), fields))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn expand_single_field() {
+        check_assist(
+            expand_field_shorthand,
+            r#"
+struct S { x: i32, y: i32 }
+fn f(x: i32, y: i32) -> S {
+    S { x, $0y }
+}
+"#,
+            r#"
+struct S { x: i32, y: i32 }
+fn f(x: i32, y: i32) -> S {
+    S { x, y: y }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn expand_whole_literal() {
+        check_assist(
+            expand_field_shorthand,
+            r#"
+struct S { x: i32, y: i32, z: i32 }
+fn f(x: i32, y: i32) -> S {
+    $0S { x, y, z: 0 }
+}
+"#,
+            r#"
+struct S { x: i32, y: i32, z: i32 }
+fn f(x: i32, y: i32) -> S {
+    S { x: x, y: y, z: 0 }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn expand_keeps_field_attributes() {
+        check_assist(
+            expand_field_shorthand,
+            r#"
+struct S { x: i32 }
+fn f(x: i32) -> S {
+    S { #[cfg(test)] x$0 }
+}
+"#,
+            r#"
+struct S { x: i32 }
+fn f(x: i32) -> S {
+    S { #[cfg(test)] x: x }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn expand_not_applicable_without_shorthand() {
+        cov_mark::check!(field_shorthand_no_fields);
+        check_assist_not_applicable(
+            expand_field_shorthand,
+            r#"
+struct S { x: i32 }
+fn f(x: i32) -> S {
+    $0S { x: x }
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,323 @@
+use std::iter;
+
+use hir::HirDisplay;
+use ide_db::assists::GroupLabel;
+use syntax::{
+    ast::{self, edit::IndentLevel, AstNode},
+    SyntaxNode,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: extract_constant
+//
+// Extracts a literal into a constant at module scope.
+//
+// ```
+// fn area(r: f64) -> f64 {
+//     3.14159$0 * r * r
+// }
+// ```
+// ->
+// ```
+// const $0VALUE: f64 = 3.14159;
+//
+// fn area(r: f64) -> f64 {
+//     VALUE * r * r
+// }
+// ```
+
+// Assist: extract_constant_all
+//
+// Extracts a literal into a constant at module scope, replacing all equal literals in the module.
+//
+// ```
+// fn area(r: f64) -> f64 {
+//     3.14159$0 * r * r
+// }
+// fn circumference(r: f64) -> f64 {
+//     2.0 * 3.14159 * r
+// }
+// ```
+// ->
+// ```
+// const $0VALUE: f64 = 3.14159;
+//
+// fn area(r: f64) -> f64 {
+//     VALUE * r * r
+// }
+// fn circumference(r: f64) -> f64 {
+//     2.0 * VALUE * r
+// }
+// ```
+pub(crate) fn extract_constant(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let literal = ctx.find_node_at_offset::<ast::Literal>()?;
+    let ty = literal_type(ctx, &literal)?;
+    let item = top_level_item(literal.syntax())?;
+    let module_items = item.syntax().parent()?;
+
+    let module = ctx.sema.scope(literal.syntax())?.module();
+    let ty_text = ty.display_source_code(ctx.db(), module.into()).ok()?;
+    // The constant doesn't resolve from nested modules.
+    let equal_literals: Vec<_> = module_items
+        .descendants()
+        .filter_map(ast::Literal::cast)
+        .filter(|it| {
+            !it.syntax()
+                .ancestors()
+                .take_while(|it| it != &module_items)
+                .any(|it| ast::Module::can_cast(it.kind()))
+        })
+        .filter(|it| it.token().text() == literal.token().text())
+        .filter(|it| literal_type(ctx, it).as_ref() == Some(&ty))
+        .collect();
+
+    let insert_pos = item.syntax().text_range().start();
+    let indent = IndentLevel::from_node(item.syntax());
+    let const_text = |cursor: &str, name: &str| {
+        format!("const {cursor}{name}: {ty_text} = {literal};\n\n{indent}")
+    };
+
+    let group = GroupLabel("Extract into constant".to_owned());
+    let mut add = |id: &'static str, label: &str, literals: &[ast::Literal]| {
+        acc.add_group(
+            &group,
+            AssistId(id, AssistKind::RefactorExtract),
+            label,
+            literal.syntax().text_range(),
+            |builder| {
+                let name = make_const_name(ctx, item.syntax(), literals);
+                for it in literals {
+                    builder.replace(it.syntax().text_range(), &name);
+                }
+                match ctx.config.snippet_cap {
+                    Some(cap) => builder.insert_snippet(cap, insert_pos, const_text("$0", &name)),
+                    None => builder.insert(insert_pos, const_text("", &name)),
+                }
+            },
+        )
+    };
+    add("extract_constant", "Extract into constant", &[literal.clone()]);
+    if equal_literals.len() < 2 {
+        return None;
+    }
+    add(
+        "extract_constant_all",
+        "Extract into constant, replacing all occurrences in the module",
+        &equal_literals,
+    )
+}
+
+/// Picks a name for the constant that nothing in scope of `item` or of `literals` already has.
+fn make_const_name(
+    ctx: &AssistContext<'_>,
+    item: &SyntaxNode,
+    literals: &[ast::Literal],
+) -> String {
+    let mut names_in_scope = vec![];
+    let nodes = iter::once(item).chain(literals.iter().map(|it| it.syntax()));
+    for scope in nodes.filter_map(|it| ctx.sema.scope(it)) {
+        scope.process_all_names(&mut |name, _| names_in_scope.push(name.to_string()));
+    }
+
+    let default_name = "VALUE";
+
+    let mut name = default_name.to_string();
+    let mut counter = 0;
+    while names_in_scope.contains(&name) {
+        counter += 1;
+        name = format!("{default_name}{counter}")
+    }
+    name
+}
+
+/// Returns the type of a literal that can be extracted, that is one evaluated in an expression
+/// which isn't already a constant.
+fn literal_type(ctx: &AssistContext<'_>, literal: &ast::Literal) -> Option<hir::Type> {
+    // `true` and `false` are hardly magic.
+    if matches!(literal.kind(), ast::LiteralKind::Bool(_)) {
+        return None;
+    }
+    // Literals in patterns, attributes and const generic arguments can't refer to a constant
+    // as written.
+    let parent = literal.syntax().parent()?;
+    if ast::LiteralPat::can_cast(parent.kind())
+        || ast::ConstArg::can_cast(parent.kind())
+        || literal.syntax().ancestors().any(|it| ast::Attr::can_cast(it.kind()))
+    {
+        return None;
+    }
+    let in_const_item = literal
+        .syntax()
+        .ancestors()
+        .find_map(ast::Item::cast)
+        .map_or(false, |it| matches!(it, ast::Item::Const(_) | ast::Item::Static(_)));
+    if in_const_item {
+        return None;
+    }
+    let ty = ctx.sema.type_of_expr(&ast::Expr::Literal(literal.clone()))?.original;
+    if ty.contains_unknown() {
+        return None;
+    }
+    Some(ty)
+}
+
+/// Finds the item directly in a module that contains `node`, before which the constant goes.
+fn top_level_item(node: &SyntaxNode) -> Option<ast::Item> {
+    node.ancestors().filter_map(ast::Item::cast).find(|it| {
+        it.syntax().parent().map_or(false, |parent| {
+            ast::SourceFile::can_cast(parent.kind()) || ast::ItemList::can_cast(parent.kind())
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_by_label, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn extract_in_nested_module_and_impl() {
+        check_assist_by_label(
+            extract_constant,
+            r#"
+mod m {
+    struct S;
+    impl S {
+        fn timeout(&self) -> u64 {
+            500$0
+        }
+        fn retry(&self) -> u64 {
+            500
+        }
+    }
+}
+"#,
+            r#"
+mod m {
+    struct S;
+    const $0VALUE: u64 = 500;
+
+    impl S {
+        fn timeout(&self) -> u64 {
+            VALUE
+        }
+        fn retry(&self) -> u64 {
+            500
+        }
+    }
+}
+"#,
+            "Extract into constant",
+        );
+    }
+
+    #[test]
+    fn extract_all_of_the_same_type() {
+        check_assist(
+            extract_constant,
+            r#"
+fn foo() -> (&'static str, u8) {
+    let name = "foo";
+    baz("foo$0");
+    (name, 1)
+}
+fn bar() -> u16 {
+    1
+}
+fn baz(_: &str) {}
+"#,
+            r#"
+const $0VALUE: &str = "foo";
+
+fn foo() -> (&'static str, u8) {
+    let name = VALUE;
+    baz(VALUE);
+    (name, 1)
+}
+fn bar() -> u16 {
+    1
+}
+fn baz(_: &str) {}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_patterns_or_constants() {
+        check_assist_not_applicable(
+            extract_constant,
+            r#"
+fn foo(x: u32) {
+    match x {
+        1$0 => (),
+        _ => (),
+    }
+}
+"#,
+        );
+        check_assist_not_applicable(extract_constant, "const X: u32 = 1$0;");
+        check_assist_not_applicable(extract_constant, "fn foo() -> bool { true$0 }");
+    }
+
+    #[test]
+    fn extract_with_fresh_name() {
+        check_assist_by_label(
+            extract_constant,
+            r#"
+const VALUE: u32 = 1;
+fn foo() -> u32 {
+    let VALUE1 = 2;
+    VALUE + VALUE1 + 3$0
+}
+"#,
+            r#"
+const VALUE: u32 = 1;
+const $0VALUE2: u32 = 3;
+
+fn foo() -> u32 {
+    let VALUE1 = 2;
+    VALUE + VALUE1 + VALUE2
+}
+"#,
+            "Extract into constant",
+        );
+    }
+
+    #[test]
+    fn extract_all_skips_nested_modules() {
+        check_assist_by_label(
+            extract_constant,
+            r#"
+fn foo() -> u32 {
+    7$0
+}
+fn bar() -> u32 {
+    7
+}
+mod m {
+    fn baz() -> u32 {
+        7
+    }
+}
+"#,
+            r#"
+const $0VALUE: u32 = 7;
+
+fn foo() -> u32 {
+    VALUE
+}
+fn bar() -> u32 {
+    VALUE
+}
+mod m {
+    fn baz() -> u32 {
+        7
+    }
+}
+"#,
+            "Extract into constant, replacing all occurrences in the module",
+        );
+    }
+}
@@ -1355,14 +1355,25 @@ fn make_call(ctx: &AssistContext<'_>, fun: &Function, indent: IndentLevel) -> St
     }
 
     format_to!(buf, "{expr}");
-    let insert_comma = fun
-        .body
-        .parent()
-        .and_then(ast::MatchArm::cast)
-        .map_or(false, |it| it.comma_token().is_none());
+    let parent = fun.body.parent();
+    let insert_comma =
+        parent.clone().and_then(ast::MatchArm::cast).map_or(false, |it| it.comma_token().is_none());
+    // The call replaces an initializer or an expression statement, which already ends in `;`.
+    let has_semicolon = parent.map_or(false, |it| {
+        match_ast! {
+            match it {
+                ast::LetStmt(it) => it.semicolon_token().is_some(),
+                ast::ExprStmt(it) => it.semicolon_token().is_some(),
+                _ => false,
+            }
+        }
+    });
     if insert_comma {
         buf.push(',');
-    } else if fun.ret_ty.is_unit() && (!fun.outliving_locals.is_empty() || !expr.is_block_like()) {
+    } else if fun.ret_ty.is_unit()
+        && !has_semicolon
+        && (!fun.outliving_locals.is_empty() || !expr.is_block_like())
+    {
         buf.push(';');
     }
     buf
@@ -2111,6 +2122,29 @@ fn $0fun_name() -> i32 {
         );
     }
 
+    #[test]
+    fn no_args_from_unit_expr_stmt() {
+        check_assist(
+            extract_function,
+            r#"
+fn bar() {}
+fn foo() {
+    $0bar()$0;
+}
+"#,
+            r#"
+fn bar() {}
+fn foo() {
+    fun_name();
+}
+
+fn $0fun_name() {
+    bar()
+}
+"#,
+        );
+    }
+
     #[test]
     fn no_args_from_stmt_with_last_expr() {
         check_assist(
@@ -5663,7 +5697,6 @@ fn $0fun_name() -> ControlFlow<()> {
 
     #[test]
     fn non_tail_expr_of_tail_if_block() {
-        // FIXME: double semicolon
         check_assist(
             extract_function,
             r#"
@@ -5696,7 +5729,7 @@ impl<T> core::ops::FromResidual for Option<T> {}
 
 fn f() -> Option<()> {
     if true {
-        let a = fun_name()?;;
+        let a = fun_name()?;
         Some(a)
     } else {
         None
@@ -1,11 +1,11 @@
 use either::Either;
-use ide_db::syntax_helpers::node_ext::walk_ty;
+use ide_db::{assists::GroupLabel, syntax_helpers::node_ext::walk_ty};
 use syntax::{
     ast::{self, edit::IndentLevel, make, AstNode, HasGenericParams, HasName},
-    match_ast,
+    match_ast, SyntaxNode,
 };
 
-use crate::{AssistContext, AssistId, AssistKind, Assists};
+use crate::{assist_context::SourceChangeBuilder, AssistContext, AssistId, AssistKind, Assists};
 
 // Assist: extract_type_alias
 //
@@ -25,11 +25,29 @@
 // }
 // ```
 pub(crate) fn extract_type_alias(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
-    if ctx.has_empty_selection() {
-        return None;
-    }
-
-    let ty = ctx.find_node_at_range::<ast::Type>()?;
+    let ty = if ctx.has_empty_selection() {
+        // Without a selection, only offer it for the whole of a type spelled out with generic
+        // arguments, as anything smaller isn't worth an alias.
+        let ty = ctx
+            .token_at_offset()
+            .right_biased()?
+            .parent_ancestors()
+            .take_while(|it| !ast::Item::can_cast(it.kind()))
+            .filter_map(ast::Type::cast)
+            .last()?;
+        let has_generic_args = match &ty {
+            ast::Type::PathType(it) => {
+                it.path()?.segments().any(|it| it.generic_arg_list().is_some())
+            }
+            _ => false,
+        };
+        if !has_generic_args {
+            return None;
+        }
+        ty
+    } else {
+        ctx.find_node_at_range::<ast::Type>()?
+    };
     let item = ty.syntax().ancestors().find_map(ast::Item::cast)?;
     let assoc_owner = item.syntax().ancestors().nth(2).and_then(|it| {
         match_ast! {
@@ -47,52 +65,107 @@ pub(crate) fn extract_type_alias(acc: &mut Assists, ctx: &AssistContext<'_>) ->
     let insert_pos = node.text_range().start();
     let target = ty.syntax().text_range();
 
-    acc.add(
+    let mut known_generics = match item.generic_param_list() {
+        Some(it) => it.generic_params().collect(),
+        None => Vec::new(),
+    };
+    if let Some(it) = assoc_owner.as_ref().and_then(|it| match it {
+        Either::Left(it) => it.generic_param_list(),
+        Either::Right(it) => it.generic_param_list(),
+    }) {
+        known_generics.extend(it.generic_params());
+    }
+    let generic_params = collect_used_generics(&ty, &known_generics)
+        .map(|it| make::generic_param_list(it.into_iter().cloned()));
+    let ty_args =
+        generic_params.as_ref().map_or(String::new(), |it| it.to_generic_args().to_string());
+    let replacement = format!("Type{ty_args}");
+
+    let indent = IndentLevel::from_node(node);
+    let generic_params = generic_params.map(|it| it.to_string());
+    let insert_alias = |builder: &mut SourceChangeBuilder| {
+        let generic_params = generic_params.as_deref().unwrap_or_default();
+        match ctx.config.snippet_cap {
+            Some(cap) => {
+                builder.insert_snippet(
+                    cap,
+                    insert_pos,
+                    format!("type $0Type{generic_params} = {ty};\n\n{indent}"),
+                );
+            }
+            None => {
+                builder
+                    .insert(insert_pos, format!("type Type{generic_params} = {ty};\n\n{indent}"));
+            }
+        }
+    };
+
+    // Other occurrences can only use the alias if it doesn't depend on the item's generics.
+    // Whether they really are the same type is only checked once the assist is applied.
+    let scope = node.parent().filter(|_| generic_params.is_none());
+    let has_other_occurrences =
+        scope.as_ref().map_or(false, |scope| same_spelled_types(&ty, scope).nth(1).is_some());
+
+    let group = GroupLabel("Extract type as type alias".to_owned());
+    acc.add_group(
+        &group,
         AssistId("extract_type_alias", AssistKind::RefactorExtract),
         "Extract type as type alias",
         target,
         |builder| {
-            let mut known_generics = match item.generic_param_list() {
-                Some(it) => it.generic_params().collect(),
-                None => Vec::new(),
-            };
-            if let Some(it) = assoc_owner.as_ref().and_then(|it| match it {
-                Either::Left(it) => it.generic_param_list(),
-                Either::Right(it) => it.generic_param_list(),
-            }) {
-                known_generics.extend(it.generic_params());
-            }
-            let generics = collect_used_generics(&ty, &known_generics);
-            let generic_params =
-                generics.map(|it| make::generic_param_list(it.into_iter().cloned()));
-
-            let ty_args = generic_params
-                .as_ref()
-                .map_or(String::new(), |it| it.to_generic_args().to_string());
-            let replacement = format!("Type{ty_args}");
-            builder.replace(target, replacement);
-
-            let indent = IndentLevel::from_node(node);
-            let generic_params = generic_params.map_or(String::new(), |it| it.to_string());
-            match ctx.config.snippet_cap {
-                Some(cap) => {
-                    builder.insert_snippet(
-                        cap,
-                        insert_pos,
-                        format!("type $0Type{generic_params} = {ty};\n\n{indent}"),
-                    );
-                }
-                None => {
-                    builder.insert(
-                        insert_pos,
-                        format!("type Type{generic_params} = {ty};\n\n{indent}"),
-                    );
+            builder.replace(target, &replacement);
+            insert_alias(builder);
+        },
+    );
+    let scope = match scope {
+        Some(it) if has_other_occurrences => it,
+        _ => return None,
+    };
+    acc.add_group(
+        &group,
+        AssistId("extract_type_alias_all_occurrences", AssistKind::RefactorExtract),
+        "Extract type as type alias, replacing all occurrences",
+        target,
+        |builder| {
+            // Types spelled the same way may still resolve to different ones.
+            let resolved = ctx.sema.resolve_type(&ty);
+            for it in same_spelled_types(&ty, &scope) {
+                if ctx.sema.resolve_type(&it) == resolved {
+                    builder.replace(it.syntax().text_range(), &replacement);
                 }
             }
+            insert_alias(builder);
         },
     )
 }
 
+/// Finds the types in `scope` that are spelled the same way as `ty`, skipping nested modules
+/// where the alias isn't in scope.
+fn same_spelled_types<'a>(
+    ty: &ast::Type,
+    scope: &'a SyntaxNode,
+) -> impl Iterator<Item = ast::Type> + 'a {
+    let tokens = |ty: &ast::Type| {
+        ty.syntax()
+            .descendants_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter(|it| !it.kind().is_trivia())
+            .map(|it| it.text().to_owned())
+            .collect::<Vec<_>>()
+    };
+    let ty_tokens = tokens(ty);
+    scope
+        .descendants()
+        .filter_map(ast::Type::cast)
+        .filter(move |it| {
+            !it.syntax()
+                .ancestors()
+                .take_while(|it| it != scope)
+                .any(|it| ast::Module::can_cast(it.kind()))
+        })
+        .filter(move |it| tokens(it) == ty_tokens)
+}
+
 fn collect_used_generics<'gp>(
     ty: &ast::Type,
     known_generics: &'gp [ast::GenericParam],
@@ -195,7 +268,7 @@ fn find_lifetime(text: &str) -> impl Fn(&&ast::GenericParam) -> bool + '_ {
 
 #[cfg(test)]
 mod tests {
-    use crate::tests::{check_assist, check_assist_not_applicable};
+    use crate::tests::{check_assist, check_assist_by_label, check_assist_not_applicable};
 
     use super::*;
 
@@ -393,4 +466,67 @@ struct Foo<T, const N: usize>
             "#,
         );
     }
+
+    #[test]
+    fn whole_generic_type_without_selection() {
+        check_assist(
+            extract_type_alias,
+            r#"
+struct HashMap<K, V>(K, V);
+fn f(m: HashMap<String, Vec<(u32, $0u32)>>) {}
+"#,
+            r#"
+struct HashMap<K, V>(K, V);
+type $0Type = HashMap<String, Vec<(u32, u32)>>;
+
+fn f(m: Type) {}
+"#,
+        );
+    }
+
+    #[test]
+    fn replace_all_occurrences() {
+        check_assist_by_label(
+            extract_type_alias,
+            r#"
+struct HashMap<K, V>(K, V);
+struct Vec<T>(T);
+struct S {
+    edges: HashMap<u32, Vec<u32>>,
+}
+fn f(edges: $0HashMap<u32, Vec<u32>>) -> HashMap<u32,Vec<u32>> {
+    let other: HashMap<u32, Vec<u8>> = loop {};
+    edges
+}
+fn g<T>(x: HashMap<T, Vec<u32>>) {}
+mod m {
+    fn h(x: HashMap<u32, Vec<u32>>) {}
+}
+"#,
+            r#"
+struct HashMap<K, V>(K, V);
+struct Vec<T>(T);
+struct S {
+    edges: Type,
+}
+type $0Type = HashMap<u32, Vec<u32>>;
+
+fn f(edges: Type) -> Type {
+    let other: HashMap<u32, Vec<u8>> = loop {};
+    edges
+}
+fn g<T>(x: HashMap<T, Vec<u32>>) {}
+mod m {
+    fn h(x: HashMap<u32, Vec<u32>>) {}
+}
+"#,
+            "Extract type as type alias, replacing all occurrences",
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_simple_types_without_selection() {
+        check_assist_not_applicable(extract_type_alias, "fn f(x: $0u32) {}");
+        check_assist_not_applicable(extract_type_alias, "fn f(x: &$0str) {}");
+    }
 }
@@ -1,15 +1,18 @@
 use stdx::format_to;
 use syntax::{
     ast::{self, AstNode},
-    NodeOrToken,
+    match_ast, NodeOrToken,
     SyntaxKind::{
-        BLOCK_EXPR, BREAK_EXPR, CLOSURE_EXPR, COMMENT, LOOP_EXPR, MATCH_ARM, MATCH_GUARD,
-        PATH_EXPR, RETURN_EXPR,
+        BLOCK_EXPR, BREAK_EXPR, CLOSURE_EXPR, COMMENT, FOR_EXPR, LOOP_EXPR, MATCH_ARM, MATCH_GUARD,
+        PATH_EXPR, RETURN_EXPR, WHILE_EXPR,
     },
     SyntaxNode,
 };
 
-use crate::{utils::suggest_name, AssistContext, AssistId, AssistKind, Assists};
+use crate::{
+    utils::{is_side_effect_free, suggest_name},
+    AssistContext, AssistId, AssistKind, Assists,
+};
 
 // Assist: extract_variable
 //
@@ -28,23 +31,7 @@
 // }
 // ```
 pub(crate) fn extract_variable(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
-    if ctx.has_empty_selection() {
-        return None;
-    }
-
-    let node = match ctx.covering_element() {
-        NodeOrToken::Node(it) => it,
-        NodeOrToken::Token(it) if it.kind() == COMMENT => {
-            cov_mark::hit!(extract_var_in_comment_is_not_applicable);
-            return None;
-        }
-        NodeOrToken::Token(it) => it.parent()?,
-    };
-    let node = node.ancestors().take_while(|anc| anc.text_range() == node.text_range()).last()?;
-    let to_extract = node
-        .descendants()
-        .take_while(|it| ctx.selection_trimmed().contains_range(it.text_range()))
-        .find_map(valid_target_expr)?;
+    let to_extract = selected_expr(ctx)?;
 
     if let Some(ty_info) = ctx.sema.type_of_expr(&to_extract) {
         if ty_info.adjusted().is_unit() {
@@ -150,6 +137,213 @@ pub(crate) fn extract_variable(acc: &mut Assists, ctx: &AssistContext<'_>) -> Op
     )
 }
 
+// Assist: extract_variable_all_occurrences
+//
+// Extracts subexpression into a variable, replacing every identical occurrence of it that
+// follows in the same block.
+//
+// ```
+// # //- minicore: copy
+// fn main() {
+//     let a = $0(1 + 2)$0 * 4;
+//     let b = (1 + 2) * 8;
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let $0var_name = (1 + 2);
+//     let a = var_name * 4;
+//     let b = var_name * 8;
+// }
+// ```
+pub(crate) fn extract_variable_all_occurrences(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let to_extract = selected_expr(ctx)?;
+    // Every occurrence has to evaluate to the same value, which is then copied out of the
+    // variable rather than moved.
+    if !is_side_effect_free(&to_extract) {
+        cov_mark::hit!(extract_var_all_occurrences_side_effects);
+        return None;
+    }
+    let ty = ctx.sema.type_of_expr(&to_extract)?.original();
+    if ty.is_unit() {
+        return None;
+    }
+    if !ty.is_copy(ctx.db()) {
+        cov_mark::hit!(extract_var_all_occurrences_not_copy);
+        return None;
+    }
+    let anchor = match Anchor::from(&to_extract)? {
+        Anchor::Before(it) => it,
+        _ => return None,
+    };
+    let stmt_list = anchor.parent().and_then(ast::StmtList::cast)?;
+    let occurrences = occurrences_after(ctx, &stmt_list, &anchor, &to_extract);
+    if occurrences.len() < 2 {
+        cov_mark::hit!(extract_var_all_occurrences_single);
+        return None;
+    }
+
+    let indent = anchor.prev_sibling_or_token()?.as_token()?.clone();
+    let target = to_extract.syntax().text_range();
+    acc.add(
+        AssistId("extract_variable_all_occurrences", AssistKind::RefactorExtract),
+        format!("Extract all {} occurrences into variable", occurrences.len()),
+        target,
+        move |edit| {
+            let var_name = suggest_name::for_variable(&to_extract, &ctx.sema);
+            let mut buf = format!("let {var_name} = {to_extract};");
+            let text = indent.text();
+            if text.starts_with('\n') {
+                buf.push('\n');
+                buf.push_str(text.trim_start_matches('\n'));
+            } else {
+                buf.push_str(text);
+            }
+
+            for occurrence in &occurrences {
+                edit.replace(occurrence.syntax().text_range(), var_name.clone());
+            }
+            let offset = anchor.text_range().start();
+            match ctx.config.snippet_cap {
+                Some(cap) => edit.insert_snippet(
+                    cap,
+                    offset,
+                    buf.replacen(&format!("let {var_name}"), &format!("let $0{var_name}"), 1),
+                ),
+                None => edit.insert(offset, buf),
+            }
+        },
+    )
+}
+
+/// Returns the expression the selection covers, if it's one that can be extracted.
+fn selected_expr(ctx: &AssistContext<'_>) -> Option<ast::Expr> {
+    if ctx.has_empty_selection() {
+        return None;
+    }
+
+    let node = match ctx.covering_element() {
+        NodeOrToken::Node(it) => it,
+        NodeOrToken::Token(it) if it.kind() == COMMENT => {
+            cov_mark::hit!(extract_var_in_comment_is_not_applicable);
+            return None;
+        }
+        NodeOrToken::Token(it) => it.parent()?,
+    };
+    let node = node.ancestors().take_while(|anc| anc.text_range() == node.text_range()).last()?;
+    node.descendants()
+        .take_while(|it| ctx.selection_trimmed().contains_range(it.text_range()))
+        .find_map(valid_target_expr)
+}
+
+/// Finds the expressions in `stmt_list`, starting from the statement `anchor`, that are spelled
+/// the same as `expr` up to trivia and whose paths all resolve to the same items and locals.
+/// Occurrences inside nested items are skipped, and the search stops where one of the locals
+/// `expr` reads may be mutated, as the variable would be stale after it.
+fn occurrences_after(
+    ctx: &AssistContext<'_>,
+    stmt_list: &ast::StmtList,
+    anchor: &SyntaxNode,
+    expr: &ast::Expr,
+) -> Vec<ast::Expr> {
+    let tokens = |node: &SyntaxNode| {
+        node.descendants_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter(|it| !it.kind().is_trivia())
+            .map(|it| (it.kind(), it.text().to_string()))
+            .collect::<Vec<_>>()
+    };
+    let resolutions = |node: &SyntaxNode| {
+        node.descendants()
+            .filter_map(ast::Path::cast)
+            .map(|path| ctx.sema.resolve_path(&path))
+            .collect::<Vec<_>>()
+    };
+    let expected_tokens = tokens(expr.syntax());
+    let expected_resolutions = resolutions(expr.syntax());
+    let locals: Vec<_> = expected_resolutions
+        .iter()
+        .filter_map(|it| match it {
+            Some(hir::PathResolution::Local(local)) => Some(*local),
+            _ => None,
+        })
+        .collect();
+
+    let start = anchor.text_range().start();
+    let in_scope = |it: &SyntaxNode| it.text_range().start() >= start;
+    // A mutation inside a loop affects the occurrences before it on the next iteration.
+    let first_mutation = stmt_list
+        .syntax()
+        .descendants()
+        .filter(in_scope)
+        .filter_map(|it| mutated_place(ctx, &it))
+        .filter(|place| place_local(ctx, place).map_or(false, |it| locals.contains(&it)))
+        .map(|place| {
+            let outermost_loop = place
+                .syntax()
+                .ancestors()
+                .take_while(|it| it != stmt_list.syntax())
+                .filter(|it| matches!(it.kind(), LOOP_EXPR | WHILE_EXPR | FOR_EXPR))
+                .last();
+            outermost_loop.unwrap_or_else(|| place.syntax().clone()).text_range().start()
+        })
+        .min();
+
+    stmt_list
+        .syntax()
+        .descendants()
+        .filter(|it| in_scope(it) && it.kind() == expr.syntax().kind())
+        .take_while(|it| first_mutation.map_or(true, |offset| it.text_range().start() < offset))
+        .filter_map(ast::Expr::cast)
+        .filter(|it| {
+            !it.syntax()
+                .ancestors()
+                .take_while(|it| it != stmt_list.syntax())
+                .any(|it| ast::Item::can_cast(it.kind()))
+        })
+        .filter(|it| tokens(it.syntax()) == expected_tokens)
+        .filter(|it| resolutions(it.syntax()) == expected_resolutions)
+        .collect()
+}
+
+/// Returns the place `node` writes to or borrows mutably, if any.
+fn mutated_place(ctx: &AssistContext<'_>, node: &SyntaxNode) -> Option<ast::Expr> {
+    match_ast! {
+        match node {
+            ast::BinExpr(it) => match it.op_kind()? {
+                ast::BinaryOp::Assignment { .. } => it.lhs(),
+                _ => None,
+            },
+            ast::RefExpr(it) => it.mut_token().and_then(|_| it.expr()),
+            ast::MethodCallExpr(it) => {
+                let receiver = it.receiver()?;
+                let ty = ctx.sema.type_of_expr(&receiver)?.adjusted();
+                ty.is_mutable_reference().then_some(receiver)
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Returns the local `place` is a part of, like `a` for `a.b[0]`.
+fn place_local(ctx: &AssistContext<'_>, place: &ast::Expr) -> Option<hir::Local> {
+    match place {
+        ast::Expr::PathExpr(it) => match ctx.sema.resolve_path(&it.path()?)? {
+            hir::PathResolution::Local(local) => Some(local),
+            _ => None,
+        },
+        ast::Expr::FieldExpr(it) => place_local(ctx, &it.expr()?),
+        ast::Expr::IndexExpr(it) => place_local(ctx, &it.base()?),
+        ast::Expr::ParenExpr(it) => place_local(ctx, &it.expr()?),
+        ast::Expr::PrefixExpr(it) => place_local(ctx, &it.expr()?),
+        _ => None,
+    }
+}
+
 /// Check whether the node is a valid expression which can be extracted to a variable.
 /// In general that's true for any expression, but in some cases that would produce invalid code.
 fn valid_target_expr(node: SyntaxNode) -> Option<ast::Expr> {
@@ -1029,8 +1223,8 @@ struct S {
 }
 
 fn foo(s: &S) {
-    let $0x = &s.sub;
-    x.do_thing();
+    let $0sub = &s.sub;
+    sub.do_thing();
 }"#,
         );
     }
@@ -1083,8 +1277,8 @@ struct S {
 }
 
 fn foo(s: &S) {
-    let $0z = &s.sub.field.field;
-    z.do_thing();
+    let $0field = &s.sub.field.field;
+    field.do_thing();
 }"#,
         );
     }
@@ -1123,8 +1317,8 @@ struct S {
 }
 
 fn foo(s: S) {
-    let $0x = s.sub;
-    x.do_thing();
+    let $0sub = s.sub;
+    sub.do_thing();
 }"#,
         );
     }
@@ -1189,8 +1383,8 @@ fn do_thing(&self) {
 
 fn foo() {
     let local = &mut S::new();
-    let $0x = &mut local.sub;
-    x.do_thing();
+    let $0sub = &mut local.sub;
+    sub.do_thing();
 }"#,
         );
     }
@@ -1255,8 +1449,8 @@ fn do_thing(&self) {
 
 fn foo() {
     let local = &S::new();
-    let $0x = &local.sub;
-    x.do_thing();
+    let $0sub = &local.sub;
+    sub.do_thing();
 }"#,
         );
     }
@@ -1273,6 +1467,193 @@ fn foo() {
 fn foo() {
     let mut $0var_name = 0;
     let v = &mut var_name;
+}"#,
+        );
+    }
+
+    #[test]
+    fn extract_all_occurrences() {
+        check_assist(
+            extract_variable_all_occurrences,
+            r#"
+//- minicore: copy
+fn foo(a: u32, b: u32) -> u32 {
+    let first = 1 + $0a * b$0;
+    let second = 2 + a  *  b;
+    first * second * (a*b)
+}"#,
+            r#"
+fn foo(a: u32, b: u32) -> u32 {
+    let $0var_name = a * b;
+    let first = 1 + var_name;
+    let second = 2 + var_name;
+    first * second * (var_name)
+}"#,
+        );
+    }
+
+    #[test]
+    fn extract_all_occurrences_only_after_anchor() {
+        check_assist(
+            extract_variable_all_occurrences,
+            r#"
+//- minicore: copy
+fn foo(a: u32) {
+    let x = a * 2;
+    let y = $0a * 2$0;
+    let z = a * 2;
+}"#,
+            r#"
+fn foo(a: u32) {
+    let x = a * 2;
+    let $0var_name = a * 2;
+    let y = var_name;
+    let z = var_name;
+}"#,
+        );
+    }
+
+    #[test]
+    fn extract_all_occurrences_respects_shadowing() {
+        check_assist(
+            extract_variable_all_occurrences,
+            r#"
+//- minicore: copy
+fn foo(a: u32) {
+    let x = $0a + 1$0;
+    let y = a + 1;
+    let a = 5;
+    let z = a + 1;
+}"#,
+            r#"
+fn foo(a: u32) {
+    let $0var_name = a + 1;
+    let x = var_name;
+    let y = var_name;
+    let a = 5;
+    let z = a + 1;
+}"#,
+        );
+    }
+
+    #[test]
+    fn extract_all_occurrences_stops_at_mutation() {
+        check_assist(
+            extract_variable_all_occurrences,
+            r#"
+//- minicore: copy
+struct S { x: u32 }
+fn foo(mut s: S) {
+    let a = $0s.x$0 + 1;
+    fn bar(s: S) -> u32 { s.x }
+    let b = s.x;
+    s.x = 2;
+    let c = s.x;
+}"#,
+            r#"
+struct S { x: u32 }
+fn foo(mut s: S) {
+    let $0x = s.x;
+    let a = x + 1;
+    fn bar(s: S) -> u32 { s.x }
+    let b = x;
+    s.x = 2;
+    let c = s.x;
+}"#,
+        );
+    }
+
+    #[test]
+    fn extract_all_occurrences_not_applicable_to_single_occurrence() {
+        cov_mark::check!(extract_var_all_occurrences_single);
+        check_assist_not_applicable(
+            extract_variable_all_occurrences,
+            r#"
+//- minicore: copy
+fn foo() {
+    let x = $01 + 2$0;
+    let y = 1 + 3;
+}"#,
+        );
+    }
+
+    #[test]
+    fn extract_all_occurrences_stops_at_operand_mutation() {
+        check_assist(
+            extract_variable_all_occurrences,
+            r#"
+//- minicore: copy
+fn foo(mut a: u32) {
+    let x = $0a + 1$0;
+    let y = a + 1;
+    a += 1;
+    let z = a + 1;
+}"#,
+            r#"
+fn foo(mut a: u32) {
+    let $0var_name = a + 1;
+    let x = var_name;
+    let y = var_name;
+    a += 1;
+    let z = a + 1;
+}"#,
+        );
+    }
+
+    #[test]
+    fn extract_all_occurrences_stops_at_mutation_in_loop() {
+        cov_mark::check!(extract_var_all_occurrences_single);
+        check_assist_not_applicable(
+            extract_variable_all_occurrences,
+            r#"
+//- minicore: copy
+fn foo(mut a: u32) {
+    let x = $0a * 2$0;
+    loop {
+        let y = a * 2;
+        a += 1;
+    }
+}"#,
+        );
+    }
+
+    #[test]
+    fn extract_all_occurrences_not_applicable_with_side_effects() {
+        cov_mark::check_count!(extract_var_all_occurrences_side_effects, 2);
+        check_assist_not_applicable(
+            extract_variable_all_occurrences,
+            r#"
+//- minicore: copy, iterator
+fn foo(mut it: impl Iterator<Item = u32>) {
+    let x = $0it.next()$0;
+    let y = it.next();
+}"#,
+        );
+        check_assist_not_applicable(
+            extract_variable_all_occurrences,
+            r#"
+//- minicore: copy
+fn len(v: &[u32]) -> usize { v.len() }
+fn foo(v: &[u32]) {
+    let x = $0len(v)$0;
+    let y = len(v);
+}"#,
+        );
+    }
+
+    #[test]
+    fn extract_all_occurrences_not_applicable_to_moves() {
+        cov_mark::check!(extract_var_all_occurrences_not_copy);
+        check_assist_not_applicable(
+            extract_variable_all_occurrences,
+            r#"
+//- minicore: copy
+struct S { name: Name }
+struct Name;
+fn take(name: Name) {}
+fn foo(s: &S) {
+    take($0s.name$0);
+    take(s.name);
 }"#,
         );
     }
@@ -0,0 +1,304 @@
+use hir::HasVisibility;
+use ide_db::{assists::GroupLabel, famous_defs::FamousDefs};
+use syntax::{
+    ast::{self, edit::IndentLevel, make, AstNode},
+    TextRange, T,
+};
+
+use crate::{
+    utils::{render_snippet, Cursor},
+    AssistContext, AssistId, AssistKind, Assists,
+};
+
+// Assist: fill_record_expr_fields
+//
+// Adds the fields missing from a record literal, with placeholder values.
+//
+// ```
+// struct Point { x: i32, y: i32, z: i32 }
+// fn main() {
+//     let p = Point$0 { x: 1 };
+// }
+// ```
+// ->
+// ```
+// struct Point { x: i32, y: i32, z: i32 }
+// fn main() {
+//     let p = Point { x: 1, y: ${0:todo!()}, z: todo!() };
+// }
+// ```
+
+// Assist: fill_record_expr_fields_with_default
+//
+// Fills the fields missing from a record literal with `..Default::default()`.
+//
+// ```
+// # //- minicore: default
+// struct Point { x: i32, y: i32, z: i32 }
+// impl Default for Point {
+//     fn default() -> Self { Point { x: 0, y: 0, z: 0 } }
+// }
+// fn main() {
+//     let p = Point$0 { x: 1 };
+// }
+// ```
+// ->
+// ```
+// struct Point { x: i32, y: i32, z: i32 }
+// impl Default for Point {
+//     fn default() -> Self { Point { x: 0, y: 0, z: 0 } }
+// }
+// fn main() {
+//     let p = Point { x: 1, ..Default::default() };
+// }
+// ```
+pub(crate) fn fill_record_expr_fields(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let record = ctx.find_node_at_offset::<ast::RecordExpr>()?;
+    let field_list = record.record_expr_field_list()?;
+    if field_list.dotdot_token().is_some() {
+        return None;
+    }
+    let missing_fields = ctx.sema.record_literal_missing_fields(&record);
+    // `S { 0: x }` is valid, but nobody wants to write it.
+    if missing_fields.is_empty()
+        || missing_fields.iter().any(|(it, _)| it.name(ctx.db()).as_tuple_index().is_some())
+    {
+        return None;
+    }
+
+    let module = ctx.sema.scope(record.syntax())?.module();
+    let default_trait = FamousDefs(&ctx.sema, module.krate()).core_default_Default();
+    let impls_default =
+        |ty: &hir::Type| default_trait.map_or(false, |it| ty.impls_trait(ctx.db(), it, &[]));
+    let default_expr = || {
+        let path = make::ext::path_from_idents(["Default", "default"])?;
+        Some(make::expr_call(make::expr_path(path), make::arg_list(None)))
+    };
+
+    // Private fields of a struct from another module can't be filled in at all, not even by a
+    // spread.
+    if !missing_fields.iter().all(|(it, _)| it.is_visible_from(ctx.db(), module)) {
+        cov_mark::hit!(fill_record_expr_fields_private);
+        return None;
+    }
+
+    let target = record.syntax().text_range();
+    let group = GroupLabel("Fill remaining fields".to_owned());
+    acc.add_group(
+        &group,
+        AssistId("fill_record_expr_fields", AssistKind::RefactorRewrite),
+        "Fill remaining fields",
+        target,
+        |builder| {
+            let existing = field_list.fields().count();
+            let is_multiline = field_list.syntax().text().contains_char('\n');
+            let new_fields = missing_fields.iter().map(|(field, ty)| {
+                let expr = match impls_default(ty) {
+                    true => default_expr().unwrap_or_else(make::ext::expr_todo),
+                    false => make::ext::expr_todo(),
+                };
+                let name = make::name_ref(&field.name(ctx.db()).to_smol_str());
+                make::record_expr_field(name, Some(expr))
+            });
+            let new_list = if existing == 0 && !is_multiline {
+                make::record_expr_field_list(new_fields).clone_for_update()
+            } else {
+                let new_list = field_list.clone_for_update();
+                new_fields.for_each(|it| new_list.add_field(it.clone_for_update()));
+                new_list
+            };
+
+            let range = field_list.syntax().text_range();
+            let placeholder = new_list.fields().nth(existing).and_then(|it| it.expr());
+            match (ctx.config.snippet_cap, placeholder) {
+                (Some(cap), Some(placeholder)) => {
+                    let cursor = Cursor::Replace(placeholder.syntax());
+                    builder.replace_snippet(
+                        cap,
+                        range,
+                        render_snippet(cap, new_list.syntax(), cursor),
+                    )
+                }
+                _ => builder.replace(range, new_list.to_string()),
+            }
+        },
+    );
+
+    let ty = ctx.sema.type_of_expr(&ast::Expr::RecordExpr(record))?.original;
+    if !impls_default(&ty) {
+        return None;
+    }
+    acc.add_group(
+        &group,
+        AssistId("fill_record_expr_fields_with_default", AssistKind::RefactorRewrite),
+        "Fill remaining fields with `..Default::default()`",
+        target,
+        |builder| {
+            let (range, text) = spread_insertion(&field_list, "..Default::default()");
+            builder.replace(range, text)
+        },
+    )
+}
+
+/// Computes the edit appending `spread` after the fields of `field_list`.
+fn spread_insertion(field_list: &ast::RecordExprFieldList, spread: &str) -> (TextRange, String) {
+    let last_field = match field_list.fields().last() {
+        Some(it) => it,
+        None => return (field_list.syntax().text_range(), format!("{{ {spread} }}")),
+    };
+    let comma = last_field
+        .syntax()
+        .siblings_with_tokens(syntax::Direction::Next)
+        .filter_map(|it| it.into_token())
+        .find(|it| it.kind() == T![,]);
+    let offset =
+        comma.as_ref().map_or(last_field.syntax().text_range().end(), |it| it.text_range().end());
+    let comma = if comma.is_some() { "" } else { "," };
+    let text = match field_list.syntax().text().contains_char('\n') {
+        true => format!("{comma}\n{}{spread}", IndentLevel::from_node(last_field.syntax())),
+        false => format!("{comma} {spread}"),
+    };
+    (TextRange::empty(offset), text)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist_by_label, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn fill_multiline_record_with_defaults() {
+        check_assist_by_label(
+            fill_record_expr_fields,
+            r#"
+//- minicore: default
+struct S { a: u32, b: D, c: T }
+struct D;
+impl Default for D { fn default() -> Self { D } }
+struct T;
+fn main() {
+    let s = S$0 {
+        a: 1,
+    };
+}
+"#,
+            r#"
+struct S { a: u32, b: D, c: T }
+struct D;
+impl Default for D { fn default() -> Self { D } }
+struct T;
+fn main() {
+    let s = S {
+        a: 1,
+        b: ${0:Default::default()},
+        c: todo!(),
+    };
+}
+"#,
+            "Fill remaining fields",
+        );
+    }
+
+    #[test]
+    fn fill_empty_record() {
+        check_assist_by_label(
+            fill_record_expr_fields,
+            r#"
+struct S { a: u32, b: u32 }
+fn main() {
+    let s = S {$0};
+}
+"#,
+            r#"
+struct S { a: u32, b: u32 }
+fn main() {
+    let s = S { a: ${0:todo!()}, b: todo!() };
+}
+"#,
+            "Fill remaining fields",
+        );
+    }
+
+    #[test]
+    fn fill_with_default_spread() {
+        check_assist_by_label(
+            fill_record_expr_fields,
+            r#"
+//- minicore: default
+struct S { a: u32, b: u32 }
+impl Default for S { fn default() -> Self { S { a: 0, b: 0 } } }
+fn main() {
+    let s = S$0 {
+        a: 1
+    };
+}
+"#,
+            r#"
+struct S { a: u32, b: u32 }
+impl Default for S { fn default() -> Self { S { a: 0, b: 0 } } }
+fn main() {
+    let s = S {
+        a: 1,
+        ..Default::default()
+    };
+}
+"#,
+            "Fill remaining fields with `..Default::default()`",
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_complete() {
+        check_assist_not_applicable(
+            fill_record_expr_fields,
+            r#"
+struct S { a: u32 }
+fn main() {
+    let s = S$0 { a: 1 };
+}
+"#,
+        );
+        check_assist_not_applicable(
+            fill_record_expr_fields,
+            r#"
+//- minicore: default
+#[derive(Default)]
+struct S { a: u32, b: u32 }
+fn main() {
+    let s = S$0 { a: 1, ..Default::default() };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_private_fields() {
+        cov_mark::check_count!(fill_record_expr_fields_private, 2);
+        check_assist_not_applicable(
+            fill_record_expr_fields,
+            r#"
+mod m {
+    pub struct S { pub a: u32, b: u32 }
+}
+fn main() {
+    let s = m::S$0 { a: 1 };
+}
+"#,
+        );
+        // `..Default::default()` doesn't lift the privacy of the remaining fields.
+        check_assist_not_applicable(
+            fill_record_expr_fields,
+            r#"
+//- minicore: default
+mod m {
+    pub struct S { pub a: u32, b: u32 }
+    impl Default for S { fn default() -> Self { S { a: 0, b: 0 } } }
+}
+fn main() {
+    let s = m::S$0 {};
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use ide_db::{path_transform::PathTransform, traits::resolve_target_trait};
+use syntax::ast::{AstNode, HasName};
+
+use crate::{
+    utils::{impl_fn_at_cursor, signature_shape, trait_fn_named},
+    AssistContext, AssistId, AssistKind, Assists,
+};
+
+// Assist: fix_impl_method_signature
+//
+// Replaces the parameters of a method in a trait impl with the ones of the trait declaration, if
+// the two take a different number or kind of parameters.
+//
+// ```
+// trait Trait {
+//     fn foo(&self, x: u32);
+// }
+// struct S;
+// impl Trait for S {
+//     fn $0foo(&self) {}
+// }
+// ```
+// ->
+// ```
+// trait Trait {
+//     fn foo(&self, x: u32);
+// }
+// struct S;
+// impl Trait for S {
+//     fn foo(&self, x: u32) {}
+// }
+// ```
+pub(crate) fn fix_impl_method_signature(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let (impl_, fn_) = impl_fn_at_cursor(ctx)?;
+    let name = fn_.name()?;
+    let trait_ = resolve_target_trait(&ctx.sema, &impl_)?;
+
+    let trait_fn = trait_fn_named(ctx.db(), trait_, &name.text())?;
+    let trait_params = ctx.sema.source(trait_fn)?.value.param_list()?;
+    let params = fn_.param_list()?;
+    if signature_shape(&params) == signature_shape(&trait_params) {
+        cov_mark::hit!(fix_impl_method_signature_matching);
+        return None;
+    }
+
+    let target_scope = ctx.sema.scope(impl_.syntax())?;
+    let source_scope = ctx.sema.scope_for_def(trait_);
+    let target = params.syntax().text_range();
+    acc.add(
+        AssistId("fix_impl_method_signature", AssistKind::QuickFix),
+        format!("Fix signature of `{name}`"),
+        target,
+        |builder| {
+            let new_params = trait_params.clone_for_update();
+            PathTransform::trait_impl(&target_scope, &source_scope, trait_, impl_.clone())
+                .apply(new_params.syntax());
+            builder.replace(target, new_params.to_string());
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn fix_missing_param() {
+        check_assist(
+            fix_impl_method_signature,
+            r#"
+trait Trait {
+    fn foo(&self, x: u32, y: u32);
+}
+struct S;
+impl Trait for S {
+    fn fo$0o(&self, x: u32) {}
+}
+"#,
+            r#"
+trait Trait {
+    fn foo(&self, x: u32, y: u32);
+}
+struct S;
+impl Trait for S {
+    fn foo(&self, x: u32, y: u32) {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn fix_self_param_kind() {
+        check_assist(
+            fix_impl_method_signature,
+            r#"
+trait Trait {
+    fn foo(&mut self);
+}
+struct S;
+impl Trait for S {
+    fn $0foo(self) {}
+}
+"#,
+            r#"
+trait Trait {
+    fn foo(&mut self);
+}
+struct S;
+impl Trait for S {
+    fn foo(&mut self) {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn fix_substitutes_trait_generics() {
+        check_assist(
+            fix_impl_method_signature,
+            r#"
+trait Trait<T> {
+    fn foo(&self, t: T);
+}
+struct S;
+impl Trait<u32> for S {
+    fn $0foo(&self) {}
+}
+"#,
+            r#"
+trait Trait<T> {
+    fn foo(&self, t: T);
+}
+struct S;
+impl Trait<u32> for S {
+    fn foo(&self, t: u32) {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_matching_signature() {
+        cov_mark::check!(fix_impl_method_signature_matching);
+        check_assist_not_applicable(
+            fix_impl_method_signature,
+            r#"
+trait Trait {
+    fn foo(&self, x: u32);
+}
+struct S;
+impl Trait for S {
+    fn $0foo(&self, y: i64) {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_outside_trait_impls() {
+        check_assist_not_applicable(
+            fix_impl_method_signature,
+            r#"
+struct S;
+impl S {
+    fn $0foo(&self) {}
+}
+"#,
+        );
+        check_assist_not_applicable(
+            fix_impl_method_signature,
+            r#"
+trait Trait {
+    fn foo(&self, x: u32);
+}
+struct S;
+impl Trait for S {
+    fn $0bar(&self) {}
+}
+"#,
+        );
+    }
+}
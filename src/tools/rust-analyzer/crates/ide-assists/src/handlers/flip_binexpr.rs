@@ -60,6 +60,14 @@ impl From<ast::BinaryOp> for FlipAction {
     fn from(op_kind: ast::BinaryOp) -> Self {
         match op_kind {
             ast::BinaryOp::Assignment { .. } => FlipAction::DontFlip,
+            // Swapping the operands of non-commutative operators changes the result
+            ast::BinaryOp::ArithOp(
+                ast::ArithOp::Sub
+                | ast::ArithOp::Div
+                | ast::ArithOp::Rem
+                | ast::ArithOp::Shl
+                | ast::ArithOp::Shr,
+            ) => FlipAction::DontFlip,
             ast::BinaryOp::CmpOp(ast::CmpOp::Ord { ordering, strict }) => {
                 let rev_op = match (ordering, strict) {
                     (ast::Ordering::Less, true) => ">",
@@ -90,6 +98,24 @@ fn flip_binexpr_not_applicable_for_assignment() {
         check_assist_not_applicable(flip_binexpr, "fn f() { let mut _x = 1; _x +=$0 2 }")
     }
 
+    #[test]
+    fn flip_binexpr_not_applicable_for_non_commutative_ops() {
+        for op in ["-", "/", "%", "<<", ">>"] {
+            check_assist_not_applicable(flip_binexpr, &format!("fn f() {{ let _ = 1 {op}$0 2; }}"))
+        }
+    }
+
+    #[test]
+    fn flip_binexpr_works_for_commutative_ops() {
+        for op in ["+", "*", "&", "|", "^"] {
+            check_assist(
+                flip_binexpr,
+                &format!("fn f() {{ let _ = 1 {op}$0 2; }}"),
+                &format!("fn f() {{ let _ = 2 {op} 1; }}"),
+            )
+        }
+    }
+
     #[test]
     fn flip_binexpr_works_for_eq() {
         check_assist(flip_binexpr, "fn f() { let res = 1 ==$0 2; }", "fn f() { let res = 2 == 1; }")
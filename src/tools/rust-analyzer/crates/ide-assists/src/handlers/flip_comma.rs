@@ -83,6 +83,38 @@ fn flip_comma_works() {
         )
     }
 
+    #[test]
+    fn flip_comma_works_for_call_args() {
+        check_assist(
+            flip_comma,
+            r#"fn main() { foo(a + 1,$0 b); }"#,
+            r#"fn main() { foo(b, a + 1); }"#,
+        )
+    }
+
+    #[test]
+    fn flip_comma_works_for_generic_args() {
+        check_assist(
+            flip_comma,
+            r#"type T = HashMap<String,$0 Vec<u8>>;"#,
+            r#"type T = HashMap<Vec<u8>, String>;"#,
+        )
+    }
+
+    #[test]
+    fn flip_comma_works_for_record_literal_fields() {
+        check_assist(
+            flip_comma,
+            r#"fn main() { S { a: 1,$0 b: 2 }; }"#,
+            r#"fn main() { S { b: 2, a: 1 }; }"#,
+        )
+    }
+
+    #[test]
+    fn flip_comma_not_applicable_for_trailing_comma_in_call() {
+        check_assist_not_applicable(flip_comma, r#"fn main() { foo(a, b,$0); }"#);
+    }
+
     #[test]
     fn flip_comma_not_applicable_for_macro_input() {
         // "Flip comma" assist shouldn't be applicable inside the macro call
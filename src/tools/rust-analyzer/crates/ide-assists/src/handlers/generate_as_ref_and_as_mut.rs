@@ -0,0 +1,256 @@
+use hir::{HirDisplay, ModuleDef};
+use ide_db::famous_defs::FamousDefs;
+use itertools::Itertools;
+use syntax::ast::{self, AstNode, HasName};
+
+use crate::{
+    utils::{generate_trait_impl_text_intransitive, IndentStyle},
+    AssistContext, AssistId, AssistKind, Assists,
+};
+
+// Assist: generate_as_ref_and_as_mut
+//
+// Adds `AsRef` and `AsMut` impls exposing the only field of a wrapper struct.
+//
+// ```
+// # //- minicore: as_ref, as_mut
+// struct Meters$0(f64);
+// ```
+// ->
+// ```
+// struct Meters(f64);
+//
+// impl AsRef<f64> for Meters {
+//     fn as_ref(&self) -> &f64 {
+//         &self.0
+//     }
+// }
+//
+// impl AsMut<f64> for Meters {
+//     fn as_mut(&mut self) -> &mut f64 {
+//         &mut self.0
+//     }
+// }
+// ```
+pub(crate) fn generate_as_ref_and_as_mut(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let strukt = ctx.find_node_at_offset::<ast::Struct>()?;
+    let field_list = strukt.field_list()?;
+    // Fields have assists of their own. A cursor right after the name of a tuple struct touches
+    // its field list, but is still on the name.
+    let fields_range = field_list.syntax().text_range();
+    if fields_range.start() < ctx.offset() && ctx.offset() <= fields_range.end() {
+        return None;
+    }
+    let field_name = match &field_list {
+        ast::FieldList::RecordFieldList(it) => it.fields().next()?.name()?.to_string(),
+        ast::FieldList::TupleFieldList(_) => "0".to_owned(),
+    };
+
+    let strukt_def = ctx.sema.to_def(&strukt)?;
+    let field = match strukt_def.fields(ctx.db()).as_slice() {
+        [it] => *it,
+        _ => return None,
+    };
+    let module = strukt_def.module(ctx.db());
+    let inner_ty = field.ty(ctx.db());
+    let inner = inner_ty.display_source_code(ctx.db(), module.into()).ok()?;
+
+    let famous_defs = FamousDefs(&ctx.sema, module.krate());
+    let strukt_ty = strukt_def.ty(ctx.db());
+    let traits = [
+        ("as_ref", "&self", "&", famous_defs.core_convert_AsRef()),
+        ("as_mut", "&mut self", "&mut ", famous_defs.core_convert_AsMut()),
+    ];
+    let missing = traits
+        .into_iter()
+        .filter_map(|(method, self_param, borrow, trait_)| {
+            let trait_ = trait_?;
+            if strukt_ty.impls_trait(ctx.db(), trait_, &[inner_ty.clone()]) {
+                cov_mark::hit!(conversion_is_already_implemented);
+                return None;
+            }
+            let path = module.find_use_path(
+                ctx.db(),
+                ModuleDef::Trait(trait_),
+                ctx.config.prefer_no_std,
+            )?;
+            Some((trait_.name(ctx.db()).to_smol_str(), path, method, self_param, borrow))
+        })
+        .collect::<Vec<_>>();
+    let label = match missing.as_slice() {
+        [] => return None,
+        [(name, ..)] => format!("Generate `{name}<{inner}>` impl"),
+        [..] => "Generate `AsRef` and `AsMut` impls".to_owned(),
+    };
+
+    let target = strukt.syntax().text_range();
+    acc.add(AssistId("generate_as_ref_and_as_mut", AssistKind::Generate), label, target, |edit| {
+        let adt = ast::Adt::Struct(strukt.clone());
+        let style = IndentStyle::of_file(strukt.syntax());
+        let unit = style.unit();
+        let strukt_indent = unit.repeat(style.indent_level(strukt.syntax()).0.into());
+        let impls = missing
+            .iter()
+            .map(|(_, path, method, self_param, borrow)| {
+                let signature = format!("fn {method}({self_param}) -> {borrow}{inner}");
+                let code = format!(
+                    "{unit}{signature} {{\n{unit}{unit}{borrow}self.{field_name}\n{unit}}}"
+                );
+                generate_trait_impl_text_intransitive(&adt, &format!("{path}<{inner}>"), &code)
+            })
+            .collect::<String>();
+        // The impls go on the same level as the struct, and blank lines stay without indentation.
+        let impls = impls
+            .split('\n')
+            .map(
+                |line| {
+                    if line.is_empty() {
+                        String::new()
+                    } else {
+                        format!("{strukt_indent}{line}")
+                    }
+                },
+            )
+            .join("\n");
+        edit.insert(target.end(), impls);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn generic_record_struct() {
+        check_assist(
+            generate_as_ref_and_as_mut,
+            r#"
+//- minicore: as_ref, as_mut
+struct $0Stack<T> {
+    items: Vec<T>,
+}
+struct Vec<T>(T);
+"#,
+            r#"
+struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl<T> AsRef<Vec<T>> for Stack<T> {
+    fn as_ref(&self) -> &Vec<T> {
+        &self.items
+    }
+}
+
+impl<T> AsMut<Vec<T>> for Stack<T> {
+    fn as_mut(&mut self) -> &mut Vec<T> {
+        &mut self.items
+    }
+}
+struct Vec<T>(T);
+"#,
+        );
+    }
+
+    #[test]
+    fn only_as_mut_is_missing() {
+        check_assist(
+            generate_as_ref_and_as_mut,
+            r#"
+//- minicore: as_ref, as_mut
+struct W$0(u32);
+impl AsRef<u32> for W {
+    fn as_ref(&self) -> &u32 { &self.0 }
+}
+"#,
+            r#"
+struct W(u32);
+
+impl AsMut<u32> for W {
+    fn as_mut(&mut self) -> &mut u32 {
+        &mut self.0
+    }
+}
+impl AsRef<u32> for W {
+    fn as_ref(&self) -> &u32 { &self.0 }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_already_implemented() {
+        cov_mark::check_count!(conversion_is_already_implemented, 2);
+        check_assist_not_applicable(
+            generate_as_ref_and_as_mut,
+            r#"
+//- minicore: as_ref, as_mut
+struct W$0(u32);
+impl AsRef<u32> for W {
+    fn as_ref(&self) -> &u32 { &self.0 }
+}
+impl AsMut<u32> for W {
+    fn as_mut(&mut self) -> &mut u32 { &mut self.0 }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_structs() {
+        check_assist_not_applicable(
+            generate_as_ref_and_as_mut,
+            r#"
+//- minicore: as_ref, as_mut
+struct S$0(u32, u32);
+"#,
+        );
+        check_assist_not_applicable(
+            generate_as_ref_and_as_mut,
+            r#"
+//- minicore: as_ref, as_mut
+struct S { $0a: u32 }
+"#,
+        );
+    }
+
+    #[test]
+    fn follows_the_indentation_of_the_file() {
+        check_assist(
+            generate_as_ref_and_as_mut,
+            r#"
+//- minicore: as_ref, as_mut
+mod m {
+	struct W$0(u32);
+
+	fn f() {
+		let _ = 0;
+	}
+}
+"#,
+            "
+mod m {
+	struct W(u32);
+
+	impl AsRef<u32> for W {
+		fn as_ref(&self) -> &u32 {
+			&self.0
+		}
+	}
+
+	impl AsMut<u32> for W {
+		fn as_mut(&mut self) -> &mut u32 {
+			&mut self.0
+		}
+	}
+
+	fn f() {
+		let _ = 0;
+	}
+}
+",
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use hir::ModuleDef;
+use ide_db::{famous_defs::FamousDefs, helpers::mod_path_to_ast};
+use syntax::ast::{self, AstNode, HasName};
+
+use crate::{
+    utils::{impl_def_from_trait, render_snippet, Cursor},
+    AssistContext, AssistId, AssistKind, Assists,
+};
+
+// Assist: generate_debug_impl
+//
+// Adds a hand-written `Debug` impl for a struct or an enum, as a starting point for custom
+// formatting.
+//
+// ```
+// # //- minicore: fmt
+// enum Shape$0 {
+//     Circle { r: f64 },
+//     Square(f64),
+// }
+// ```
+// ->
+// ```
+// enum Shape {
+//     Circle { r: f64 },
+//     Square(f64),
+// }
+//
+// impl core::fmt::Debug for Shape {
+//     $0fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+//         match self {
+//             Self::Circle { r } => f.debug_struct("Circle").field("r", r).finish(),
+//             Self::Square(arg0) => f.debug_tuple("Square").field(arg0).finish(),
+//         }
+//     }
+// }
+// ```
+pub(crate) fn generate_debug_impl(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let adt = ctx.find_node_at_offset::<ast::Adt>()?;
+
+    // Only offer this on the header, fields and variants have assists of their own.
+    let body_range = match &adt {
+        ast::Adt::Struct(strukt) => strukt.field_list().map(|it| it.syntax().text_range()),
+        ast::Adt::Enum(enum_) => enum_.variant_list().map(|it| it.syntax().text_range()),
+        // `Debug` has no sensible default body for unions.
+        ast::Adt::Union(_) => return None,
+    };
+    if body_range.map_or(false, |range| range.contains_inclusive(ctx.offset())) {
+        return None;
+    }
+    let name = adt.name()?;
+
+    let module = ctx.sema.scope(adt.syntax())?.module();
+    let debug_trait = FamousDefs(&ctx.sema, module.krate()).core_fmt_Debug()?;
+    let adt_def = ctx.sema.to_def(&adt)?;
+    if adt_def.ty(ctx.db()).impls_trait(ctx.db(), debug_trait, &[]) {
+        cov_mark::hit!(debug_is_already_implemented);
+        return None;
+    }
+
+    let trait_path = module
+        .find_use_path(ctx.db(), ModuleDef::Trait(debug_trait), ctx.config.prefer_no_std)
+        .as_ref()
+        .map(mod_path_to_ast)?;
+    let (impl_def, first_assoc_item) =
+        impl_def_from_trait(ctx, &adt, &name, Some(debug_trait), &trait_path)?;
+
+    let target = adt.syntax().text_range();
+    acc.add(
+        AssistId("generate_debug_impl", AssistKind::Generate),
+        "Generate `Debug` impl",
+        target,
+        |builder| {
+            let insert_offset = adt.syntax().text_range().end();
+            match ctx.config.snippet_cap {
+                Some(cap) => {
+                    let cursor = Cursor::Before(first_assoc_item.syntax());
+                    let rendered = render_snippet(cap, impl_def.syntax(), cursor);
+                    builder.insert_snippet(cap, insert_offset, format!("\n\n{rendered}"));
+                }
+                None => builder.insert(insert_offset, format!("\n\n{impl_def}")),
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn record_struct() {
+        check_assist(
+            generate_debug_impl,
+            r#"
+//- minicore: fmt
+struct $0Foo {
+    bar: String,
+    baz: usize,
+}
+"#,
+            r#"
+struct Foo {
+    bar: String,
+    baz: usize,
+}
+
+impl core::fmt::Debug for Foo {
+    $0fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Foo").field("bar", &self.bar).field("baz", &self.baz).finish()
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn tuple_struct() {
+        check_assist(
+            generate_debug_impl,
+            r#"
+//- minicore: fmt
+struct $0Foo(String, usize);
+"#,
+            r#"
+struct Foo(String, usize);
+
+impl core::fmt::Debug for Foo {
+    $0fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Foo").field(&self.0).field(&self.1).finish()
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn enum_binds_variant_fields() {
+        check_assist(
+            generate_debug_impl,
+            r#"
+//- minicore: fmt
+enum Foo$0 {
+    Bar { baz: usize },
+    Qux(usize, usize),
+    Quux,
+}
+"#,
+            r#"
+enum Foo {
+    Bar { baz: usize },
+    Qux(usize, usize),
+    Quux,
+}
+
+impl core::fmt::Debug for Foo {
+    $0fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Bar { baz } => f.debug_struct("Bar").field("baz", baz).finish(),
+            Self::Qux(arg0, arg1) => f.debug_tuple("Qux").field(arg0).field(arg1).finish(),
+            Self::Quux => write!(f, "Quux"),
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn generic_struct() {
+        check_assist(
+            generate_debug_impl,
+            r#"
+//- minicore: fmt
+struct Foo$0<T> {
+    bar: T,
+}
+"#,
+            r#"
+struct Foo<T> {
+    bar: T,
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for Foo<T> {
+    $0fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Foo").field("bar", &self.bar).finish()
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_in_field_list() {
+        check_assist_not_applicable(
+            generate_debug_impl,
+            r#"
+//- minicore: fmt
+struct Foo {
+    $0bar: u32,
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_already_implemented() {
+        cov_mark::check!(debug_is_already_implemented);
+        check_assist_not_applicable(
+            generate_debug_impl,
+            r#"
+//- minicore: fmt
+struct Foo$0;
+
+impl core::fmt::Debug for Foo {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Foo").finish()
+    }
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,308 @@
+use ide_db::famous_defs::FamousDefs;
+use syntax::{
+    ast::{self, edit::IndentLevel, edit_in_place::Indent, make, AstNode, HasName, StructKind},
+    ted, SyntaxKind,
+};
+
+use crate::{
+    utils::{generate_trait_impl_text, render_snippet, Cursor},
+    AssistContext, AssistId, AssistKind, Assists,
+};
+
+// Assist: generate_default
+//
+// Adds a `Default` impl for a struct, filling every field with its default value.
+//
+// ```
+// struct Point$0 {
+//     x: i32,
+//     y: i32,
+// }
+// ```
+// ->
+// ```
+// struct Point {
+//     x: i32,
+//     y: i32,
+// }
+//
+// impl Default for Point {
+//     fn default() -> Self {
+//         $0Self { x: Default::default(), y: Default::default() }
+//     }
+// }
+// ```
+pub(crate) fn generate_default(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let strukt = ctx.find_node_at_offset::<ast::Struct>()?;
+
+    // Fields have plenty of assists of their own, only offer this on the header.
+    if let Some(field_list) = strukt.field_list() {
+        if field_list.syntax().text_range().contains_inclusive(ctx.offset()) {
+            return None;
+        }
+    }
+
+    if is_default_implemented(ctx, &strukt) {
+        cov_mark::hit!(default_is_already_implemented);
+        return None;
+    }
+
+    let default_call = || {
+        make::expr_call(
+            make::expr_path(make::path_from_text("Default::default")),
+            make::arg_list(None),
+        )
+    };
+    let self_path = || make::ext::ident_path("Self");
+    let body = match strukt.kind() {
+        StructKind::Record(field_list) => {
+            let fields = field_list.fields().filter_map(|field| field.name()).map(|name| {
+                make::record_expr_field(make::name_ref(&name.text()), Some(default_call()))
+            });
+            let fields = make::record_expr_field_list(fields).clone_for_update();
+            // `make` pads the braces with spaces, which is left over as `{  }` without fields.
+            if fields.fields().next().is_none() {
+                fields
+                    .syntax()
+                    .children_with_tokens()
+                    .filter(|it| it.kind() == SyntaxKind::WHITESPACE)
+                    .for_each(ted::remove);
+            }
+            make::record_expr(self_path(), fields).into()
+        }
+        StructKind::Tuple(field_list) => {
+            let args = field_list.fields().map(|_| default_call());
+            make::expr_call(make::expr_path(self_path()), make::arg_list(args))
+        }
+        StructKind::Unit => make::expr_path(self_path()),
+    };
+    let default_fn = make::fn_(
+        None,
+        make::name("default"),
+        None,
+        make::param_list(None, None),
+        make::block_expr(None, Some(body)),
+        Some(make::ret_type(make::ty("Self"))),
+        false,
+    );
+
+    let adt = ast::Adt::Struct(strukt.clone());
+    let impl_def = syntax::SourceFile::parse(&generate_trait_impl_text(&adt, "Default", ""))
+        .tree()
+        .syntax()
+        .descendants()
+        .find_map(ast::Impl::cast)?
+        .clone_for_update();
+    let default_fn = default_fn.clone_for_update();
+    default_fn.indent(IndentLevel(1));
+    impl_def.get_or_create_assoc_item_list().add_item(default_fn.into());
+    let indent = IndentLevel::from_node(strukt.syntax());
+    impl_def.indent(indent);
+
+    let target = strukt.syntax().text_range();
+    acc.add(
+        AssistId("generate_default", AssistKind::Generate),
+        "Generate `Default` impl",
+        target,
+        |builder| {
+            let insert_offset = strukt.syntax().text_range().end();
+            match ctx.config.snippet_cap {
+                Some(cap) => {
+                    let body = impl_def
+                        .syntax()
+                        .descendants()
+                        .find_map(ast::Fn::cast)
+                        .and_then(|it| it.body()?.tail_expr());
+                    let rendered = match &body {
+                        Some(body) => {
+                            render_snippet(cap, impl_def.syntax(), Cursor::Before(body.syntax()))
+                        }
+                        None => impl_def.to_string(),
+                    };
+                    builder.insert_snippet(cap, insert_offset, format!("\n\n{indent}{rendered}"));
+                }
+                None => builder.insert(insert_offset, format!("\n\n{indent}{impl_def}")),
+            }
+        },
+    )
+}
+
+fn is_default_implemented(ctx: &AssistContext<'_>, strukt: &ast::Struct) -> bool {
+    let db = ctx.db();
+    let strukt = match ctx.sema.to_def(strukt) {
+        Some(it) => it,
+        None => return false,
+    };
+    let krate = strukt.module(db).krate();
+    match FamousDefs(&ctx.sema, krate).core_default_Default() {
+        Some(default_trait) => strukt.ty(db).impls_trait(db, default_trait, &[]),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn record_struct() {
+        check_assist(
+            generate_default,
+            r#"
+//- minicore: default
+struct $0Foo {
+    bar: u32,
+    baz: String,
+}
+"#,
+            r#"
+struct Foo {
+    bar: u32,
+    baz: String,
+}
+
+impl Default for Foo {
+    fn default() -> Self {
+        $0Self { bar: Default::default(), baz: Default::default() }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn empty_record_struct() {
+        check_assist(
+            generate_default,
+            r#"
+struct $0Foo {}
+"#,
+            r#"
+struct Foo {}
+
+impl Default for Foo {
+    fn default() -> Self {
+        $0Self {}
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn tuple_struct() {
+        check_assist(
+            generate_default,
+            r#"
+struct $0Foo(u32, bool);
+"#,
+            r#"
+struct Foo(u32, bool);
+
+impl Default for Foo {
+    fn default() -> Self {
+        $0Self(Default::default(), Default::default())
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn unit_struct() {
+        check_assist(
+            generate_default,
+            r#"
+struct Foo$0;
+"#,
+            r#"
+struct Foo;
+
+impl Default for Foo {
+    fn default() -> Self {
+        $0Self
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn generic_struct() {
+        check_assist(
+            generate_default,
+            r#"
+struct Foo$0<T: Clone> {
+    bar: T,
+}
+"#,
+            r#"
+struct Foo<T: Clone> {
+    bar: T,
+}
+
+impl<T: Clone + Default> Default for Foo<T> {
+    fn default() -> Self {
+        $0Self { bar: Default::default() }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn struct_in_module() {
+        check_assist(
+            generate_default,
+            r#"
+mod foo {
+    struct $0Foo {
+        bar: u32,
+    }
+}
+"#,
+            r#"
+mod foo {
+    struct Foo {
+        bar: u32,
+    }
+
+    impl Default for Foo {
+        fn default() -> Self {
+            $0Self { bar: Default::default() }
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_in_field_list() {
+        check_assist_not_applicable(
+            generate_default,
+            r#"
+struct Foo {
+    $0bar: u32,
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_already_implemented() {
+        cov_mark::check!(default_is_already_implemented);
+        check_assist_not_applicable(
+            generate_default,
+            r#"
+//- minicore: default, derive
+#[derive(Default)]
+struct Foo$0 {
+    bar: u32,
+}
+"#,
+        );
+    }
+}
@@ -1,10 +1,9 @@
 use syntax::{
-    ast::{self, AstNode, HasAttrs},
-    SyntaxKind::{COMMENT, WHITESPACE},
+    ast::{self, edit::IndentLevel, AstNode, HasAttrs},
     TextSize,
 };
 
-use crate::{AssistContext, AssistId, AssistKind, Assists};
+use crate::{utils::attr_insertion_offset, AssistContext, AssistId, AssistKind, Assists};
 
 // Assist: generate_derive
 //
@@ -27,7 +26,7 @@
 pub(crate) fn generate_derive(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
     let cap = ctx.config.snippet_cap?;
     let nominal = ctx.find_node_at_offset::<ast::Adt>()?;
-    let node_start = derive_insertion_offset(&nominal)?;
+    let node_start = attr_insertion_offset(&nominal)?;
     let target = nominal.syntax().text_range();
     acc.add(
         AssistId("generate_derive", AssistKind::Generate),
@@ -42,7 +41,8 @@ pub(crate) fn generate_derive(acc: &mut Assists, ctx: &AssistContext<'_>) -> Opt
                 .next();
             match derive_attr {
                 None => {
-                    builder.insert_snippet(cap, node_start, "#[derive($0)]\n");
+                    let indent = IndentLevel::from_node(nominal.syntax());
+                    builder.insert_snippet(cap, node_start, format!("#[derive($0)]\n{indent}"));
                 }
                 Some(tt) => {
                     // Just move the cursor.
@@ -57,15 +57,6 @@ pub(crate) fn generate_derive(acc: &mut Assists, ctx: &AssistContext<'_>) -> Opt
     )
 }
 
-// Insert `derive` after doc comments.
-fn derive_insertion_offset(nominal: &ast::Adt) -> Option<TextSize> {
-    let non_ws_child = nominal
-        .syntax()
-        .children_with_tokens()
-        .find(|it| it.kind() != COMMENT && it.kind() != WHITESPACE)?;
-    Some(non_ws_child.text_range().start())
-}
-
 #[cfg(test)]
 mod tests {
     use crate::tests::{check_assist, check_assist_target};
@@ -113,6 +104,62 @@ struct Foo { a: i32, }
         );
     }
 
+    #[test]
+    fn add_derive_below_attributes() {
+        check_assist(
+            generate_derive,
+            "
+/// `Foo` is a pretty important struct.
+#[repr(C)]
+#[allow(dead_code)]
+struct Foo { a: i32$0, }
+            ",
+            "
+/// `Foo` is a pretty important struct.
+#[repr(C)]
+#[allow(dead_code)]
+#[derive($0)]
+struct Foo { a: i32, }
+            ",
+        );
+    }
+
+    #[test]
+    fn add_derive_above_doc_comment_after_attributes() {
+        check_assist(
+            generate_derive,
+            "
+#[repr(C)]
+/// `Foo` is a pretty important struct.
+struct Foo { a: i32$0, }
+            ",
+            "
+#[repr(C)]
+#[derive($0)]
+/// `Foo` is a pretty important struct.
+struct Foo { a: i32, }
+            ",
+        );
+    }
+
+    #[test]
+    fn add_derive_keeps_indentation() {
+        check_assist(
+            generate_derive,
+            "
+mod foo {
+    pub enum Foo { A$0 }
+}
+            ",
+            "
+mod foo {
+    #[derive($0)]
+    pub enum Foo { A }
+}
+            ",
+        );
+    }
+
     #[test]
     fn add_derive_target() {
         check_assist_target(
@@ -0,0 +1,160 @@
+use hir::ModuleDef;
+use ide_db::{famous_defs::FamousDefs, helpers::mod_path_to_ast};
+use syntax::ast::{self, AstNode, HasName};
+
+use crate::{
+    utils::{impl_def_from_trait_intransitive, render_snippet, Cursor},
+    AssistContext, AssistId, AssistKind, Assists,
+};
+
+// Assist: generate_display_impl
+//
+// Adds a `Display` impl for an enum, writing the name of each variant as a placeholder.
+//
+// ```
+// # //- minicore: fmt
+// enum Shape$0 {
+//     Circle { r: f64 },
+//     Square(f64),
+//     Empty,
+// }
+// ```
+// ->
+// ```
+// enum Shape {
+//     Circle { r: f64 },
+//     Square(f64),
+//     Empty,
+// }
+//
+// impl core::fmt::Display for Shape {
+//     $0fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+//         match self {
+//             Self::Circle { .. } => write!(f, "Circle"),
+//             Self::Square(..) => write!(f, "Square"),
+//             Self::Empty => write!(f, "Empty"),
+//         }
+//     }
+// }
+// ```
+pub(crate) fn generate_display_impl(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let enum_ = ctx.find_node_at_offset::<ast::Enum>()?;
+    let variant_list = enum_.variant_list()?;
+    // Variants have assists of their own.
+    if variant_list.syntax().text_range().contains_inclusive(ctx.offset()) {
+        return None;
+    }
+    // `match self {}` isn't exhaustive for references to empty enums.
+    variant_list.variants().next()?;
+    let name = enum_.name()?;
+
+    let module = ctx.sema.scope(enum_.syntax())?.module();
+    let display_trait = FamousDefs(&ctx.sema, module.krate()).core_fmt_Display()?;
+    let enum_def = ctx.sema.to_def(&enum_)?;
+    if enum_def.ty(ctx.db()).impls_trait(ctx.db(), display_trait, &[]) {
+        cov_mark::hit!(display_is_already_implemented);
+        return None;
+    }
+
+    let trait_path = module
+        .find_use_path(ctx.db(), ModuleDef::Trait(display_trait), ctx.config.prefer_no_std)
+        .as_ref()
+        .map(mod_path_to_ast)?;
+    let adt = ast::Adt::Enum(enum_.clone());
+    // The placeholders only write the names of the variants, so the generic parameters don't
+    // need to implement `Display`.
+    let (impl_def, first_assoc_item) =
+        impl_def_from_trait_intransitive(ctx, &adt, &name, Some(display_trait), &trait_path)?;
+
+    let target = enum_.syntax().text_range();
+    acc.add(
+        AssistId("generate_display_impl", AssistKind::Generate),
+        "Generate `Display` impl",
+        target,
+        |builder| {
+            let insert_offset = enum_.syntax().text_range().end();
+            match ctx.config.snippet_cap {
+                Some(cap) => {
+                    let cursor = Cursor::Before(first_assoc_item.syntax());
+                    let rendered = render_snippet(cap, impl_def.syntax(), cursor);
+                    builder.insert_snippet(cap, insert_offset, format!("\n\n{rendered}"));
+                }
+                None => builder.insert(insert_offset, format!("\n\n{impl_def}")),
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn generic_enum() {
+        check_assist(
+            generate_display_impl,
+            r#"
+//- minicore: fmt
+enum $0Either<L, R> {
+    Left(L),
+    Right(R),
+}
+"#,
+            r#"
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> core::fmt::Display for Either<L, R> {
+    $0fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Left(..) => write!(f, "Left"),
+            Self::Right(..) => write!(f, "Right"),
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_structs_or_variants() {
+        check_assist_not_applicable(
+            generate_display_impl,
+            r#"
+//- minicore: fmt
+struct Foo$0;
+"#,
+        );
+        check_assist_not_applicable(
+            generate_display_impl,
+            r#"
+//- minicore: fmt
+enum Foo {
+    $0Bar,
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_already_implemented() {
+        cov_mark::check!(display_is_already_implemented);
+        check_assist_not_applicable(
+            generate_display_impl,
+            r#"
+//- minicore: fmt
+enum Foo$0 { Bar }
+
+impl core::fmt::Display for Foo {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Foo")
+    }
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use hir::HasVisibility;
+use itertools::Itertools;
+use syntax::ast::{self, edit::IndentLevel, AstNode, HasName};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: generate_fields_from_params
+//
+// Fills the empty body of a constructor with a `Self` literal built from the parameters
+// named after fields. In a method taking `&mut self`, generates field assignments instead.
+//
+// ```
+// struct Point { x: i32, y: i32, label: String }
+// impl Point {
+//     fn new(x: i32, y: i32) -> Self {$0}
+// }
+// ```
+// ->
+// ```
+// struct Point { x: i32, y: i32, label: String }
+// impl Point {
+//     fn new(x: i32, y: i32) -> Self {
+//         Self { x, y, label: ${0:todo!()} }
+//     }
+// }
+// ```
+pub(crate) fn generate_fields_from_params(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let fn_ = ctx.find_node_at_offset::<ast::Fn>()?;
+    let stmt_list = fn_.body()?.stmt_list()?;
+    if !stmt_list.syntax().text_range().contains_range(ctx.selection_trimmed()) {
+        return None;
+    }
+    // The generated code replaces the body, so don't offer it once something has been written.
+    if stmt_list.statements().next().is_some() || stmt_list.tail_expr().is_some() {
+        return None;
+    }
+    let impl_ = fn_
+        .syntax()
+        .parent()
+        .and_then(ast::AssocItemList::cast)?
+        .syntax()
+        .parent()
+        .and_then(ast::Impl::cast)?;
+    let strukt = match ctx.sema.to_def(&impl_)?.self_ty(ctx.db()).as_adt()? {
+        hir::Adt::Struct(it) if it.kind(ctx.db()) == hir::StructKind::Record => it,
+        _ => return None,
+    };
+
+    let param_list = fn_.param_list()?;
+    let param_names = param_list
+        .params()
+        .filter_map(|it| match it.pat()? {
+            ast::Pat::IdentPat(it) => Some(it.name()?.to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let fields = strukt
+        .fields(ctx.db())
+        .into_iter()
+        .map(|it| {
+            let name = it.name(ctx.db()).to_smol_str();
+            let is_param = param_names.iter().any(|param| param.as_str() == name.as_str());
+            (it, name, is_param)
+        })
+        .collect::<Vec<_>>();
+    if !fields.iter().any(|(_, _, is_param)| *is_param) {
+        return None;
+    }
+
+    let indent = IndentLevel::from_node(fn_.syntax());
+    let target = stmt_list.syntax().text_range();
+    let id = AssistId("generate_fields_from_params", AssistKind::Generate);
+    if let Some(self_param) = param_list.self_param() {
+        if self_param.kind() != ast::SelfParamKind::MutRef && self_param.mut_token().is_none() {
+            return None;
+        }
+        return acc.add(id, "Generate field assignments from parameters", target, |builder| {
+            let assignments = fields
+                .iter()
+                .filter(|(_, _, is_param)| *is_param)
+                .map(|(_, name, _)| format!("{}self.{name} = {name};\n", indent + 1))
+                .collect::<String>();
+            builder.replace(target, format!("{{\n{assignments}{indent}}}"))
+        });
+    }
+
+    let ret_ty = ctx.sema.resolve_type(&fn_.ret_type()?.ty()?)?;
+    if ret_ty.as_adt() != Some(hir::Adt::Struct(strukt)) {
+        return None;
+    }
+    let module = ctx.sema.scope(fn_.syntax())?.module();
+    if !fields.iter().all(|(it, _, _)| it.is_visible_from(ctx.db(), module)) {
+        return None;
+    }
+    acc.add(id, "Generate `Self` from parameters", target, |builder| {
+        let mut placeholder = ctx.config.snippet_cap.map(|_| "${0:todo!()}");
+        let fields = fields
+            .iter()
+            .map(|(_, name, is_param)| match is_param {
+                true => name.to_string(),
+                false => format!("{name}: {}", placeholder.take().unwrap_or("todo!()")),
+            })
+            .join(", ");
+        let text = format!("{{\n{}Self {{ {fields} }}\n{indent}}}", indent + 1);
+        match ctx.config.snippet_cap.filter(|_| placeholder.is_none()) {
+            Some(cap) => builder.replace_snippet(cap, target, text),
+            None => builder.replace(target, text),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn all_fields_are_params() {
+        check_assist(
+            generate_fields_from_params,
+            r#"
+struct S<T> { a: T, b: u8 }
+impl<T> S<T> {
+    pub fn new(b: u8, a: T) -> S<T> {
+        $0
+    }
+}
+"#,
+            r#"
+struct S<T> { a: T, b: u8 }
+impl<T> S<T> {
+    pub fn new(b: u8, a: T) -> S<T> {
+        Self { a, b }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn assignments_in_mut_method() {
+        check_assist(
+            generate_fields_from_params,
+            r#"
+struct S { a: u8, b: u8, c: u8 }
+impl S {
+    fn reset(&mut self, c: u8, other: u8, a: u8) {$0}
+}
+"#,
+            r#"
+struct S { a: u8, b: u8, c: u8 }
+impl S {
+    fn reset(&mut self, c: u8, other: u8, a: u8) {
+        self.a = a;
+        self.c = c;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable() {
+        // No parameter is named after a field.
+        check_assist_not_applicable(
+            generate_fields_from_params,
+            "struct S { a: u8 }\nimpl S { fn new(x: u8) -> Self {$0} }",
+        );
+        // The body isn't empty.
+        check_assist_not_applicable(
+            generate_fields_from_params,
+            "struct S { a: u8 }\nimpl S { fn new(a: u8) -> Self { $0todo!() } }",
+        );
+        // Doesn't return the struct.
+        check_assist_not_applicable(
+            generate_fields_from_params,
+            "struct S { a: u8 }\nimpl S { fn check(a: u8) -> bool {$0} }",
+        );
+        // Can't assign through `&self`.
+        check_assist_not_applicable(
+            generate_fields_from_params,
+            "struct S { a: u8 }\nimpl S { fn set(&self, a: u8) {$0} }",
+        );
+        check_assist_not_applicable(
+            generate_fields_from_params,
+            "struct S(u8);\nimpl S { fn new(a: u8) -> Self {$0} }",
+        );
+    }
+}
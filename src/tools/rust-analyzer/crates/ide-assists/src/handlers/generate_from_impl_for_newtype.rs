@@ -0,0 +1,218 @@
+use hir::HirDisplay;
+use ide_db::famous_defs::FamousDefs;
+use itertools::Itertools;
+use syntax::ast::{self, AstNode};
+
+use crate::{
+    utils::generate_trait_impl_text_intransitive, AssistContext, AssistId, AssistKind, Assists,
+};
+
+// Assist: generate_from_impl_for_newtype
+//
+// Adds a From impl for a tuple struct, taking a tuple if the struct has several fields.
+//
+// ```
+// # //- minicore: from
+// struct $0Meters(f64);
+// ```
+// ->
+// ```
+// struct Meters(f64);
+//
+// impl From<f64> for Meters {
+//     fn from(v: f64) -> Self {
+//         Self(v)
+//     }
+// }
+// ```
+pub(crate) fn generate_from_impl_for_newtype(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let strukt = ctx.find_node_at_offset::<ast::Struct>()?;
+    let field_list = match strukt.kind() {
+        ast::StructKind::Tuple(it) => it,
+        _ => return None,
+    };
+    let field_count = field_list.fields().count();
+    if field_count == 0 {
+        return None;
+    }
+
+    let def = ctx.sema.to_def(&strukt)?;
+    let module = def.module(ctx.db());
+    let field_tys: Vec<_> = def.fields(ctx.db()).into_iter().map(|it| it.ty(ctx.db())).collect();
+    if existing_from_impl(ctx, def, &field_tys)? {
+        cov_mark::hit!(generate_from_impl_for_newtype_already_exists);
+        return None;
+    }
+
+    // Types that don't resolve are spelled as written.
+    let field_ty_texts = field_list
+        .fields()
+        .zip(&field_tys)
+        .map(|(field, ty)| match ty.display_source_code(ctx.db(), module.into()) {
+            Ok(it) if !ty.contains_unknown() => Some(it),
+            _ => field.ty().map(|it| it.to_string()),
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let target = strukt.syntax().text_range();
+    acc.add(
+        AssistId("generate_from_impl_for_newtype", AssistKind::Generate),
+        "Generate `From` impl for this struct",
+        target,
+        |edit| {
+            let (param, param_ty, args) = match &*field_ty_texts {
+                [ty] => ("v".to_owned(), ty.clone(), "v".to_owned()),
+                tys => {
+                    let names = (0..tys.len()).map(|idx| format!("v{idx}")).join(", ");
+                    (format!("({names})"), format!("({})", tys.join(", ")), names)
+                }
+            };
+            let from_trait = format!("From<{param_ty}>");
+            let impl_code = format!(
+                r#"    fn from({param}: {param_ty}) -> Self {{
+        Self({args})
+    }}"#
+            );
+            let adt = ast::Adt::Struct(strukt.clone());
+            let from_impl = generate_trait_impl_text_intransitive(&adt, &from_trait, &impl_code);
+            edit.insert(strukt.syntax().text_range().end(), from_impl);
+        },
+    )
+}
+
+/// Checks whether `strukt` already implements `From` for its field, or for the tuple of its
+/// fields.
+fn existing_from_impl(
+    ctx: &AssistContext<'_>,
+    strukt: hir::Struct,
+    field_tys: &[hir::Type],
+) -> Option<bool> {
+    let krate = strukt.module(ctx.db()).krate();
+    let from_trait = FamousDefs(&ctx.sema, krate).core_convert_From()?;
+    let struct_ty = strukt.ty(ctx.db());
+    if let [field_ty] = field_tys {
+        return Some(struct_ty.impls_trait(ctx.db(), from_trait, &[field_ty.clone()]));
+    }
+
+    // There's no way to build a tuple type to ask the trait solver, so this looks at the
+    // arguments of the `From` impls written for the struct instead.
+    let exists = hir::Impl::all_for_type(ctx.db(), struct_ty)
+        .into_iter()
+        .filter(|it| it.trait_(ctx.db()) == Some(from_trait))
+        .filter_map(|it| ctx.sema.source(it))
+        .filter_map(|it| it.value.trait_())
+        .filter_map(|trait_| match trait_ {
+            ast::Type::PathType(it) => it.path()?.segment()?.generic_arg_list(),
+            _ => None,
+        })
+        .filter_map(|it| match it.generic_args().next()? {
+            ast::GenericArg::TypeArg(it) => ctx.sema.resolve_type(&it.ty()?),
+            _ => None,
+        })
+        .any(|arg| {
+            let arg_fields = arg.tuple_fields(ctx.db());
+            arg_fields.len() == field_tys.len()
+                && arg_fields.iter().zip(field_tys).all(|(a, b)| a.could_unify_with(ctx.db(), b))
+        });
+    Some(exists)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn generate_for_newtype_with_full_path() {
+        check_assist(
+            generate_from_impl_for_newtype,
+            r#"
+//- minicore: from
+mod units {
+    pub struct Unit;
+}
+use units::Unit;
+struct $0Meters(Unit);
+"#,
+            r#"
+mod units {
+    pub struct Unit;
+}
+use units::Unit;
+struct Meters(Unit);
+
+impl From<Unit> for Meters {
+    fn from(v: Unit) -> Self {
+        Self(v)
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn generate_for_multiple_fields() {
+        check_assist(
+            generate_from_impl_for_newtype,
+            r#"
+//- minicore: from
+struct Point<T>(T, $0T);
+"#,
+            r#"
+struct Point<T>(T, T);
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((v0, v1): (T, T)) -> Self {
+        Self(v0, v1)
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_impl_exists() {
+        cov_mark::check!(generate_from_impl_for_newtype_already_exists);
+        check_assist_not_applicable(
+            generate_from_impl_for_newtype,
+            r#"
+//- minicore: from
+struct $0Meters(f64);
+
+impl From<f64> for Meters {
+    fn from(v: f64) -> Self {
+        Self(v)
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_tuple_impl_exists() {
+        check_assist_not_applicable(
+            generate_from_impl_for_newtype,
+            r#"
+//- minicore: from
+struct $0Point(u32, u32);
+
+impl From<(u32, u32)> for Point {
+    fn from((x, y): (u32, u32)) -> Self {
+        Self(x, y)
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_structs() {
+        check_assist_not_applicable(generate_from_impl_for_newtype, "struct $0S { x: u32 }");
+        check_assist_not_applicable(generate_from_impl_for_newtype, "struct $0S();");
+        check_assist_not_applicable(generate_from_impl_for_newtype, "struct $0S;");
+    }
+}
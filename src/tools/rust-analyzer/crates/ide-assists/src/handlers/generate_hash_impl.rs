@@ -0,0 +1,184 @@
+use hir::ModuleDef;
+use ide_db::{famous_defs::FamousDefs, helpers::mod_path_to_ast};
+use syntax::ast::{self, AstNode, HasGenericParams, HasName};
+
+use crate::{
+    utils::{impl_def_from_trait, render_snippet, Cursor},
+    AssistContext, AssistId, AssistKind, Assists,
+};
+
+// Assist: generate_hash_impl
+//
+// Adds a `Hash` impl for a struct, hashing its fields in declaration order.
+//
+// ```
+// # //- minicore: hash
+// struct Id;
+// impl core::hash::Hash for Id {
+//     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {}
+// }
+// struct Key$0 {
+//     id: Id,
+//     parent: Id,
+// }
+// ```
+// ->
+// ```
+// struct Id;
+// impl core::hash::Hash for Id {
+//     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {}
+// }
+// struct Key {
+//     id: Id,
+//     parent: Id,
+// }
+//
+// impl core::hash::Hash for Key {
+//     $0fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+//         self.id.hash(state);
+//         self.parent.hash(state);
+//     }
+// }
+// ```
+pub(crate) fn generate_hash_impl(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let strukt = ctx.find_node_at_offset::<ast::Struct>()?;
+    let field_list = strukt.field_list()?;
+    // Fields have assists of their own. A cursor right after the name of a tuple struct touches
+    // its field list, but is still on the name.
+    let fields_range = field_list.syntax().text_range();
+    if fields_range.start() < ctx.offset() && ctx.offset() <= fields_range.end() {
+        return None;
+    }
+    let name = strukt.name()?;
+
+    let module = ctx.sema.scope(strukt.syntax())?.module();
+    let hash_trait = FamousDefs(&ctx.sema, module.krate()).core_hash_Hash()?;
+    let strukt_def = ctx.sema.to_def(&strukt)?;
+    if strukt_def.ty(ctx.db()).impls_trait(ctx.db(), hash_trait, &[]) {
+        cov_mark::hit!(hash_is_already_implemented);
+        return None;
+    }
+    let fields = strukt_def.fields(ctx.db());
+    if fields.is_empty() {
+        return None;
+    }
+    // Fields involving the struct's type parameters are covered by the bounds of the impl.
+    let type_params = strukt
+        .generic_param_list()
+        .into_iter()
+        .flat_map(|it| it.type_or_const_params())
+        .filter_map(|it| it.name())
+        .collect::<Vec<_>>();
+    let is_generic = |ty: &ast::Type| {
+        ty.syntax()
+            .descendants()
+            .filter_map(ast::NameRef::cast)
+            .any(|name_ref| type_params.iter().any(|it| it.text() == name_ref.text()))
+    };
+    let field_tys = match &field_list {
+        ast::FieldList::RecordFieldList(it) => it.fields().map(|it| it.ty()).collect::<Vec<_>>(),
+        ast::FieldList::TupleFieldList(it) => it.fields().map(|it| it.ty()).collect(),
+    };
+    let field_is_hash = |(field, field_ty): (&hir::Field, &Option<ast::Type>)| {
+        let ty = field.ty(ctx.db());
+        field_ty.as_ref().map_or(false, is_generic)
+            || ty.contains_unknown()
+            || ty.impls_trait(ctx.db(), hash_trait, &[])
+    };
+    if !fields.iter().zip(&field_tys).all(field_is_hash) {
+        cov_mark::hit!(hash_field_is_not_hash);
+        return None;
+    }
+
+    let trait_path = module
+        .find_use_path(ctx.db(), ModuleDef::Trait(hash_trait), ctx.config.prefer_no_std)
+        .as_ref()
+        .map(mod_path_to_ast)?;
+    let adt = ast::Adt::Struct(strukt.clone());
+    let (impl_def, first_assoc_item) =
+        impl_def_from_trait(ctx, &adt, &name, Some(hash_trait), &trait_path)?;
+
+    let target = strukt.syntax().text_range();
+    acc.add(
+        AssistId("generate_hash_impl", AssistKind::Generate),
+        "Generate `Hash` impl",
+        target,
+        |builder| {
+            let insert_offset = strukt.syntax().text_range().end();
+            match ctx.config.snippet_cap {
+                Some(cap) => {
+                    let cursor = Cursor::Before(first_assoc_item.syntax());
+                    let rendered = render_snippet(cap, impl_def.syntax(), cursor);
+                    builder.insert_snippet(cap, insert_offset, format!("\n\n{rendered}"));
+                }
+                None => builder.insert(insert_offset, format!("\n\n{impl_def}")),
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn generic_tuple_struct() {
+        check_assist(
+            generate_hash_impl,
+            r#"
+//- minicore: hash
+struct Id;
+impl core::hash::Hash for Id {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {}
+}
+struct $0Tagged<'a, T>(&'a T, Id);
+"#,
+            r#"
+struct Id;
+impl core::hash::Hash for Id {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {}
+}
+struct Tagged<'a, T>(&'a T, Id);
+
+impl<'a, T: core::hash::Hash> core::hash::Hash for Tagged<'a, T> {
+    $0fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+        self.1.hash(state);
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_a_field_is_not_hash() {
+        cov_mark::check!(hash_field_is_not_hash);
+        check_assist_not_applicable(
+            generate_hash_impl,
+            r#"
+//- minicore: hash
+struct NotHash;
+struct S$0 {
+    a: NotHash,
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_already_implemented() {
+        cov_mark::check!(hash_is_already_implemented);
+        check_assist_not_applicable(
+            generate_hash_impl,
+            r#"
+//- minicore: hash
+struct S$0(u32);
+impl core::hash::Hash for S {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {}
+}
+"#,
+        );
+    }
+}
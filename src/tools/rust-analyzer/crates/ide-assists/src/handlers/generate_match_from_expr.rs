@@ -0,0 +1,219 @@
+use hir::{ModuleDef, StructKind};
+use ide_db::helpers::mod_path_to_ast;
+use itertools::Itertools;
+use syntax::{
+    ast::{self, edit::IndentLevel, AstNode},
+    match_ast,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: generate_match_from_expr
+//
+// Generates a `match` with an arm for each variant of the enum an expression evaluates to.
+//
+// ```
+// enum Shape { Circle(f64), Rect { w: f64, h: f64 }, Empty }
+//
+// fn area(s: Shape) -> f64 {
+//     $0s
+// }
+// ```
+// ->
+// ```
+// enum Shape { Circle(f64), Rect { w: f64, h: f64 }, Empty }
+//
+// fn area(s: Shape) -> f64 {
+//     match s {
+//         $0Shape::Circle(..) => todo!(),
+//         Shape::Rect { .. } => todo!(),
+//         Shape::Empty => todo!(),
+//     }
+// }
+// ```
+pub(crate) fn generate_match_from_expr(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let expr = ctx
+        .find_node_at_offset::<ast::Expr>()?
+        .syntax()
+        .ancestors()
+        .take_while(|it| !ast::Item::can_cast(it.kind()))
+        .filter_map(ast::Expr::cast)
+        .find(is_in_statement_position)?;
+    // `match` and `if` already are control flow, and blocks would rather be matched on their
+    // tail expression.
+    if matches!(
+        expr,
+        ast::Expr::MatchExpr(_)
+            | ast::Expr::IfExpr(_)
+            | ast::Expr::BlockExpr(_)
+            | ast::Expr::LoopExpr(_)
+            | ast::Expr::WhileExpr(_)
+            | ast::Expr::ForExpr(_)
+    ) {
+        return None;
+    }
+
+    let ty = ctx.sema.type_of_expr(&expr)?.original.strip_references();
+    let enum_ = match ty.as_adt()? {
+        hir::Adt::Enum(it) => it,
+        _ => return None,
+    };
+    let module = ctx.sema.scope(expr.syntax())?.module();
+    let pats = enum_
+        .variants(ctx.db())
+        .into_iter()
+        .map(|variant| {
+            let path = module.find_use_path(
+                ctx.db(),
+                ModuleDef::from(variant),
+                ctx.config.prefer_no_std,
+            )?;
+            let path = mod_path_to_ast(&path);
+            Some(match variant.kind(ctx.db()) {
+                StructKind::Tuple => format!("{path}(..)"),
+                StructKind::Record => format!("{path} {{ .. }}"),
+                StructKind::Unit => path.to_string(),
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+    if pats.is_empty() {
+        return None;
+    }
+
+    // Statements are replaced as a whole, so that no `;` is left after the match.
+    let stmt = expr.syntax().parent().and_then(ast::ExprStmt::cast);
+    let target = stmt.as_ref().map_or(expr.syntax().text_range(), |it| it.syntax().text_range());
+    acc.add(
+        AssistId("generate_match_from_expr", AssistKind::Generate),
+        "Generate match on this value",
+        target,
+        |builder| {
+            let indent = IndentLevel::from_node(expr.syntax());
+            let cursor = if ctx.config.snippet_cap.is_some() { "$0" } else { "" };
+            let arms = pats
+                .iter()
+                .enumerate()
+                .map(|(idx, pat)| {
+                    let cursor = if idx == 0 { cursor } else { "" };
+                    format!("{}{cursor}{pat} => todo!(),", indent + 1)
+                })
+                .join("\n");
+            let match_expr = format!("match {expr} {{\n{arms}\n{indent}}}");
+            match ctx.config.snippet_cap {
+                Some(cap) => builder.replace_snippet(cap, target, match_expr),
+                None => builder.replace(target, match_expr),
+            }
+        },
+    )
+}
+
+/// Checks whether `expr` is a statement on its own, the tail of a block or the initializer of a
+/// `let`, where a `match` can be put without extra parentheses.
+fn is_in_statement_position(expr: &ast::Expr) -> bool {
+    let parent = match expr.syntax().parent() {
+        Some(it) => it,
+        None => return false,
+    };
+    match_ast! {
+        match parent {
+            ast::ExprStmt(_) => true,
+            ast::StmtList(it) => it.tail_expr().as_ref() == Some(expr),
+            ast::LetStmt(it) => it.initializer().as_ref() == Some(expr),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn generate_for_statement() {
+        check_assist(
+            generate_match_from_expr,
+            r#"
+mod m {
+    pub enum E { A, B(u32, u32) }
+    pub fn get() -> E { E::A }
+}
+fn main() {
+    m::get$0();
+}
+"#,
+            r#"
+mod m {
+    pub enum E { A, B(u32, u32) }
+    pub fn get() -> E { E::A }
+}
+fn main() {
+    match m::get() {
+        $0m::E::A => todo!(),
+        m::E::B(..) => todo!(),
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn generate_for_let_initializer_of_reference() {
+        check_assist(
+            generate_match_from_expr,
+            r#"
+enum E { A { x: u32 }, B }
+fn foo(e: &E) {
+    let x = $0e;
+}
+"#,
+            r#"
+enum E { A { x: u32 }, B }
+fn foo(e: &E) {
+    let x = match e {
+        $0E::A { .. } => todo!(),
+        E::B => todo!(),
+    };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_subexpressions_or_matches() {
+        check_assist_not_applicable(
+            generate_match_from_expr,
+            r#"
+enum E { A, B }
+fn foo(e: E) {
+    match $0e {
+        _ => {}
+    }
+}
+"#,
+        );
+        check_assist_not_applicable(
+            generate_match_from_expr,
+            r#"
+enum E { A, B }
+fn foo(e: E) -> u32 {
+    $0e as u32
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_non_enums() {
+        check_assist_not_applicable(
+            generate_match_from_expr,
+            r#"
+struct S;
+fn foo(s: S) {
+    $0s;
+}
+"#,
+        );
+    }
+}
@@ -292,6 +292,36 @@ fn $0new(baz: String, qux: Vec<i32>) -> Self { Self { baz, qux } }
         );
     }
 
+    #[test]
+    fn test_generate_new_with_where_clause() {
+        check_assist(
+            generate_new,
+            r#"
+struct Foo<T>
+where
+    T: Clone,
+{
+    bar: T,$0
+}
+"#,
+            r#"
+struct Foo<T>
+where
+    T: Clone,
+{
+    bar: T,
+}
+
+impl<T> Foo<T>
+where
+    T: Clone,
+{
+    fn $0new(bar: T) -> Self { Self { bar } }
+}
+"#,
+        );
+    }
+
     #[test]
     fn check_that_visibility_modifiers_dont_get_brought_in() {
         check_assist(
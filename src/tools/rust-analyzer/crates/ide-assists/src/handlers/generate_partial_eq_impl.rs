@@ -0,0 +1,243 @@
+use hir::ModuleDef;
+use ide_db::{assists::GroupLabel, famous_defs::FamousDefs, helpers::mod_path_to_ast};
+use syntax::{
+    ast::{self, make, AstNode, HasName},
+    ted, SyntaxNode,
+};
+
+use crate::{
+    assist_context::SourceChangeBuilder,
+    utils::{generate_trait_impl_text, impl_def_from_trait, render_snippet, Cursor},
+    AssistContext, AssistId, AssistKind, Assists,
+};
+
+// Assist: generate_partial_eq_impl
+//
+// Adds a `PartialEq` impl comparing all fields, to be adjusted where a derive doesn't fit.
+//
+// ```
+// # //- minicore: eq
+// struct Point$0 {
+//     x: i32,
+//     y: i32,
+// }
+// ```
+// ->
+// ```
+// struct Point {
+//     x: i32,
+//     y: i32,
+// }
+//
+// impl PartialEq for Point {
+//     $0fn eq(&self, other: &Self) -> bool {
+//         self.x == other.x && self.y == other.y
+//     }
+// }
+// ```
+
+// Assist: generate_partial_eq_and_eq_impls
+//
+// Adds a `PartialEq` impl comparing all fields, along with an `Eq` impl.
+//
+// ```
+// # //- minicore: eq
+// struct Point$0 {
+//     x: i32,
+//     y: i32,
+// }
+// ```
+// ->
+// ```
+// struct Point {
+//     x: i32,
+//     y: i32,
+// }
+//
+// impl PartialEq for Point {
+//     $0fn eq(&self, other: &Self) -> bool {
+//         self.x == other.x && self.y == other.y
+//     }
+// }
+//
+// impl Eq for Point {}
+// ```
+pub(crate) fn generate_partial_eq_impl(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let adt = ctx.find_node_at_offset::<ast::Adt>()?;
+    // Fields and variants have assists of their own. A cursor right after the name of a tuple
+    // struct touches its field list, but is still on the name.
+    let body = match &adt {
+        ast::Adt::Struct(it) => it.field_list().map(|it| it.syntax().clone()),
+        ast::Adt::Enum(it) => it.variant_list().map(|it| it.syntax().clone()),
+        ast::Adt::Union(_) => return None,
+    };
+    let in_body = |it: SyntaxNode| {
+        let range = it.text_range();
+        range.start() < ctx.offset() && ctx.offset() <= range.end()
+    };
+    if body.map_or(false, in_body) {
+        return None;
+    }
+    let name = adt.name()?;
+
+    let module = ctx.sema.scope(adt.syntax())?.module();
+    let famous_defs = FamousDefs(&ctx.sema, module.krate());
+    let partial_eq_trait = famous_defs.core_cmp_PartialEq()?;
+    let adt_ty = ctx.sema.to_def(&adt)?.ty(ctx.db());
+    if adt_ty.impls_trait(ctx.db(), partial_eq_trait, &[adt_ty.clone()]) {
+        cov_mark::hit!(partial_eq_is_already_implemented);
+        return None;
+    }
+    let trait_path = |trait_: hir::Trait| {
+        let path =
+            module.find_use_path(ctx.db(), ModuleDef::Trait(trait_), ctx.config.prefer_no_std);
+        path.as_ref().map(mod_path_to_ast)
+    };
+    let (impl_def, first_assoc_item) = impl_def_from_trait(
+        ctx,
+        &adt,
+        &name,
+        Some(partial_eq_trait),
+        &trait_path(partial_eq_trait)?,
+    )?;
+    let eq_impl_def = famous_defs
+        .core_cmp_Eq()
+        .filter(|it| !adt_ty.impls_trait(ctx.db(), *it, &[]))
+        .and_then(trait_path)
+        .and_then(|path| empty_impl(&adt, &path));
+
+    let target = adt.syntax().text_range();
+    let insert_offset = target.end();
+    let rendered = match ctx.config.snippet_cap {
+        Some(cap) => {
+            render_snippet(cap, impl_def.syntax(), Cursor::Before(first_assoc_item.syntax()))
+        }
+        None => impl_def.to_string(),
+    };
+    let insert = |builder: &mut SourceChangeBuilder, text: String| match ctx.config.snippet_cap {
+        Some(cap) => builder.insert_snippet(cap, insert_offset, text),
+        None => builder.insert(insert_offset, text),
+    };
+
+    let group = GroupLabel("Generate `PartialEq` impl".to_owned());
+    acc.add_group(
+        &group,
+        AssistId("generate_partial_eq_impl", AssistKind::Generate),
+        "Generate `PartialEq` impl",
+        target,
+        |builder| insert(builder, format!("\n\n{rendered}")),
+    );
+    let eq_impl_def = eq_impl_def?;
+    acc.add_group(
+        &group,
+        AssistId("generate_partial_eq_and_eq_impls", AssistKind::Generate),
+        "Generate `PartialEq` and `Eq` impls",
+        target,
+        |builder| insert(builder, format!("\n\n{rendered}\n\n{eq_impl_def}")),
+    )
+}
+
+/// Builds an `impl Trait for Adt {}` for a marker trait such as `Eq`.
+fn empty_impl(adt: &ast::Adt, trait_path: &ast::Path) -> Option<ast::Impl> {
+    let text = generate_trait_impl_text(adt, &trait_path.to_string(), "");
+    let impl_def = syntax::SourceFile::parse(&text)
+        .tree()
+        .syntax()
+        .descendants()
+        .find_map(ast::Impl::cast)?
+        .clone_for_update();
+    ted::replace(
+        impl_def.assoc_item_list()?.syntax(),
+        make::assoc_item_list().clone_for_update().syntax(),
+    );
+    Some(impl_def)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist_by_label, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn enum_with_generics() {
+        check_assist_by_label(
+            generate_partial_eq_impl,
+            r#"
+//- minicore: eq
+enum $0Either<L, R> {
+    Left(L),
+    Right { r: R },
+}
+"#,
+            r#"
+enum Either<L, R> {
+    Left(L),
+    Right { r: R },
+}
+
+impl<L: PartialEq, R: PartialEq> PartialEq for Either<L, R> {
+    $0fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Left(l0), Self::Left(r0)) => l0 == r0,
+            (Self::Right { r: l_r }, Self::Right { r: r_r }) => l_r == r_r,
+            _ => false,
+        }
+    }
+}
+
+impl<L: Eq, R: Eq> Eq for Either<L, R> {}
+"#,
+            "Generate `PartialEq` and `Eq` impls",
+        );
+    }
+
+    #[test]
+    fn tuple_struct_already_eq() {
+        check_assist_by_label(
+            generate_partial_eq_impl,
+            r#"
+//- minicore: eq
+struct S$0(u32, u32);
+impl Eq for S {}
+"#,
+            r#"
+struct S(u32, u32);
+
+impl PartialEq for S {
+    $0fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 && self.1 == other.1
+    }
+}
+impl Eq for S {}
+"#,
+            "Generate `PartialEq` impl",
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_already_implemented() {
+        cov_mark::check!(partial_eq_is_already_implemented);
+        check_assist_not_applicable(
+            generate_partial_eq_impl,
+            r#"
+//- minicore: eq
+struct S$0;
+impl PartialEq for S {
+    fn eq(&self, other: &Self) -> bool { true }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_in_fields() {
+        check_assist_not_applicable(
+            generate_partial_eq_impl,
+            r#"
+//- minicore: eq
+struct S { $0a: u32 }
+"#,
+        );
+    }
+}
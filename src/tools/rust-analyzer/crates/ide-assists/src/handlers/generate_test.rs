@@ -0,0 +1,201 @@
+use syntax::{
+    ast::{self, edit::IndentLevel, AstNode, HasModuleItem, HasName},
+    SyntaxKind, TextSize,
+};
+
+use crate::{utils::test_related_attribute, AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: generate_test
+//
+// Adds a test for a function, in the `tests` module next to it.
+//
+// ```
+// fn $0parse(s: &str) -> u32 {
+//     s.len() as u32
+// }
+// ```
+// ->
+// ```
+// fn parse(s: &str) -> u32 {
+//     s.len() as u32
+// }
+//
+// #[cfg(test)]
+// mod tests {
+//     use super::*;
+//
+//     #[test]
+//     fn test_parse() {
+//         $0
+//     }
+// }
+// ```
+pub(crate) fn generate_test(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let fn_ = ctx.find_node_at_offset::<ast::Fn>()?;
+    let name = fn_.name()?;
+    // Only offer it on the signature, and not for tests themselves.
+    if fn_.body()?.syntax().text_range().contains_range(ctx.selection_trimmed())
+        || test_related_attribute(&fn_).is_some()
+    {
+        return None;
+    }
+    let items = fn_.syntax().parent()?;
+    if !matches!(items.kind(), SyntaxKind::SOURCE_FILE | SyntaxKind::ITEM_LIST) {
+        return None;
+    }
+
+    let tests_module = items.children().filter_map(ast::Module::cast).find(|it| {
+        it.name().map_or(false, |name| name.text() == "tests") && it.item_list().is_some()
+    });
+    let test_name = format!("test_{name}");
+    let tests_item_list = tests_module.as_ref().and_then(|it| it.item_list());
+    let test_exists = tests_item_list.iter().flat_map(|it| it.items()).any(|it| match it {
+        ast::Item::Fn(it) => it.name().map_or(false, |name| name.text() == test_name),
+        _ => false,
+    });
+    if test_exists {
+        return None;
+    }
+
+    let target = fn_.syntax().text_range();
+    acc.add(
+        AssistId("generate_test", AssistKind::Generate),
+        format!("Generate test for `{name}`"),
+        target,
+        |builder| {
+            let cursor = if ctx.config.snippet_cap.is_some() { "$0" } else { "" };
+            let (offset, text) = match &tests_item_list {
+                Some(item_list) => {
+                    let indent = IndentLevel::from_node(item_list.syntax()) + 1;
+                    let test = test_fn_text(&test_name, indent, cursor);
+                    match item_list.items().last() {
+                        Some(last) => (last.syntax().text_range().end(), format!("\n\n{test}")),
+                        None => {
+                            let close_indent = IndentLevel::from_node(item_list.syntax());
+                            (list_start(item_list), format!("\n{test}\n{close_indent}"))
+                        }
+                    }
+                }
+                None => {
+                    let indent = IndentLevel::from_node(fn_.syntax());
+                    let test = test_fn_text(&test_name, indent + 1, cursor);
+                    let offset = items
+                        .children()
+                        .filter(|it| ast::Item::can_cast(it.kind()))
+                        .last()
+                        .map_or_else(|| fn_.syntax().text_range().end(), |it| it.text_range().end());
+                    let module = format!(
+                        "\n\n{indent}#[cfg(test)]\n{indent}mod tests {{\n{}use super::*;\n\n{test}\n{indent}}}",
+                        indent + 1
+                    );
+                    (offset, module)
+                }
+            };
+            match ctx.config.snippet_cap {
+                Some(cap) => builder.insert_snippet(cap, offset, text),
+                None => builder.insert(offset, text),
+            }
+        },
+    )
+}
+
+fn test_fn_text(name: &str, indent: IndentLevel, cursor: &str) -> String {
+    format!("{indent}#[test]\n{indent}fn {name}() {{\n{}{cursor}\n{indent}}}", indent + 1)
+}
+
+fn list_start(item_list: &ast::ItemList) -> TextSize {
+    item_list
+        .l_curly_token()
+        .map_or_else(|| item_list.syntax().text_range().start(), |it| it.text_range().end())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn add_to_existing_tests_module() {
+        check_assist(
+            generate_test,
+            r#"
+mod m {
+    pub fn $0foo() {}
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_bar() {}
+    }
+}
+"#,
+            r#"
+mod m {
+    pub fn foo() {}
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_bar() {}
+
+        #[test]
+        fn test_foo() {
+            $0
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn create_module_after_last_item() {
+        check_assist(
+            generate_test,
+            r#"
+mod m {
+    fn foo$0() {}
+    struct S;
+}
+"#,
+            r#"
+mod m {
+    fn foo() {}
+    struct S;
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_foo() {
+            $0
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable() {
+        check_assist_not_applicable(generate_test, "fn foo() { $0 }");
+        check_assist_not_applicable(generate_test, "#[test]\nfn $0foo() {}");
+        check_assist_not_applicable(generate_test, "struct S;\nimpl S { fn $0foo() {} }");
+        check_assist_not_applicable(
+            generate_test,
+            r#"
+fn $0foo() {}
+mod tests {
+    #[test]
+    fn test_foo() {}
+}
+"#,
+        );
+    }
+}
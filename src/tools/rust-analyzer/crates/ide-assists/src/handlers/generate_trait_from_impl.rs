@@ -0,0 +1,400 @@
+use hir::ModuleDef;
+use ide_db::{
+    defs::Definition,
+    helpers::mod_path_to_ast,
+    imports::insert_use::{insert_use, ImportScope},
+    FxHashSet,
+};
+use syntax::{
+    ast::{
+        self,
+        edit::{AstNodeEdit, IndentLevel},
+        make, HasGenericParams, HasVisibility,
+    },
+    ted, AstNode, SyntaxKind, TextRange, T,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: generate_trait_from_impl
+//
+// Generates a trait declaring the methods of an inherent impl, and turns the impl into an
+// implementation of that trait.
+//
+// ```
+// struct Foo;
+// impl$0 Foo {
+//     pub fn bar(&self) -> u32 {
+//         92
+//     }
+// }
+// ```
+// ->
+// ```
+// struct Foo;
+// pub trait ${0:FooTrait} {
+//     fn bar(&self) -> u32;
+// }
+//
+// impl FooTrait for Foo {
+//     fn bar(&self) -> u32 {
+//         92
+//     }
+// }
+// ```
+pub(crate) fn generate_trait_from_impl(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let impl_ = ctx.find_node_at_offset::<ast::Impl>()?;
+    let assoc_item_list = impl_.assoc_item_list()?;
+    // Only offer this on the header, so that it doesn't show up everywhere inside the methods.
+    if impl_.trait_().is_some()
+        || impl_.generic_param_list().is_some()
+        || ctx.offset() > assoc_item_list.syntax().text_range().start()
+    {
+        return None;
+    }
+    let self_ty = match impl_.self_ty()? {
+        ast::Type::PathType(it) => it,
+        _ => return None,
+    };
+    let self_name = self_ty.path()?.segment()?.name_ref()?;
+
+    let fns = assoc_item_list
+        .assoc_items()
+        .map(|item| match item {
+            ast::AssocItem::Fn(it) => Some(it),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>();
+    let fns = match fns {
+        Some(fns) if !fns.is_empty() => fns,
+        _ => {
+            cov_mark::hit!(generate_trait_from_impl_non_fn_items);
+            return None;
+        }
+    };
+
+    let trait_name = format!("{self_name}Trait");
+    let module = ctx.sema.to_def(&impl_)?.module(ctx.db());
+    if module.scope(ctx.db(), None).iter().any(|(name, _)| name.to_smol_str() == trait_name) {
+        cov_mark::hit!(generate_trait_from_impl_name_taken);
+        return None;
+    }
+    let usages = fns
+        .iter()
+        .filter_map(|it| ctx.sema.to_def(it))
+        .flat_map(|it| Definition::Function(it).usages(&ctx.sema).all())
+        .collect::<Vec<_>>();
+
+    let target = impl_.syntax().text_range();
+    acc.add(
+        AssistId("generate_trait_from_impl", AssistKind::Generate),
+        "Generate trait from impl",
+        target,
+        |builder| {
+            // The methods can only be called where the trait is in scope, which it isn't in other
+            // modules, not even the ones nested in its own.
+            let mut importing_modules = FxHashSet::default();
+            for (file_id, references) in usages {
+                builder.edit_file(file_id);
+                let imports = references
+                    .into_iter()
+                    .filter_map(|reference| {
+                        let node = reference.name.syntax();
+                        let usage_module = ctx.sema.scope(node)?.module();
+                        if usage_module == module || !importing_modules.insert(usage_module) {
+                            return None;
+                        }
+                        let module_path = usage_module.find_use_path_prefixed(
+                            ctx.db(),
+                            ModuleDef::Module(module),
+                            ctx.config.insert_use.prefix_kind,
+                            ctx.config.prefer_no_std,
+                        )?;
+                        let path = make::path_concat(
+                            mod_path_to_ast(&module_path),
+                            make::ext::ident_path(&trait_name),
+                        );
+                        let node = builder.make_syntax_mut(node.clone());
+                        Some((ImportScope::find_insert_use_container(&node, &ctx.sema)?, path))
+                    })
+                    .collect::<Vec<_>>();
+                for (scope, path) in imports {
+                    insert_use(&scope, path, &ctx.config.insert_use);
+                }
+            }
+            builder.edit_file(ctx.file_id());
+
+            let indent = IndentLevel::from_node(impl_.syntax());
+            let vis = fns
+                .iter()
+                .find_map(|it| it.visibility())
+                .map(|it| format!("{it} "))
+                .unwrap_or_default();
+            let mut items = String::new();
+            for fn_ in &fns {
+                if let Some(signature) = signature(fn_, indent + 1) {
+                    items.push_str(&format!("{}{signature}\n", indent + 1));
+                }
+            }
+            let for_text = format!("{trait_name} for ");
+            let name = match ctx.config.snippet_cap {
+                Some(_) => format!("${{0:{trait_name}}}"),
+                None => trait_name,
+            };
+            let trait_text = format!("{vis}trait {name} {{\n{items}{indent}}}\n\n{indent}");
+            let start = impl_.syntax().text_range().start();
+            match ctx.config.snippet_cap {
+                Some(cap) => builder.insert_snippet(cap, start, trait_text),
+                None => builder.insert(start, trait_text),
+            }
+
+            builder.insert(self_ty.syntax().text_range().start(), for_text);
+            // Items of trait impls take the visibility of the trait.
+            for vis in fns.iter().filter_map(|it| it.visibility()) {
+                let range = vis.syntax().text_range();
+                let range = match vis.syntax().next_sibling_or_token() {
+                    Some(ws) if ws.kind() == SyntaxKind::WHITESPACE => {
+                        TextRange::new(range.start(), ws.text_range().end())
+                    }
+                    _ => range,
+                };
+                builder.delete(range);
+            }
+        },
+    )
+}
+
+/// Turns `fn_` into a declaration for a trait, dropping its visibility and body.
+fn signature(fn_: &ast::Fn, indent: IndentLevel) -> Option<String> {
+    let fn_ = fn_.clone_for_update();
+    let body = fn_.body()?;
+    if let Some(vis) = fn_.visibility() {
+        if let Some(ws) = vis.syntax().next_sibling_or_token() {
+            if ws.kind() == SyntaxKind::WHITESPACE {
+                ted::remove(ws);
+            }
+        }
+        ted::remove(vis.syntax());
+    }
+    if let Some(ws) = body.syntax().prev_sibling_or_token() {
+        if ws.kind() == SyntaxKind::WHITESPACE {
+            ted::remove(ws);
+        }
+    }
+    ted::replace(body.syntax(), make::token(T![;]));
+    Some(fn_.reset_indent().indent(indent).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tests::{check_assist, check_assist_not_applicable, check_assist_with_config, TEST_CONFIG},
+        AssistConfig,
+    };
+
+    use super::*;
+
+    #[test]
+    fn generate_trait() {
+        check_assist(
+            generate_trait_from_impl,
+            r#"
+struct Foo(u32);
+$0impl Foo {
+    /// Returns the value.
+    fn get(&self) -> u32 {
+        self.0
+    }
+
+    fn set(&mut self, value: u32) {
+        self.0 = value;
+    }
+}
+"#,
+            r#"
+struct Foo(u32);
+trait ${0:FooTrait} {
+    /// Returns the value.
+    fn get(&self) -> u32;
+    fn set(&mut self, value: u32);
+}
+
+impl FooTrait for Foo {
+    /// Returns the value.
+    fn get(&self) -> u32 {
+        self.0
+    }
+
+    fn set(&mut self, value: u32) {
+        self.0 = value;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn generate_trait_strips_visibility() {
+        check_assist(
+            generate_trait_from_impl,
+            r#"
+mod m {
+    pub struct Foo;
+    impl Foo$0 {
+        pub(crate) fn new() -> Foo {
+            Foo
+        }
+    }
+}
+"#,
+            r#"
+mod m {
+    pub struct Foo;
+    pub(crate) trait ${0:FooTrait} {
+        fn new() -> Foo;
+    }
+
+    impl FooTrait for Foo {
+        fn new() -> Foo {
+            Foo
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn generate_trait_without_snippet_cap() {
+        check_assist_with_config(
+            generate_trait_from_impl,
+            AssistConfig { snippet_cap: None, ..TEST_CONFIG },
+            r#"
+struct Foo;
+impl$0 Foo {
+    fn foo() {}
+}
+"#,
+            r#"
+struct Foo;
+trait FooTrait {
+    fn foo();
+}
+
+impl FooTrait for Foo {
+    fn foo() {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_other_items() {
+        cov_mark::check!(generate_trait_from_impl_non_fn_items);
+        check_assist_not_applicable(
+            generate_trait_from_impl,
+            r#"
+struct Foo;
+impl$0 Foo {
+    const C: u32 = 0;
+    fn foo() {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_inside_items_or_to_trait_impls() {
+        check_assist_not_applicable(
+            generate_trait_from_impl,
+            r#"
+struct Foo;
+impl Foo {
+    fn foo() {$0}
+}
+"#,
+        );
+        check_assist_not_applicable(
+            generate_trait_from_impl,
+            r#"
+trait Trait { fn foo(); }
+struct Foo;
+impl$0 Trait for Foo {
+    fn foo() {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn generate_trait_imports_it_for_callers_in_other_modules() {
+        check_assist(
+            generate_trait_from_impl,
+            r#"
+//- /main.rs
+mod m;
+struct Foo;
+impl$0 Foo {
+    pub fn bar(&self) {}
+}
+fn f(foo: Foo) {
+    foo.bar();
+}
+mod inner {
+    fn g(foo: crate::Foo) {
+        foo.bar();
+        crate::Foo::bar(&foo);
+    }
+}
+//- /m.rs
+fn h(foo: crate::Foo) {
+    foo.bar();
+}
+"#,
+            r#"
+//- /main.rs
+mod m;
+struct Foo;
+pub trait ${0:FooTrait} {
+    fn bar(&self);
+}
+
+impl FooTrait for Foo {
+    fn bar(&self) {}
+}
+fn f(foo: Foo) {
+    foo.bar();
+}
+mod inner {
+    use crate::FooTrait;
+
+    fn g(foo: crate::Foo) {
+        foo.bar();
+        crate::Foo::bar(&foo);
+    }
+}
+//- /m.rs
+use crate::FooTrait;
+
+fn h(foo: crate::Foo) {
+    foo.bar();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn generate_trait_not_applicable_when_name_is_taken() {
+        cov_mark::check!(generate_trait_from_impl_name_taken);
+        check_assist_not_applicable(
+            generate_trait_from_impl,
+            r#"
+struct Foo;
+struct FooTrait;
+impl$0 Foo {
+    fn bar(&self) {}
+}
+"#,
+        );
+    }
+}
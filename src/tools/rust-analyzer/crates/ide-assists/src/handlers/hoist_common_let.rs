@@ -0,0 +1,304 @@
+use ide_db::FxHashSet;
+use syntax::{
+    ast::{self, edit::IndentLevel, AstNode, HasName},
+    SyntaxKind, TextRange,
+};
+
+use crate::{utils::is_side_effect_free, AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: hoist_common_let
+//
+// Moves a `let` statement all arms of a `match` start with in front of the `match`.
+//
+// ```
+// fn foo(x: Option<u32>) -> u32 {
+//     $0match x {
+//         Some(x) => {
+//             let base = 10;
+//             base + x
+//         }
+//         None => {
+//             let base = 10;
+//             base
+//         }
+//     }
+// }
+// ```
+// ->
+// ```
+// fn foo(x: Option<u32>) -> u32 {
+//     let base = 10;
+//     match x {
+//         Some(x) => {
+//             base + x
+//         }
+//         None => {
+//             base
+//         }
+//     }
+// }
+// ```
+pub(crate) fn hoist_common_let(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let match_expr = ctx.find_node_at_offset::<ast::MatchExpr>()?;
+    let arms: Vec<_> = match_expr.match_arm_list()?.arms().collect();
+    if arms.len() < 2 {
+        return None;
+    }
+    let lets = arms
+        .iter()
+        .map(|arm| match arm.expr()? {
+            ast::Expr::BlockExpr(block) if block.modifier().is_none() => {
+                match block.stmt_list()?.statements().next()? {
+                    ast::Stmt::LetStmt(it) => Some(it),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let first = lets.first()?;
+    if lets.iter().any(|it| it.syntax().text() != first.syntax().text()) {
+        return None;
+    }
+    // The initializer now runs before the scrutinee and the guards, so neither may be able to
+    // tell. A `let`-`else` could return before the scrutinee is evaluated.
+    if first.let_else().is_some() {
+        return None;
+    }
+    if let Some(initializer) = first.initializer() {
+        let reads_locals =
+            initializer.syntax().descendants().filter_map(ast::Path::cast).any(|path| {
+                matches!(ctx.sema.resolve_path(&path), Some(hir::PathResolution::Local(_)))
+            });
+        let match_has_side_effects = match_expr
+            .expr()
+            .into_iter()
+            .chain(arms.iter().filter_map(|arm| arm.guard()?.condition()))
+            .any(|it| !is_side_effect_free(&it));
+        if !is_side_effect_free(&initializer) || (reads_locals && match_has_side_effects) {
+            cov_mark::hit!(hoist_common_let_reorders_side_effects);
+            return None;
+        }
+    }
+
+    // Hoisting must neither make the `let` lose access to the bindings of an arm nor let it
+    // shadow names used by the `match` itself.
+    let bound_names: FxHashSet<_> = first
+        .pat()?
+        .syntax()
+        .descendants()
+        .filter_map(ast::IdentPat::cast)
+        .filter_map(|it| Some(it.name()?.text().to_string()))
+        .collect();
+    for (arm, let_stmt) in arms.iter().zip(&lets) {
+        let arm_pats: Vec<_> =
+            arm.pat()?.syntax().descendants().filter_map(ast::IdentPat::cast).collect();
+        let is_shadowed = arm_pats
+            .iter()
+            .any(|it| it.name().map_or(false, |name| bound_names.contains(name.text().as_str())));
+        if is_shadowed {
+            cov_mark::hit!(hoist_common_let_shadows_names);
+            return None;
+        }
+        let arm_locals: Vec<_> = arm_pats.iter().filter_map(|it| ctx.sema.to_def(it)).collect();
+        let uses_arm_locals =
+            let_stmt.syntax().descendants().filter_map(ast::Path::cast).any(|path| {
+                match ctx.sema.resolve_path(&path) {
+                    Some(hir::PathResolution::Local(local)) => arm_locals.contains(&local),
+                    _ => false,
+                }
+            });
+        if uses_arm_locals {
+            cov_mark::hit!(hoist_common_let_uses_arm_bindings);
+            return None;
+        }
+    }
+    let mentions_bound_name = match_expr
+        .expr()
+        .into_iter()
+        .map(|it| it.syntax().clone())
+        .chain(arms.iter().filter_map(|arm| Some(arm.guard()?.syntax().clone())))
+        .flat_map(|it| it.descendants().filter_map(ast::NameRef::cast).collect::<Vec<_>>())
+        .any(|it| bound_names.contains(it.text().as_str()));
+    if mentions_bound_name {
+        cov_mark::hit!(hoist_common_let_shadows_names);
+        return None;
+    }
+
+    let stmt = match_expr
+        .syntax()
+        .ancestors()
+        .find(|it| it.parent().map_or(false, |parent| ast::StmtList::can_cast(parent.kind())))?;
+    // The hoisted `let` stays in scope for the rest of the block.
+    let match_range = match_expr.syntax().text_range();
+    let shadows_later_use = stmt
+        .siblings(syntax::Direction::Next)
+        .flat_map(|it| it.descendants().filter_map(ast::NameRef::cast).collect::<Vec<_>>())
+        .filter(|it| !match_range.contains_range(it.syntax().text_range()))
+        .any(|it| bound_names.contains(it.text().as_str()));
+    if shadows_later_use {
+        cov_mark::hit!(hoist_common_let_shadows_later_use);
+        return None;
+    }
+    let target = match_expr.syntax().text_range();
+    acc.add(
+        AssistId("hoist_common_let", AssistKind::RefactorRewrite),
+        "Hoist common `let` out of the match arms",
+        target,
+        |builder| {
+            for let_stmt in &lets {
+                builder.delete(range_with_trailing_whitespace(let_stmt));
+            }
+            let indent = IndentLevel::from_node(&stmt);
+            builder.insert(stmt.text_range().start(), format!("{first}\n{indent}"));
+        },
+    )
+}
+
+fn range_with_trailing_whitespace(let_stmt: &ast::LetStmt) -> TextRange {
+    let range = let_stmt.syntax().text_range();
+    match let_stmt.syntax().next_sibling_or_token() {
+        Some(it) if it.kind() == SyntaxKind::WHITESPACE => range.cover(it.text_range()),
+        _ => range,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn hoist_from_match_in_let() {
+        check_assist(
+            hoist_common_let,
+            r#"
+fn foo(x: Option<u32>, y: u32) -> u32 {
+    let z = match$0 x {
+        Some(x) => { let (a, b) = (y, 1); a + b + x }
+        None => { let (a, b) = (y, 1); a * b }
+    };
+    z
+}
+"#,
+            r#"
+fn foo(x: Option<u32>, y: u32) -> u32 {
+    let (a, b) = (y, 1);
+    let z = match x {
+        Some(x) => { a + b + x }
+        None => { a * b }
+    };
+    z
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_different_lets() {
+        check_assist_not_applicable(
+            hoist_common_let,
+            r#"
+fn foo(x: Option<u32>) -> u32 {
+    match$0 x {
+        Some(_) => { let a = 1; a }
+        None => { let a = 2; a }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_using_arm_bindings() {
+        cov_mark::check!(hoist_common_let_uses_arm_bindings);
+        check_assist_not_applicable(
+            hoist_common_let,
+            r#"
+enum E { A(u32), B(u32) }
+fn foo(e: E) -> u32 {
+    match$0 e {
+        E::A(v) => { let a = v + 1; a }
+        E::B(v) => { let a = v + 1; a * 2 }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_shadowing() {
+        cov_mark::check!(hoist_common_let_shadows_names);
+        check_assist_not_applicable(
+            hoist_common_let,
+            r#"
+fn foo(x: Option<u32>) -> u32 {
+    match$0 x {
+        Some(_) => { let x = 1; x }
+        None => { let x = 1; x + 1 }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_shadowing_later_use() {
+        cov_mark::check_count!(hoist_common_let_shadows_later_use, 2);
+        check_assist_not_applicable(
+            hoist_common_let,
+            r#"
+fn foo(x: Option<u32>) -> u32 {
+    let a = 5;
+    let z = match$0 x {
+        Some(_) => { let a = 1; a }
+        None => { let a = 1; a }
+    };
+    a + z
+}
+"#,
+        );
+        check_assist_not_applicable(
+            hoist_common_let,
+            r#"
+fn add(a: u32, b: u32) -> u32 { a + b }
+fn foo(x: Option<u32>, a: u32) -> u32 {
+    add(a, match$0 x {
+        Some(_) => { let a = 1; a }
+        None => { let a = 1; a }
+    })
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_reordering_side_effects() {
+        cov_mark::check_count!(hoist_common_let_reorders_side_effects, 2);
+        check_assist_not_applicable(
+            hoist_common_let,
+            r#"
+fn len(v: &Vec<u32>) -> usize { 0 }
+fn foo(v: &Vec<u32>, x: Option<u32>) -> usize {
+    match$0 x {
+        Some(_) => { let n = len(v); n }
+        None => { let n = len(v); n + 1 }
+    }
+}
+"#,
+        );
+        check_assist_not_applicable(
+            hoist_common_let,
+            r#"
+fn pop(v: &mut u32) -> Option<u32> { None }
+fn foo(mut v: u32) -> u32 {
+    match$0 pop(&mut v) {
+        Some(_) => { let n = v; n }
+        None => { let n = v; n + 1 }
+    }
+}
+"#,
+        );
+    }
+}
@@ -437,6 +437,11 @@ fn inline(
                 cov_mark::hit!(inline_call_inline_literal);
                 inline_direct(usage, expr);
             }
+            // literals with a fixed type can be repeated at every use without changing meaning
+            [_, ..] if literal_with_fixed_type(expr) => {
+                cov_mark::hit!(inline_call_inline_repeated_literal);
+                usages.iter().for_each(|usage| inline_direct(usage, expr));
+            }
             // inline direct local arguments
             [_, ..] if expr_as_name_ref(expr).is_some() => {
                 cov_mark::hit!(inline_call_inline_locals);
@@ -485,6 +490,22 @@ fn path_expr_as_record_field(usage: &PathExpr) -> Option<ast::RecordExprField> {
     ast::RecordExprField::for_name_ref(&name_ref)
 }
 
+/// Checks whether `expr` is a short literal whose type doesn't depend on inference, like `'a'`
+/// or `1u32`. Unsuffixed numbers could be inferred differently at each use, and strings are
+/// left out so that they aren't spelled out over and over again.
+fn literal_with_fixed_type(expr: &ast::Expr) -> bool {
+    let literal = match expr {
+        ast::Expr::Literal(it) => it,
+        _ => return false,
+    };
+    match literal.kind() {
+        ast::LiteralKind::IntNumber(it) => it.suffix().is_some(),
+        ast::LiteralKind::FloatNumber(it) => it.suffix().is_some(),
+        ast::LiteralKind::Char(_) | ast::LiteralKind::Byte(_) | ast::LiteralKind::Bool(_) => true,
+        ast::LiteralKind::String(_) | ast::LiteralKind::ByteString(_) => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tests::{check_assist, check_assist_not_applicable};
@@ -894,6 +915,56 @@ fn main() {
         );
     }
 
+    #[test]
+    fn inline_repeated_literal_expr() {
+        cov_mark::check!(inline_call_inline_repeated_literal);
+        check_assist(
+            inline_call,
+            r#"
+fn square(x: u32) -> u32 {
+    x * x
+}
+fn main() {
+    let y = square$0(10u32);
+}
+"#,
+            r#"
+fn square(x: u32) -> u32 {
+    x * x
+}
+fn main() {
+    let y = 10u32 * 10u32;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn inline_repeated_unsuffixed_literal_with_let() {
+        check_assist(
+            inline_call,
+            r#"
+fn square(x: u32) -> u32 {
+    x * x
+}
+fn main() {
+    let y = square$0(10);
+}
+"#,
+            r#"
+fn square(x: u32) -> u32 {
+    x * x
+}
+fn main() {
+    let y = {
+        let x = 10;
+        x * x
+    };
+}
+"#,
+        );
+    }
+
     #[test]
     fn inline_emits_type_for_coercion() {
         check_assist(
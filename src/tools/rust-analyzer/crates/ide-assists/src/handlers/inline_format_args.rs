@@ -0,0 +1,420 @@
+use ide_db::{
+    assists::{AssistId, AssistKind},
+    syntax_helpers::format_string::{is_format_string, lex_format_specifiers, FormatSpecifier},
+};
+use itertools::Itertools;
+use syntax::{
+    ast::{self, AstNode, AstToken},
+    NodeOrToken, SyntaxKind, TextRange, TextSize, T,
+};
+
+use crate::{AssistContext, Assists};
+
+// Assist: inline_format_args
+//
+// Moves identifier arguments of a format macro into the format string.
+//
+// ```
+// macro_rules! format_args {
+//     ($lit:literal $($tt:tt)*) => { 0 },
+// }
+// macro_rules! print {
+//     ($($arg:tt)*) => (std::io::_print(format_args!($($arg)*)));
+// }
+//
+// fn main() {
+//     let (x, y) = (1, 2);
+//     print!("{} {:?}$0", x, y);
+// }
+// ```
+// ->
+// ```
+// macro_rules! format_args {
+//     ($lit:literal $($tt:tt)*) => { 0 },
+// }
+// macro_rules! print {
+//     ($($arg:tt)*) => (std::io::_print(format_args!($($arg)*)));
+// }
+//
+// fn main() {
+//     let (x, y) = (1, 2);
+//     print!("{x} {y:?}");
+// }
+// ```
+pub(crate) fn inline_format_args(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let call = FormatCall::at_cursor(ctx)?;
+    let implicit: Vec<_> = call.placeholders.iter().filter(|it| it.capture.is_none()).collect();
+    if implicit.is_empty() || implicit.len() != call.args.len() {
+        return None;
+    }
+    let idents = call.args.iter().map(|it| it.ident.clone()).collect::<Option<Vec<_>>>();
+    let idents = match idents {
+        Some(it) if call.args.iter().all(|it| it.name.is_none()) => it,
+        _ => {
+            cov_mark::hit!(inline_format_args_not_identifiers);
+            return None;
+        }
+    };
+
+    acc.add(
+        AssistId("inline_format_args", AssistKind::RefactorRewrite),
+        "Inline format arguments",
+        call.tt.syntax().text_range(),
+        |builder| {
+            for (placeholder, ident) in implicit.iter().zip(idents) {
+                builder.insert(placeholder.open_end, ident);
+            }
+            builder.delete(TextRange::new(call.string.syntax().text_range().end(), call.args_end));
+        },
+    )
+}
+
+// Assist: expand_format_args
+//
+// Moves identifiers captured by a format string into the arguments of the format macro.
+//
+// ```
+// macro_rules! format_args {
+//     ($lit:literal $($tt:tt)*) => { 0 },
+// }
+// macro_rules! print {
+//     ($($arg:tt)*) => (std::io::_print(format_args!($($arg)*)));
+// }
+//
+// fn main() {
+//     let (x, y) = (1, 2);
+//     print!("{x} {}$0", y);
+// }
+// ```
+// ->
+// ```
+// macro_rules! format_args {
+//     ($lit:literal $($tt:tt)*) => { 0 },
+// }
+// macro_rules! print {
+//     ($($arg:tt)*) => (std::io::_print(format_args!($($arg)*)));
+// }
+//
+// fn main() {
+//     let (x, y) = (1, 2);
+//     print!("{} {}", x, y);
+// }
+// ```
+pub(crate) fn expand_format_args(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let call = FormatCall::at_cursor(ctx)?;
+    let text = call.tt.syntax().to_string();
+    let tt_start = call.tt.syntax().text_range().start();
+    let arg_text = |arg: &FormatArg| text[arg.range - tt_start].to_string();
+
+    let mut positional = call.args.iter().filter(|it| it.name.is_none());
+    let named: Vec<_> = call.args.iter().filter(|it| it.name.is_some()).collect();
+    let mut captures = Vec::new();
+    let mut args = Vec::new();
+    for placeholder in &call.placeholders {
+        match placeholder.capture {
+            Some(range) => {
+                let name = &call.string.text()[range - call.string.syntax().text_range().start()];
+                // Names of named arguments aren't captures.
+                if named.iter().any(|it| it.name.as_deref() == Some(name)) {
+                    continue;
+                }
+                captures.push(range);
+                args.push(name.to_string());
+            }
+            None => args.push(arg_text(positional.next()?)),
+        }
+    }
+    if captures.is_empty() || positional.next().is_some() {
+        return None;
+    }
+    args.extend(named.into_iter().map(arg_text));
+
+    acc.add(
+        AssistId("expand_format_args", AssistKind::RefactorRewrite),
+        "Expand captured identifiers",
+        call.tt.syntax().text_range(),
+        |builder| {
+            for range in captures {
+                builder.delete(range);
+            }
+            builder.replace(
+                TextRange::new(call.string.syntax().text_range().end(), call.args_end),
+                args.iter().map(|it| format!(", {it}")).join(""),
+            );
+        },
+    )
+}
+
+/// A format string along with the arguments that follow it in a macro call.
+struct FormatCall {
+    tt: ast::TokenTree,
+    string: ast::String,
+    placeholders: Vec<Placeholder>,
+    args: Vec<FormatArg>,
+    /// Where the arguments end, before the closing delimiter of the call.
+    args_end: TextSize,
+}
+
+struct Placeholder {
+    open_end: TextSize,
+    /// The identifier written into the placeholder, as in `{x}`.
+    capture: Option<TextRange>,
+}
+
+struct FormatArg {
+    range: TextRange,
+    /// The name of a named argument, as in `x = 1`.
+    name: Option<String>,
+    /// The identifier making up the argument, if it's a plain identifier.
+    ident: Option<String>,
+}
+
+impl FormatCall {
+    /// Finds the format string under the cursor and splits up its placeholders and arguments.
+    /// Explicitly numbered placeholders and arguments referenced by width or precision are not
+    /// supported, as moving arguments around would change what they refer to.
+    fn at_cursor(ctx: &AssistContext<'_>) -> Option<FormatCall> {
+        let string = ctx.find_token_at_offset::<ast::String>()?;
+        let tt = string.syntax().parent().and_then(ast::TokenTree::cast)?;
+        let expanded = ast::String::cast(
+            ctx.sema.descend_into_macros_with_kind_preference(string.syntax().clone()),
+        )?;
+        if !is_format_string(&expanded) {
+            return None;
+        }
+
+        let mut specifiers = Vec::new();
+        lex_format_specifiers(&string, &mut |range, kind| specifiers.push((range, kind)));
+        let offset = string.syntax().text_range().start();
+        let mut placeholders: Vec<Placeholder> = Vec::new();
+        for (range, kind) in specifiers {
+            let range = range + offset;
+            let at_open = placeholders.last().map_or(false, |it| it.open_end == range.start());
+            match kind {
+                FormatSpecifier::Open => {
+                    placeholders.push(Placeholder { open_end: range.end(), capture: None })
+                }
+                FormatSpecifier::Identifier if at_open => {
+                    placeholders.last_mut()?.capture = Some(range)
+                }
+                FormatSpecifier::Integer if at_open => return None,
+                FormatSpecifier::DollarSign | FormatSpecifier::Asterisk => return None,
+                _ => {}
+            }
+        }
+
+        let rest: Vec<_> = tt
+            .token_trees_and_tokens()
+            .skip_while(|it| it.as_token() != Some(string.syntax()))
+            .skip(1)
+            .filter(|it| it.as_token().map_or(true, |it| !it.kind().is_trivia()))
+            .collect();
+        let (close, rest) = rest.split_last()?;
+        if close.as_token() != tt.right_delimiter_token().as_ref() {
+            return None;
+        }
+        let mut groups: Vec<_> =
+            rest.split(|it| it.as_token().map_or(false, |it| it.kind() == T![,])).collect();
+        // The arguments are preceded by a comma, and may be followed by a trailing one.
+        if !groups.first()?.is_empty() {
+            return None;
+        }
+        groups.remove(0);
+        if groups.last().map_or(false, |it| it.is_empty()) {
+            groups.pop();
+        }
+        let args = groups
+            .into_iter()
+            .map(|group| {
+                let range = element_range(group.first()?).cover(element_range(group.last()?));
+                let name = match group {
+                    [NodeOrToken::Token(name), NodeOrToken::Token(eq), ..]
+                        if is_ident(name) && eq.kind() == T![=] =>
+                    {
+                        Some(name.text().to_string())
+                    }
+                    _ => None,
+                };
+                let ident = match group {
+                    [NodeOrToken::Token(token)] if is_ident(token) => {
+                        Some(token.text().to_string())
+                    }
+                    _ => None,
+                };
+                Some(FormatArg { range, name, ident })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let args_end = element_range(close).start();
+        Some(FormatCall { tt, string, placeholders, args, args_end })
+    }
+}
+
+fn element_range(element: &NodeOrToken<ast::TokenTree, syntax::SyntaxToken>) -> TextRange {
+    match element {
+        NodeOrToken::Node(it) => it.syntax().text_range(),
+        NodeOrToken::Token(it) => it.text_range(),
+    }
+}
+
+fn is_ident(token: &syntax::SyntaxToken) -> bool {
+    // Raw identifiers can't be captured by format strings.
+    token.kind() == SyntaxKind::IDENT && !token.text().starts_with("r#")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    const MACRO_DECL: &str = r#"
+macro_rules! format_args {
+    ($lit:literal $($tt:tt)*) => { 0 },
+}
+macro_rules! print {
+    ($($arg:tt)*) => (std::io::_print(format_args!($($arg)*)));
+}
+"#;
+
+    fn with_macro_decl(s: &str) -> String {
+        MACRO_DECL.to_string() + s
+    }
+
+    #[test]
+    fn inline_with_format_options() {
+        check_assist(
+            inline_format_args,
+            &with_macro_decl(
+                r#"
+fn main() {
+    let (a, b) = (1, 2);
+    print!("{{}} {:>5} {:?}$0 {b}", a, b,);
+}
+"#,
+            ),
+            &with_macro_decl(
+                r#"
+fn main() {
+    let (a, b) = (1, 2);
+    print!("{{}} {a:>5} {b:?} {b}");
+}
+"#,
+            ),
+        );
+    }
+
+    #[test]
+    fn inline_not_applicable_to_expressions() {
+        cov_mark::check!(inline_format_args_not_identifiers);
+        check_assist_not_applicable(
+            inline_format_args,
+            &with_macro_decl(
+                r#"
+fn main() {
+    let a = (1, 2);
+    print!("{} {}$0", a.0, a);
+}
+"#,
+            ),
+        );
+    }
+
+    #[test]
+    fn inline_not_applicable_to_named_or_numbered_args() {
+        check_assist_not_applicable(
+            inline_format_args,
+            &with_macro_decl(
+                r#"
+fn main() {
+    let a = 1;
+    print!("{} {x}$0", a, x = a);
+}
+"#,
+            ),
+        );
+        check_assist_not_applicable(
+            inline_format_args,
+            &with_macro_decl(
+                r#"
+fn main() {
+    let a = 1;
+    print!("{0} {0}$0", a);
+}
+"#,
+            ),
+        );
+        check_assist_not_applicable(
+            inline_format_args,
+            &with_macro_decl(
+                r#"
+fn main() {
+    let (a, w) = (1, 5);
+    print!("{:1$}$0", a, w);
+}
+"#,
+            ),
+        );
+    }
+
+    #[test]
+    fn expand_keeps_argument_order() {
+        check_assist(
+            expand_format_args,
+            &with_macro_decl(
+                r#"
+fn main() {
+    let (a, b, c) = (1, 2, 3);
+    print!("{} {b:?} {}$0", a + 1, c);
+}
+"#,
+            ),
+            &with_macro_decl(
+                r#"
+fn main() {
+    let (a, b, c) = (1, 2, 3);
+    print!("{} {:?} {}", a + 1, b, c);
+}
+"#,
+            ),
+        );
+    }
+
+    #[test]
+    fn expand_leaves_named_arguments() {
+        check_assist(
+            expand_format_args,
+            &with_macro_decl(
+                r#"
+fn main() {
+    let a = 1;
+    print!("{a} {x}$0", x = a + 1);
+}
+"#,
+            ),
+            &with_macro_decl(
+                r#"
+fn main() {
+    let a = 1;
+    print!("{} {x}", a, x = a + 1);
+}
+"#,
+            ),
+        );
+    }
+
+    #[test]
+    fn expand_not_applicable_without_captures() {
+        check_assist_not_applicable(
+            expand_format_args,
+            &with_macro_decl(
+                r#"
+fn main() {
+    let a = 1;
+    print!("{}$0", a);
+}
+"#,
+            ),
+        );
+    }
+}
@@ -112,6 +112,11 @@ pub(crate) fn inline_local_variable(acc: &mut Assists, ctx: &AssistContext<'_>)
         })
         .collect::<Option<Vec<_>>>()?;
 
+    if is_initializer_shadowed(&ctx.sema, &initializer_expr, &wrap_in_parens) {
+        cov_mark::hit!(test_not_inline_shadowed_initializer);
+        return None;
+    }
+
     let init_str = initializer_expr.syntax().text().to_string();
     let init_in_paren = format!("({init_str})");
 
@@ -141,6 +146,37 @@ pub(crate) fn inline_local_variable(acc: &mut Assists, ctx: &AssistContext<'_>)
     )
 }
 
+/// Checks whether a local the initializer refers to is shadowed at any of the usages, in
+/// which case inlining would silently change what the name resolves to.
+fn is_initializer_shadowed(
+    sema: &Semantics<'_, RootDatabase>,
+    initializer: &ast::Expr,
+    usages: &[(TextRange, ast::NameRef, bool)],
+) -> bool {
+    let captured_locals: Vec<_> = initializer
+        .syntax()
+        .descendants()
+        .filter_map(ast::PathExpr::cast)
+        .filter_map(|path_expr| {
+            let path = path_expr.path()?;
+            match sema.resolve_path(&path)? {
+                PathResolution::Local(local) => Some((path, local)),
+                _ => None,
+            }
+        })
+        .collect();
+    if captured_locals.is_empty() {
+        return false;
+    }
+
+    usages.iter().any(|(_, name_ref, _)| match sema.scope(name_ref.syntax()) {
+        Some(scope) => captured_locals.iter().any(|(path, local)| {
+            scope.speculative_resolve(path) != Some(PathResolution::Local(*local))
+        }),
+        None => false,
+    })
+}
+
 struct InlineData {
     let_stmt: ast::LetStmt,
     delete_let: bool,
@@ -948,6 +984,47 @@ fn f() {
     let S$0 = S;
     S;
 }
+"#,
+        );
+    }
+
+    #[test]
+    fn test_inline_into_shadowing_block() {
+        check_assist(
+            inline_local_variable,
+            r#"
+fn f() {
+    let a$0 = 1 + 2;
+    {
+        let a = 0;
+        a;
+    }
+    a;
+}
+"#,
+            r#"
+fn f() {
+    {
+        let a = 0;
+        a;
+    }
+    1 + 2;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_not_inline_shadowed_initializer() {
+        cov_mark::check!(test_not_inline_shadowed_initializer);
+        check_assist_not_applicable(
+            inline_local_variable,
+            r#"
+fn f(b: i32) {
+    let a$0 = b + 1;
+    let b = 0;
+    a;
+}
 "#,
         );
     }
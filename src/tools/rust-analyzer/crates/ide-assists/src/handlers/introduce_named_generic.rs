@@ -1,9 +1,15 @@
+use ide_db::{defs::Definition, FxHashSet};
 use syntax::{
-    ast::{self, edit_in_place::GenericParamsOwnerEdit, make, AstNode},
+    ast::{
+        self, edit_in_place::GenericParamsOwnerEdit, make, AstNode, HasGenericParams, HasTypeBounds,
+    },
     ted,
 };
 
-use crate::{utils::suggest_name, AssistContext, AssistId, AssistKind, Assists};
+use crate::{
+    handlers::remove_unused_param::range_to_remove, utils::suggest_name, AssistContext, AssistId,
+    AssistKind, Assists,
+};
 
 // Assist: introduce_named_generic
 //
@@ -32,7 +38,24 @@ pub(crate) fn introduce_named_generic(acc: &mut Assists, ctx: &AssistContext<'_>
             let impl_trait_type = edit.make_mut(impl_trait_type);
             let fn_ = edit.make_mut(fn_);
 
-            let type_param_name = suggest_name::for_generic_parameter(&impl_trait_type);
+            // `fn foo<B>(bar: impl Bar)` needs another name than `B`, and so do functions in an
+            // `impl<B>` or a trait with a `B` parameter.
+            let taken_names: FxHashSet<_> = fn_
+                .syntax()
+                .ancestors()
+                .filter_map(ast::AnyHasGenericParams::cast)
+                .filter_map(|it| it.generic_param_list())
+                .flat_map(|it| it.type_or_const_params())
+                .filter_map(|it| Some(it.name()?.to_string()))
+                .collect();
+            let base_name = suggest_name::for_generic_parameter(&impl_trait_type);
+            let type_param_name = (0..)
+                .map(|i| match i {
+                    0 => base_name.to_string(),
+                    _ => format!("{base_name}{i}"),
+                })
+                .find(|it| !taken_names.contains(it))
+                .unwrap();
 
             let type_param = make::type_param(make::name(&type_param_name), Some(type_bound_list))
                 .clone_for_update();
@@ -44,11 +67,91 @@ pub(crate) fn introduce_named_generic(acc: &mut Assists, ctx: &AssistContext<'_>
     )
 }
 
+// Assist: replace_named_generic_with_impl
+//
+// Replaces a generic type parameter used by a single argument with `impl Trait`.
+//
+// ```
+// fn foo<$0B: Bar>(bar: B) {}
+// ```
+// ->
+// ```
+// fn foo(bar: impl Bar) {}
+// ```
+pub(crate) fn replace_named_generic_with_impl(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let type_param = ctx.find_node_at_offset::<ast::TypeParam>()?;
+    let generic_param_list = type_param.syntax().parent().and_then(ast::GenericParamList::cast)?;
+    let fn_ = generic_param_list.syntax().parent().and_then(ast::Fn::cast)?;
+    let type_bound_list = type_param.type_bound_list()?;
+    if type_param.default_type().is_some() {
+        return None;
+    }
+
+    // The parameter has to be the whole type of exactly one argument, so that callers which let it
+    // be inferred can't tell the difference. Any other use, say in the return type or a bound,
+    // counts as a second one.
+    let def = ctx.sema.to_def(&type_param)?;
+    let usages = Definition::GenericParam(def.into()).usages(&ctx.sema).all();
+    let mut references = usages.iter().flat_map(|(_, refs)| refs);
+    let reference = references.next()?;
+    if references.next().is_some() {
+        cov_mark::hit!(replace_named_generic_used_more_than_once);
+        return None;
+    }
+    let path_type = reference
+        .name
+        .as_name_ref()?
+        .syntax()
+        .ancestors()
+        .find_map(ast::PathType::cast)
+        .filter(|it| it.path().map_or(false, |path| path.qualifier().is_none()))?;
+    let param = path_type.syntax().parent().and_then(ast::Param::cast)?;
+    if !fn_.param_list()?.params().any(|it| it == param) {
+        return None;
+    }
+
+    // Explicit generic arguments can't be given to functions with `impl Trait` arguments.
+    let func = ctx.sema.to_def(&fn_)?;
+    let has_turbofish_call =
+        Definition::Function(func).usages(&ctx.sema).all().iter().any(|(_, refs)| {
+            refs.iter().any(|it| {
+                it.name.as_name_ref().and_then(|it| it.syntax().parent()).map_or(false, |parent| {
+                    ast::PathSegment::cast(parent.clone())
+                        .and_then(|it| it.generic_arg_list())
+                        .or_else(|| ast::MethodCallExpr::cast(parent)?.generic_arg_list())
+                        .is_some()
+                })
+            })
+        });
+    if has_turbofish_call {
+        cov_mark::hit!(replace_named_generic_turbofish);
+        return None;
+    }
+
+    let target = type_param.syntax().text_range();
+    acc.add(
+        AssistId("replace_named_generic_with_impl", AssistKind::RefactorRewrite),
+        "Replace generic with impl trait",
+        target,
+        |builder| {
+            builder.replace(path_type.syntax().text_range(), format!("impl {type_bound_list}"));
+            let range = match generic_param_list.generic_params().count() {
+                1 => generic_param_list.syntax().text_range(),
+                _ => range_to_remove(type_param.syntax()),
+            };
+            builder.delete(range);
+        },
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::tests::check_assist;
+    use crate::tests::{check_assist, check_assist_not_applicable};
 
     #[test]
     fn introduce_named_generic_params() {
@@ -103,12 +206,15 @@ fn foo<B: Bar
 
     #[test]
     fn replace_impl_trait_with_exist_generic_letter() {
-        // FIXME: This is wrong, we should pick a different name if the one we
-        // want is already bound.
         check_assist(
             introduce_named_generic,
             r#"fn foo<B>(bar: $0impl Bar) {}"#,
-            r#"fn foo<B, B: Bar>(bar: B) {}"#,
+            r#"fn foo<B, B1: Bar>(bar: B1) {}"#,
+        );
+        check_assist(
+            introduce_named_generic,
+            r#"fn foo<B: Baz, B1>(baz: B, bar: $0impl Bar) {}"#,
+            r#"fn foo<B: Baz, B1, B2: Bar>(baz: B, bar: B2) {}"#,
         );
     }
 
@@ -141,4 +247,79 @@ fn replace_impl_trait_multiple() {
             r#"fn foo<F: Foo + Bar>(bar: F) {}"#,
         );
     }
+
+    #[test]
+    fn replace_generic_with_impl() {
+        check_assist(
+            replace_named_generic_with_impl,
+            r#"fn foo<I: Iterator<Item = u8>, $0F: Fn() + Clone>(i: I, f: F) {}"#,
+            r#"fn foo<I: Iterator<Item = u8>>(i: I, f: impl Fn() + Clone) {}"#,
+        );
+    }
+
+    #[test]
+    fn replace_generic_with_impl_not_applicable_when_used_elsewhere() {
+        cov_mark::check_count!(replace_named_generic_used_more_than_once, 3);
+        check_assist_not_applicable(
+            replace_named_generic_with_impl,
+            r#"fn foo<$0T: Clone>(a: T) -> T { a }"#,
+        );
+        check_assist_not_applicable(
+            replace_named_generic_with_impl,
+            r#"fn foo<$0T: Clone>(a: T, b: T) {}"#,
+        );
+        check_assist_not_applicable(
+            replace_named_generic_with_impl,
+            r#"fn foo<$0T: Clone>(a: T) { let b: T = a; }"#,
+        );
+        check_assist_not_applicable(
+            replace_named_generic_with_impl,
+            r#"fn foo<$0T: Clone>(a: Vec<T>) {}"#,
+        );
+    }
+
+    #[test]
+    fn replace_impl_trait_with_impl_generic_letter() {
+        check_assist(
+            introduce_named_generic,
+            r#"
+struct S<B>(B);
+impl<B> S<B> {
+    fn foo(bar: $0impl Bar) {}
+}
+"#,
+            r#"
+struct S<B>(B);
+impl<B> S<B> {
+    fn foo<B1: Bar>(bar: B1) {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn replace_generic_with_impl_not_applicable_with_turbofish() {
+        cov_mark::check_count!(replace_named_generic_turbofish, 2);
+        check_assist_not_applicable(
+            replace_named_generic_with_impl,
+            r#"
+fn foo<$0T: Clone>(a: T) {}
+fn main() {
+    foo::<u8>(0);
+}
+"#,
+        );
+        check_assist_not_applicable(
+            replace_named_generic_with_impl,
+            r#"
+struct S;
+impl S {
+    fn foo<$0T: Clone>(&self, a: T) {}
+}
+fn main() {
+    S.foo::<u8>(0);
+}
+"#,
+        );
+    }
 }
@@ -120,7 +120,7 @@ fn generate_impl_def_assist(
 
 /// Given a type parameter list, generate a unique lifetime parameter name
 /// which is not in the list
-fn generate_unique_lifetime_param_name(
+pub(crate) fn generate_unique_lifetime_param_name(
     existing_type_param_list: Option<ast::GenericParamList>,
 ) -> Option<ast::Lifetime> {
     match existing_type_param_list {
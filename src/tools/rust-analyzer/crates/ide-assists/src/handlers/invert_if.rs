@@ -14,6 +14,7 @@
 //
 // This transforms if expressions of the form `if !x {A} else {B}` into `if x {B} else {A}`
 // This also works with `!=`. This assist can only be applied with the cursor on `if`.
+// An `if` without an `else` gets an empty `then` branch: `if x {A}` becomes `if !x {} else {A}`.
 //
 // ```
 // fn main() {
@@ -42,21 +43,30 @@ pub(crate) fn invert_if(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()
     }
 
     let then_node = expr.then_branch()?.syntax().clone();
-    let else_block = match expr.else_branch()? {
-        ast::ElseBranch::Block(it) => it,
-        ast::ElseBranch::IfExpr(_) => return None,
+    let else_block = match expr.else_branch() {
+        Some(ast::ElseBranch::Block(it)) => Some(it),
+        Some(ast::ElseBranch::IfExpr(_)) => return None,
+        None => None,
     };
 
     acc.add(AssistId("invert_if", AssistKind::RefactorRewrite), "Invert if", if_range, |edit| {
         let flip_cond = invert_boolean_expression(cond.clone());
         edit.replace_ast(cond, flip_cond);
 
-        let else_node = else_block.syntax();
-        let else_range = else_node.text_range();
         let then_range = then_node.text_range();
-
-        edit.replace(else_range, then_node.text());
-        edit.replace(then_range, else_node.text());
+        match else_block {
+            Some(else_block) => {
+                let else_node = else_block.syntax();
+                let else_range = else_node.text_range();
+
+                edit.replace(else_range, then_node.text());
+                edit.replace(then_range, else_node.text());
+            }
+            None => {
+                cov_mark::hit!(invert_if_without_else);
+                edit.replace(then_range, format!("{{}} else {then_node}"));
+            }
+        }
     })
 }
 
@@ -141,4 +151,35 @@ fn invert_if_result_case() {
             "fn f() { if doc_style.is_ok() { Class::Ok } else { Class::Err } }",
         )
     }
+
+    #[test]
+    fn invert_if_comparison_operators() {
+        for (op, inverted) in
+            [("==", "!="), ("!=", "=="), ("<", ">="), ("<=", ">"), (">", "<="), (">=", "<")]
+        {
+            check_assist(
+                invert_if,
+                &format!("fn f() {{ i$0f x {op} 3 {{ 1 }} else {{ 2 }} }}"),
+                &format!("fn f() {{ if x {inverted} 3 {{ 2 }} else {{ 1 }} }}"),
+            )
+        }
+    }
+
+    #[test]
+    fn invert_if_without_else() {
+        cov_mark::check!(invert_if_without_else);
+        check_assist(
+            invert_if,
+            "fn f() { i$0f x == 3 { foo(); } }",
+            "fn f() { if x != 3 {} else { foo(); } }",
+        )
+    }
+
+    #[test]
+    fn invert_if_doesnt_apply_with_else_if() {
+        check_assist_not_applicable(
+            invert_if,
+            "fn f() { i$0f x == 3 { 1 } else if x == 4 { 2 } else { 3 } }",
+        )
+    }
 }
@@ -184,6 +184,62 @@ fn test_merge_second() {
         );
     }
 
+    #[test]
+    fn test_merge_into_existing_list() {
+        check_assist(
+            merge_imports,
+            r"
+use std::fmt$0::{Debug};
+use std::fmt::Display;
+",
+            r"
+use std::fmt::{Debug, Display};
+",
+        );
+    }
+
+    #[test]
+    fn test_merge_keeps_rename() {
+        check_assist(
+            merge_imports,
+            r"
+use std::fmt$0::Debug as Dbg;
+use std::fmt::Display;
+",
+            r"
+use std::fmt::{Debug as Dbg, Display};
+",
+        );
+    }
+
+    #[test]
+    fn test_merge_same_item_with_different_names() {
+        check_assist(
+            merge_imports,
+            r"
+use std::fmt$0::Debug as Dbg;
+use std::fmt::Debug;
+",
+            r"
+use std::fmt::{Debug as Dbg, Debug};
+",
+        );
+    }
+
+    #[test]
+    fn test_merge_nested_same_item_with_different_names() {
+        check_assist(
+            merge_imports,
+            r"
+use s$0td::{fmt::Debug as Dbg, io};
+use std::fmt::Debug;
+",
+            r"
+use std::{fmt::{Debug as Dbg, Debug}, io};
+",
+        );
+    }
+
     #[test]
     fn merge_self1() {
         check_assist(
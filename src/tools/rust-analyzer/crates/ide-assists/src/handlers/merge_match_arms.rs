@@ -3,14 +3,14 @@
 use syntax::{
     algo::neighbor,
     ast::{self, AstNode, HasName},
-    Direction,
+    Direction, SyntaxNode,
 };
 
 use crate::{AssistContext, AssistId, AssistKind, Assists, TextRange};
 
 // Assist: merge_match_arms
 //
-// Merges the current match arm with the following if their bodies are identical.
+// Merges the current match arm with the following if their bodies and guards are identical.
 //
 // ```
 // enum Action { Move { distance: u32 }, Stop }
@@ -34,10 +34,7 @@
 // ```
 pub(crate) fn merge_match_arms(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
     let current_arm = ctx.find_node_at_offset::<ast::MatchArm>()?;
-    // Don't try to handle arms with guards for now - can add support for this later
-    if current_arm.guard().is_some() {
-        return None;
-    }
+    let current_guard = current_arm.guard();
     let current_expr = current_arm.expr()?;
     let current_text_range = current_arm.syntax().text_range();
     let current_arm_types = get_arm_types(ctx, &current_arm);
@@ -46,9 +43,15 @@ pub(crate) fn merge_match_arms(acc: &mut Assists, ctx: &AssistContext<'_>) -> Op
     // compare to the previous match arm as well.
     let arms_to_merge = successors(Some(current_arm), |it| neighbor(it, Direction::Next))
         .take_while(|arm| match arm.expr() {
-            Some(expr) if arm.guard().is_none() => {
-                let same_text = expr.syntax().text() == current_expr.syntax().text();
-                if !same_text {
+            Some(expr) => {
+                let same_guard = match (arm.guard(), &current_guard) {
+                    (Some(guard), Some(current_guard)) => {
+                        is_same_syntax(guard.syntax(), current_guard.syntax())
+                    }
+                    (None, None) => true,
+                    _ => false,
+                };
+                if !same_guard || !is_same_syntax(expr.syntax(), current_expr.syntax()) {
                     return false;
                 }
 
@@ -78,7 +81,8 @@ pub(crate) fn merge_match_arms(acc: &mut Assists, ctx: &AssistContext<'_>) -> Op
                     .join(" | ")
             };
 
-            let arm = format!("{pats} => {current_expr},");
+            let guard = current_guard.map(|it| format!(" {it}")).unwrap_or_default();
+            let arm = format!("{pats}{guard} => {current_expr},");
 
             if let [first, .., last] = &*arms_to_merge {
                 let start = first.syntax().text_range().start();
@@ -90,6 +94,18 @@ pub(crate) fn merge_match_arms(acc: &mut Assists, ctx: &AssistContext<'_>) -> Op
     )
 }
 
+/// Compares two nodes token by token, ignoring whitespace and comments.
+fn is_same_syntax(a: &SyntaxNode, b: &SyntaxNode) -> bool {
+    let tokens = |node: &SyntaxNode| {
+        node.descendants_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter(|it| !it.kind().is_trivia())
+            .map(|it| (it.kind(), it.text().to_owned()))
+            .collect::<Vec<_>>()
+    };
+    tokens(a) == tokens(b)
+}
+
 fn contains_placeholder(a: &ast::MatchArm) -> bool {
     matches!(a.pat(), Some(ast::Pat::WildcardPat(..)))
 }
@@ -321,6 +337,54 @@ fn main() {
         );
     }
 
+    #[test]
+    fn merge_match_arms_same_guards() {
+        check_assist(
+            merge_match_arms,
+            r#"
+enum X { A(i32), B(i32), C(i32) }
+
+fn main(x: X) {
+    let y = match x {
+        X::A(a) if a > 5 => { $0a + 1 },
+        X::B(a) if a>5 => {
+            // Same as above.
+            a + 1
+        },
+        X::C(a) => { a + 1 },
+    };
+}
+"#,
+            r#"
+enum X { A(i32), B(i32), C(i32) }
+
+fn main(x: X) {
+    let y = match x {
+        X::A(a) | X::B(a) if a > 5 => { a + 1 },
+        X::C(a) => { a + 1 },
+    };
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn merge_match_arms_rejects_different_guards() {
+        check_assist_not_applicable(
+            merge_match_arms,
+            r#"
+enum X { A(i32), B(i32) }
+
+fn main(x: X) {
+    let y = match x {
+        X::A(a) if a > 5 => { $0a },
+        X::B(a) if a > 6 => { a },
+    };
+}
+"#,
+        );
+    }
+
     #[test]
     fn merge_match_arms_different_type() {
         check_assist_not_applicable(
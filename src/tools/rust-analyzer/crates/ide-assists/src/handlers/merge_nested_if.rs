@@ -0,0 +1,191 @@
+use ide_db::syntax_helpers::node_ext::is_pattern_cond;
+use syntax::{
+    ast::{
+        self,
+        edit::{AstNodeEdit, IndentLevel},
+        AstNode, BinaryOp, LogicOp,
+    },
+    SyntaxKind::COMMENT,
+    TextRange, T,
+};
+
+use crate::{
+    assist_context::{AssistContext, Assists},
+    AssistId, AssistKind,
+};
+
+// Assist: merge_nested_if
+//
+// Merges an `if` whose body is nothing but another `if` into a single condition.
+// This assist can only be applied with the cursor on the outer `if`.
+//
+// ```
+// fn main() {
+//     i$0f x == 3 {
+//         if y == 4 {
+//             foo();
+//         }
+//     }
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     if x == 3 && y == 4 {
+//         foo();
+//     }
+// }
+// ```
+pub(crate) fn merge_nested_if(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let if_keyword = ctx.find_token_syntax_at_offset(T![if])?;
+    let expr = ast::IfExpr::cast(if_keyword.parent()?)?;
+    let if_range = if_keyword.text_range();
+    let cursor_in_range = if_range.contains_range(ctx.selection_trimmed());
+    if !cursor_in_range {
+        return None;
+    }
+    if expr.else_branch().is_some() {
+        return None;
+    }
+
+    let outer_cond = expr.condition()?;
+    let stmt_list = expr.then_branch()?.stmt_list()?;
+    // Anything besides the nested `if`, comments included, would have nowhere to go.
+    if stmt_list.syntax().children_with_tokens().any(|it| it.kind() == COMMENT) {
+        return None;
+    }
+    let nested_if = match (stmt_list.statements().next(), stmt_list.tail_expr()) {
+        (None, Some(ast::Expr::IfExpr(it))) => it,
+        _ => {
+            cov_mark::hit!(merge_nested_if_not_applicable_with_other_statements);
+            return None;
+        }
+    };
+    if nested_if.else_branch().is_some() {
+        return None;
+    }
+    let inner_cond = nested_if.condition()?;
+    // This assist should not apply for if-let, `&&` between lets is still unstable.
+    if is_pattern_cond(outer_cond.clone()) || is_pattern_cond(inner_cond.clone()) {
+        return None;
+    }
+    let inner_then = nested_if.then_branch()?;
+
+    acc.add(
+        AssistId("merge_nested_if", AssistKind::RefactorRewrite),
+        "Merge nested if",
+        if_range,
+        |edit| {
+            let cond =
+                format!("{} && {}", parenthesize_or(&outer_cond), parenthesize_or(&inner_cond));
+            let then_branch = inner_then.dedent(IndentLevel(1));
+            let range = TextRange::new(
+                outer_cond.syntax().text_range().start(),
+                expr.syntax().text_range().end(),
+            );
+            edit.replace(range, format!("{cond} {then_branch}"));
+        },
+    )
+}
+
+/// `&&` binds tighter than `||`, so conditions using the latter need parentheses to keep their
+/// meaning once joined.
+fn parenthesize_or(cond: &ast::Expr) -> String {
+    match cond {
+        ast::Expr::BinExpr(bin) if bin.op_kind() == Some(BinaryOp::LogicOp(LogicOp::Or)) => {
+            format!("({cond})")
+        }
+        _ => cond.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    #[test]
+    fn merge_nested_if_simple() {
+        check_assist(
+            merge_nested_if,
+            r#"
+fn f() {
+    i$0f x == 3 {
+        if y == 4 {
+            foo();
+            bar();
+        }
+    }
+}
+"#,
+            r#"
+fn f() {
+    if x == 3 && y == 4 {
+        foo();
+        bar();
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn merge_nested_if_parenthesizes_or() {
+        check_assist(
+            merge_nested_if,
+            "fn f() { i$0f a || b { if c || d { foo() } } }",
+            "fn f() { if (a || b) && (c || d) { foo() } }",
+        )
+    }
+
+    #[test]
+    fn merge_nested_if_keeps_and() {
+        check_assist(
+            merge_nested_if,
+            "fn f() { i$0f a && b { if c { foo() } } }",
+            "fn f() { if a && b && c { foo() } }",
+        )
+    }
+
+    #[test]
+    fn merge_nested_if_not_applicable_with_cursor_not_on_if() {
+        check_assist_not_applicable(merge_nested_if, "fn f() { if a$0 { if b { foo() } } }")
+    }
+
+    #[test]
+    fn merge_nested_if_not_applicable_with_outer_else() {
+        check_assist_not_applicable(
+            merge_nested_if,
+            "fn f() { i$0f a { if b { foo() } } else { bar() } }",
+        )
+    }
+
+    #[test]
+    fn merge_nested_if_not_applicable_with_inner_else() {
+        check_assist_not_applicable(
+            merge_nested_if,
+            "fn f() { i$0f a { if b { foo() } else { bar() } } }",
+        )
+    }
+
+    #[test]
+    fn merge_nested_if_not_applicable_with_statement_before() {
+        cov_mark::check!(merge_nested_if_not_applicable_with_other_statements);
+        check_assist_not_applicable(merge_nested_if, "fn f() { i$0f a { bar(); if b { foo() } } }")
+    }
+
+    #[test]
+    fn merge_nested_if_not_applicable_with_statement_after() {
+        cov_mark::check!(merge_nested_if_not_applicable_with_other_statements);
+        check_assist_not_applicable(merge_nested_if, "fn f() { i$0f a { if b { foo() } bar(); } }")
+    }
+
+    #[test]
+    fn merge_nested_if_not_applicable_with_if_let() {
+        check_assist_not_applicable(
+            merge_nested_if,
+            "fn f() { i$0f let Some(x) = a { if x { foo() } } }",
+        )
+    }
+}
@@ -2,9 +2,9 @@
     ast::{
         self,
         edit_in_place::{GenericParamsOwnerEdit, Removable},
-        make, AstNode, HasName, HasTypeBounds,
+        make, AstNode, HasGenericParams, HasName, HasTypeBounds,
     },
-    match_ast,
+    match_ast, ted, SyntaxKind, TextRange,
 };
 
 use crate::{AssistContext, AssistId, AssistKind, Assists};
@@ -30,10 +30,11 @@ pub(crate) fn move_bounds_to_where_clause(
 ) -> Option<()> {
     let type_param_list = ctx.find_node_at_offset::<ast::GenericParamList>()?;
 
-    let mut type_params = type_param_list.type_or_const_params();
-    if type_params.all(|p| match p {
-        ast::TypeOrConstParam::Type(t) => t.type_bound_list().is_none(),
-        ast::TypeOrConstParam::Const(_) => true,
+    let mut params = type_param_list.generic_params();
+    if params.all(|p| match p {
+        ast::GenericParam::TypeParam(t) => t.type_bound_list().is_none(),
+        ast::GenericParam::LifetimeParam(l) => l.colon_token().is_none(),
+        ast::GenericParam::ConstParam(_) => true,
     }) {
         return None;
     }
@@ -60,33 +61,168 @@ pub(crate) fn move_bounds_to_where_clause(
                 }
             };
 
-            for toc_param in type_param_list.type_or_const_params() {
-                let type_param = match toc_param {
-                    ast::TypeOrConstParam::Type(x) => x,
-                    ast::TypeOrConstParam::Const(_) => continue,
-                };
-                if let Some(tbl) = type_param.type_bound_list() {
-                    if let Some(predicate) = build_predicate(type_param) {
-                        where_clause.add_predicate(predicate)
+            for param in type_param_list.generic_params() {
+                match &param {
+                    ast::GenericParam::TypeParam(it) => {
+                        if let Some(tbl) = it.type_bound_list() {
+                            if let Some(predicate) = build_predicate(param) {
+                                where_clause.add_predicate(predicate)
+                            }
+                            tbl.remove()
+                        }
+                    }
+                    // Lifetime bounds aren't a `TypeBoundList`, just the lifetimes after the colon.
+                    ast::GenericParam::LifetimeParam(it) => {
+                        if let Some(colon) = it.colon_token() {
+                            if let Some(predicate) = build_predicate(param.clone()) {
+                                where_clause.add_predicate(predicate)
+                            }
+                            let last = it.syntax().last_child_or_token();
+                            ted::remove_all(colon.into()..=last.unwrap())
+                        }
                     }
-                    tbl.remove()
+                    ast::GenericParam::ConstParam(_) => (),
                 }
             }
         },
     )
 }
 
-fn build_predicate(param: ast::TypeParam) -> Option<ast::WherePred> {
-    let path = make::ext::ident_path(&param.name()?.syntax().to_string());
-    let predicate = make::where_pred(path, param.type_bound_list()?.bounds());
+fn build_predicate(param: ast::GenericParam) -> Option<ast::WherePred> {
+    let predicate = match param {
+        ast::GenericParam::TypeParam(it) => {
+            let path = make::ext::ident_path(&it.name()?.syntax().to_string());
+            make::where_pred(path, it.type_bound_list()?.bounds())
+        }
+        ast::GenericParam::LifetimeParam(it) => {
+            make::lifetime_where_pred(it.lifetime()?, it.lifetime_bounds())
+        }
+        ast::GenericParam::ConstParam(_) => return None,
+    };
     Some(predicate.clone_for_update())
 }
 
+// Assist: move_where_clause_to_bounds
+//
+// Moves the predicates of a where clause back into inline bounds.
+//
+// ```
+// impl<T> Foo<T> where$0 T: Clone + Debug {
+// }
+// ```
+// ->
+// ```
+// impl<T: Clone + Debug> Foo<T> {
+// }
+// ```
+pub(crate) fn move_where_clause_to_bounds(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let where_clause = ctx.find_node_at_offset::<ast::WhereClause>()?;
+    let owner = ast::AnyHasGenericParams::cast(where_clause.syntax().parent()?)?;
+    let param_list = owner.generic_param_list()?;
+
+    // Predicates are grouped by the parameter they bound, so that `T: A, T: B` becomes `T: A + B`.
+    let mut moved: Vec<(ast::GenericParam, Vec<String>)> = Vec::new();
+    for predicate in where_clause.predicates() {
+        let param = match bounded_param(&param_list, &predicate) {
+            Some(it) => it,
+            None => {
+                cov_mark::hit!(where_clause_is_not_simple);
+                return None;
+            }
+        };
+        let bounds = predicate.type_bound_list()?.to_string();
+        match moved.iter_mut().find(|(it, _)| *it == param) {
+            Some((_, it)) => it.push(bounds),
+            None => moved.push((param, vec![bounds])),
+        }
+    }
+    if moved.is_empty() {
+        return None;
+    }
+
+    let target = where_clause.syntax().text_range();
+    acc.add(
+        AssistId("move_where_clause_to_bounds", AssistKind::RefactorRewrite),
+        "Move to inline bounds",
+        target,
+        |edit| {
+            for (param, bounds) in moved {
+                let bounds = bounds.join(" + ");
+                let (existing, bounded) = match &param {
+                    ast::GenericParam::TypeParam(it) => (
+                        it.type_bound_list().map(|it| it.syntax().text_range()),
+                        it.name().map(|it| it.syntax().text_range()),
+                    ),
+                    ast::GenericParam::LifetimeParam(it) => (
+                        it.lifetime_bounds().last().map(|it| it.syntax().text_range()),
+                        it.lifetime().map(|it| it.syntax().text_range()),
+                    ),
+                    ast::GenericParam::ConstParam(_) => continue,
+                };
+                match (existing, bounded) {
+                    (Some(range), _) => edit.insert(range.end(), format!(" + {bounds}")),
+                    (None, Some(range)) => edit.insert(range.end(), format!(": {bounds}")),
+                    (None, None) => (),
+                }
+            }
+            let (range, replacement) = where_clause_removal(&where_clause);
+            edit.replace(range, replacement);
+        },
+    )
+}
+
+/// Finds the parameter of `params` that `predicate` bounds, if it's a plain parameter.
+fn bounded_param(
+    params: &ast::GenericParamList,
+    predicate: &ast::WherePred,
+) -> Option<ast::GenericParam> {
+    // Higher-ranked predicates can't be written inline.
+    if predicate.generic_param_list().is_some() {
+        return None;
+    }
+    if let Some(lifetime) = predicate.lifetime() {
+        return params
+            .lifetime_params()
+            .find(|it| it.lifetime().map_or(false, |it| it.text() == lifetime.text()))
+            .map(ast::GenericParam::LifetimeParam);
+    }
+    let name_ref = match predicate.ty()? {
+        ast::Type::PathType(it) => it.path()?.as_single_name_ref()?,
+        _ => return None,
+    };
+    params.type_or_const_params().find_map(|it| match it {
+        ast::TypeOrConstParam::Type(it) if it.name()?.text() == name_ref.text() => {
+            Some(ast::GenericParam::TypeParam(it))
+        }
+        _ => None,
+    })
+}
+
+/// Returns the range to replace to remove `where_clause` along with its surrounding whitespace.
+fn where_clause_removal(where_clause: &ast::WhereClause) -> (TextRange, &'static str) {
+    let is_whitespace = |it: &syntax::SyntaxElement| it.kind() == SyntaxKind::WHITESPACE;
+    let range = where_clause.syntax().text_range();
+    let start = match where_clause.syntax().prev_sibling_or_token().filter(is_whitespace) {
+        Some(it) => it.text_range().start(),
+        None => range.start(),
+    };
+    // A multiline where clause leaves the opening brace of the body on a line of its own.
+    match where_clause.syntax().next_sibling_or_token().filter(is_whitespace) {
+        Some(it) if it.to_string().contains('\n') => {
+            (TextRange::new(start, it.text_range().end()), " ")
+        }
+        _ => (TextRange::new(start, range.end()), ""),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::tests::check_assist;
+    use crate::tests::{check_assist, check_assist_not_applicable};
 
     #[test]
     fn move_bounds_to_where_clause_fn() {
@@ -123,4 +259,95 @@ fn move_bounds_to_where_clause_tuple_struct() {
             r#"struct Pair<T>(T, T) where T: u32;"#,
         );
     }
+
+    #[test]
+    fn move_bounds_to_where_clause_lifetimes() {
+        check_assist(
+            move_bounds_to_where_clause,
+            r#"impl<'a, 'b: 'a, $0T: Clone + 'b> Foo<'a, 'b, T> {}"#,
+            r#"impl<'a, 'b, T> Foo<'a, 'b, T> where 'b: 'a, T: Clone + 'b {}"#,
+        );
+        check_assist(
+            move_bounds_to_where_clause,
+            r#"fn foo<'a, $0'b: 'a + 'static>() {}"#,
+            r#"fn foo<'a, 'b>() where 'b: 'a + 'static {}"#,
+        );
+    }
+
+    #[test]
+    fn move_bounds_to_existing_where_clause() {
+        check_assist(
+            move_bounds_to_where_clause,
+            r#"
+impl<$0T: Clone, U: Debug> Foo<T, U>
+where
+    Vec<T>: Default,
+{
+}
+"#,
+            r#"
+impl<T, U> Foo<T, U>
+where
+    Vec<T>: Default, T: Clone, U: Debug
+{
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn move_where_clause_to_bounds_multiline() {
+        check_assist(
+            move_where_clause_to_bounds,
+            r#"
+impl<'a, T: Clone, U> Foo<'a, T, U>
+where
+    T: Debug,
+    $0U: Iterator<Item = &'a T>,
+    U: 'a,
+{
+}
+"#,
+            r#"
+impl<'a, T: Clone + Debug, U: Iterator<Item = &'a T> + 'a> Foo<'a, T, U> {
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn move_where_clause_to_bounds_fn_and_lifetimes() {
+        check_assist(
+            move_where_clause_to_bounds,
+            r#"fn foo<'a, 'b, T>(x: &'a T, y: &'b T) where$0 'b: 'a, T: Copy {}"#,
+            r#"fn foo<'a, 'b: 'a, T: Copy>(x: &'a T, y: &'b T) {}"#,
+        );
+        check_assist(
+            move_where_clause_to_bounds,
+            r#"fn foo<'a, 'b: 'c, 'c>() where$0 'b: 'a {}"#,
+            r#"fn foo<'a, 'b: 'c + 'a, 'c>() {}"#,
+        );
+        check_assist(
+            move_where_clause_to_bounds,
+            r#"struct Pair<T>(T, T) where $0T: Copy;"#,
+            r#"struct Pair<T: Copy>(T, T);"#,
+        );
+    }
+
+    #[test]
+    fn move_where_clause_to_bounds_not_simple() {
+        cov_mark::check_count!(where_clause_is_not_simple, 3);
+        check_assist_not_applicable(
+            move_where_clause_to_bounds,
+            r#"fn foo<T>() where$0 T: Copy, Vec<T>: Default {}"#,
+        );
+        check_assist_not_applicable(
+            move_where_clause_to_bounds,
+            r#"fn foo<F>() where$0 for<'a> F: Fn(&'a u8) {}"#,
+        );
+        check_assist_not_applicable(
+            move_where_clause_to_bounds,
+            r#"trait Tr { fn foo<T>() where$0 Self: Sized; }"#,
+        );
+    }
 }
@@ -0,0 +1,509 @@
+use ide_db::{source_change::SourceChangeBuilder, traits::resolve_target_trait};
+use syntax::{
+    ast::{
+        self,
+        edit::{AstNodeEdit, IndentLevel},
+        make, HasGenericParams, HasName, HasVisibility,
+    },
+    ted, AstNode, SyntaxElement, SyntaxKind, TextRange, T,
+};
+
+use crate::{
+    utils::{impl_fn_at_cursor, signature_shape, trait_fn_named},
+    AssistContext, AssistId, AssistKind, Assists,
+};
+
+// Assist: move_method_to_inherent_impl
+//
+// Moves a method the trait doesn't declare out of a trait impl into an inherent impl of the same
+// type, creating one if there is none yet.
+//
+// ```
+// trait Trait {}
+// struct S;
+// impl Trait for S {
+//     fn $0helper(&self) {}
+// }
+// ```
+// ->
+// ```
+// trait Trait {}
+// struct S;
+// impl Trait for S {
+// }
+//
+// impl S {
+//     fn helper(&self) {}
+// }
+// ```
+pub(crate) fn move_method_to_inherent_impl(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let (impl_, fn_) = impl_fn_at_cursor(ctx)?;
+    impl_.trait_()?;
+    let trait_ = resolve_target_trait(&ctx.sema, &impl_)?;
+    let name = fn_.name()?;
+
+    // Moving a method the trait requires would leave the trait impl incomplete, and moving an
+    // override of a default would silently switch callers going through the trait to the default.
+    if trait_fn_named(ctx.db(), trait_, &name.text()).is_some() {
+        cov_mark::hit!(move_method_declared_by_trait);
+        return None;
+    }
+    if mentions_assoc_items_of_self(&fn_) {
+        cov_mark::hit!(move_method_uses_assoc_items);
+        return None;
+    }
+
+    // Callers could reach the method wherever they could reach the trait.
+    let vis = match ctx.sema.source(trait_)?.value.visibility() {
+        Some(vis) if vis.syntax().text() == "pub" => Some(make::visibility_pub()),
+        Some(_) => Some(make::visibility_pub_crate()),
+        None => None,
+    };
+    let moved_fn = with_visibility(&fn_, vis);
+    let inherent_impl = impls_of_same_type(&impl_).find(|it| it.trait_().is_none());
+    let target = fn_.syntax().text_range();
+    acc.add(
+        AssistId("move_method_to_inherent_impl", AssistKind::RefactorRewrite),
+        format!("Move `{name}` to an inherent impl"),
+        target,
+        |builder| {
+            builder.delete(removal_range(&fn_));
+            match inherent_impl {
+                Some(inherent_impl) => add_to_impl(builder, &inherent_impl, &moved_fn),
+                None => {
+                    let indent = IndentLevel::from_node(impl_.syntax());
+                    let fn_ = moved_fn.reset_indent().indent(indent + 1);
+                    let generics = impl_.generic_param_list().map(|it| it.to_string());
+                    let self_ty = impl_.self_ty().map(|it| it.to_string()).unwrap_or_default();
+                    let where_clause =
+                        impl_.where_clause().map(|it| format!(" {it}")).unwrap_or_default();
+                    builder.insert(
+                        impl_.syntax().text_range().end(),
+                        format!(
+                            "\n\n{indent}impl{} {self_ty}{where_clause} {{\n{}{fn_}\n{indent}}}",
+                            generics.unwrap_or_default(),
+                            indent + 1,
+                        ),
+                    );
+                }
+            }
+        },
+    )
+}
+
+// Assist: move_method_to_trait_impl
+//
+// Moves a method out of an inherent impl into an impl of a trait that declares it.
+//
+// ```
+// trait Trait {
+//     fn foo(&self);
+// }
+// struct S;
+// impl S {
+//     pub fn $0foo(&self) {}
+// }
+// impl Trait for S {}
+// ```
+// ->
+// ```
+// trait Trait {
+//     fn foo(&self);
+// }
+// struct S;
+// impl S {
+// }
+// impl Trait for S {
+//     fn foo(&self) {}
+// }
+// ```
+pub(crate) fn move_method_to_trait_impl(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let (impl_, fn_) = impl_fn_at_cursor(ctx)?;
+    if impl_.trait_().is_some() {
+        return None;
+    }
+    let name = fn_.name()?;
+    let params = fn_.param_list()?;
+
+    let target = fn_.syntax().text_range();
+    for trait_impl in impls_of_same_type(&impl_).filter(|it| it.trait_().is_some()) {
+        let trait_ = match resolve_target_trait(&ctx.sema, &trait_impl) {
+            Some(it) => it,
+            None => continue,
+        };
+        let trait_fn = match trait_fn_named(ctx.db(), trait_, &name.text()) {
+            Some(it) => it,
+            None => continue,
+        };
+        let already_implemented = trait_impl.assoc_item_list().map_or(false, |list| {
+            list.assoc_items().any(|item| match item {
+                ast::AssocItem::Fn(it) => it.name().map_or(false, |it| it.text() == name.text()),
+                _ => false,
+            })
+        });
+        if already_implemented {
+            continue;
+        }
+        let trait_params = ctx.sema.source(trait_fn).and_then(|it| it.value.param_list());
+        if trait_params.map_or(true, |it| signature_shape(&it) != signature_shape(&params)) {
+            cov_mark::hit!(move_method_signature_mismatch);
+            continue;
+        }
+
+        acc.add(
+            AssistId("move_method_to_trait_impl", AssistKind::RefactorRewrite),
+            format!("Move `{name}` to `impl {}`", trait_.name(ctx.db())),
+            target,
+            |builder| {
+                builder.delete(removal_range(&fn_));
+                // Items of trait impls take the visibility of the trait.
+                let fn_ = fn_.clone_for_update();
+                if let Some(vis) = fn_.visibility() {
+                    if let Some(ws) = vis.syntax().next_sibling_or_token() {
+                        if ws.kind() == SyntaxKind::WHITESPACE {
+                            ted::remove(ws);
+                        }
+                    }
+                    ted::remove(vis.syntax());
+                }
+                add_to_impl(builder, &trait_impl, &fn_);
+            },
+        );
+    }
+    Some(())
+}
+
+/// Returns a mutable copy of `fn_` with `vis` in front of it.
+fn with_visibility(fn_: &ast::Fn, vis: Option<ast::Visibility>) -> ast::Fn {
+    let fn_ = fn_.clone_for_update();
+    let first = fn_.syntax().children_with_tokens().find(|it| {
+        !matches!(it.kind(), SyntaxKind::WHITESPACE | SyntaxKind::COMMENT | SyntaxKind::ATTR)
+    });
+    if let Some((vis, first)) = vis.zip(first) {
+        let vis = vis.clone_for_update().syntax().clone().into();
+        ted::insert_all(
+            ted::Position::before(first),
+            vec![vis, make::tokens::single_space().into()],
+        );
+    }
+    fn_
+}
+
+/// Returns the impls next to `impl_` that are for the same, identically spelled, type.
+fn impls_of_same_type(impl_: &ast::Impl) -> impl Iterator<Item = ast::Impl> + '_ {
+    let key = |it: &ast::Impl| {
+        (it.generic_param_list().map(|it| it.to_string()), it.self_ty().map(|it| it.to_string()))
+    };
+    let parent = impl_.syntax().parent();
+    parent
+        .into_iter()
+        .flat_map(|it| it.children())
+        .filter_map(ast::Impl::cast)
+        .filter(move |it| it != impl_ && key(it) == key(impl_))
+}
+
+/// Checks whether the signature of `fn_` refers to any `Self::Item`, which might be an
+/// associated item of the trait.
+fn mentions_assoc_items_of_self(fn_: &ast::Fn) -> bool {
+    let signature = fn_
+        .param_list()
+        .map(|it| it.syntax().clone())
+        .into_iter()
+        .chain(fn_.ret_type().map(|it| it.syntax().clone()))
+        .chain(fn_.generic_param_list().map(|it| it.syntax().clone()))
+        .chain(fn_.where_clause().map(|it| it.syntax().clone()));
+    signature.flat_map(|it| it.descendants()).filter_map(ast::Path::cast).any(|path| {
+        path.qualifier()
+            .and_then(|it| it.segment())
+            .map_or(false, |it| it.self_type_token().is_some())
+    })
+}
+
+/// Returns the range to delete to remove `fn_` from its impl, along with the whitespace
+/// separating it from its neighbours.
+fn removal_range(fn_: &ast::Fn) -> TextRange {
+    let range = fn_.syntax().text_range();
+    let whitespace =
+        |it: Option<SyntaxElement>| it.filter(|it| it.kind() == SyntaxKind::WHITESPACE);
+    let prev = whitespace(fn_.syntax().prev_sibling_or_token());
+    let is_first = prev
+        .as_ref()
+        .and_then(|it| it.prev_sibling_or_token())
+        .map_or(true, |it| it.kind() == T!['{']);
+    if is_first {
+        let next = whitespace(fn_.syntax().next_sibling_or_token());
+        if let Some(next) =
+            next.filter(|it| it.next_sibling_or_token().map_or(false, |it| it.kind() != T!['}']))
+        {
+            return range.cover(next.text_range());
+        }
+    }
+    prev.map_or(range, |prev| prev.text_range().cover(range))
+}
+
+/// Appends `fn_` to the items of `impl_`.
+fn add_to_impl(builder: &mut SourceChangeBuilder, impl_: &ast::Impl, fn_: &ast::Fn) {
+    let indent = IndentLevel::from_node(impl_.syntax()) + 1;
+    let fn_ = fn_.reset_indent().indent(indent);
+    let list = match impl_.assoc_item_list() {
+        Some(it) => it,
+        None => return,
+    };
+    match list.assoc_items().last() {
+        Some(last) => {
+            builder.insert(last.syntax().text_range().end(), format!("\n\n{indent}{fn_}"))
+        }
+        None => builder.replace(
+            list.syntax().text_range(),
+            format!("{{\n{indent}{fn_}\n{}}}", IndentLevel::from_node(impl_.syntax())),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn move_to_new_inherent_impl() {
+        check_assist(
+            move_method_to_inherent_impl,
+            r#"
+trait Trait {
+    fn foo(&self);
+}
+struct S<T>(T);
+impl<T> Trait for S<T> {
+    fn foo(&self) {}
+
+    fn $0helper(&self) -> u32 {
+        0
+    }
+}
+"#,
+            r#"
+trait Trait {
+    fn foo(&self);
+}
+struct S<T>(T);
+impl<T> Trait for S<T> {
+    fn foo(&self) {}
+}
+
+impl<T> S<T> {
+    fn helper(&self) -> u32 {
+        0
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn move_to_existing_inherent_impl() {
+        check_assist(
+            move_method_to_inherent_impl,
+            r#"
+pub trait Trait {
+    fn foo(&self) {}
+}
+pub struct S;
+impl S {
+    fn new() -> S {
+        S
+    }
+}
+impl Trait for S {
+    /// Helps.
+    fn $0helper(&self) {}
+}
+"#,
+            r#"
+pub trait Trait {
+    fn foo(&self) {}
+}
+pub struct S;
+impl S {
+    fn new() -> S {
+        S
+    }
+
+    /// Helps.
+    pub fn helper(&self) {}
+}
+impl Trait for S {
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn move_to_inherent_not_applicable_for_trait_methods() {
+        cov_mark::check_count!(move_method_declared_by_trait, 2);
+        check_assist_not_applicable(
+            move_method_to_inherent_impl,
+            r#"
+trait Trait {
+    fn foo(&self);
+}
+struct S;
+impl Trait for S {
+    fn $0foo(&self) {}
+}
+"#,
+        );
+        check_assist_not_applicable(
+            move_method_to_inherent_impl,
+            r#"
+trait Trait {
+    fn foo(&self) {}
+}
+struct S;
+impl Trait for S {
+    fn $0foo(&self) {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn move_to_new_inherent_impl_in_crate() {
+        check_assist(
+            move_method_to_inherent_impl,
+            r#"
+pub(super) trait Trait {}
+struct S;
+impl Trait for S {
+    fn $0helper(&self) {}
+}
+"#,
+            r#"
+pub(super) trait Trait {}
+struct S;
+impl Trait for S {
+}
+
+impl S {
+    pub(crate) fn helper(&self) {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn move_to_inherent_not_applicable_with_assoc_types() {
+        cov_mark::check!(move_method_uses_assoc_items);
+        check_assist_not_applicable(
+            move_method_to_inherent_impl,
+            r#"
+trait Trait {
+    type Item;
+}
+struct S;
+impl Trait for S {
+    type Item = u32;
+    fn $0helper(&self) -> Self::Item {
+        0
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn move_to_trait_impl() {
+        check_assist(
+            move_method_to_trait_impl,
+            r#"
+trait Trait {
+    fn foo(&self) -> u32;
+    fn bar(&self);
+}
+struct S;
+impl S {
+    pub fn $0foo(&self) -> u32 {
+        0
+    }
+
+    fn other() {}
+}
+impl Trait for S {
+    fn bar(&self) {}
+}
+"#,
+            r#"
+trait Trait {
+    fn foo(&self) -> u32;
+    fn bar(&self);
+}
+struct S;
+impl S {
+    fn other() {}
+}
+impl Trait for S {
+    fn bar(&self) {}
+
+    fn foo(&self) -> u32 {
+        0
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn move_to_trait_impl_not_applicable_for_mismatched_signature() {
+        cov_mark::check!(move_method_signature_mismatch);
+        check_assist_not_applicable(
+            move_method_to_trait_impl,
+            r#"
+trait Trait {
+    fn foo(&self, x: u32);
+}
+struct S;
+impl S {
+    fn $0foo(&self) {}
+}
+impl Trait for S {}
+"#,
+        );
+    }
+
+    #[test]
+    fn move_to_trait_impl_not_applicable_when_implemented() {
+        check_assist_not_applicable(
+            move_method_to_trait_impl,
+            r#"
+trait Trait {
+    fn foo(&self);
+}
+struct S;
+impl S {
+    fn $0foo(&self) {}
+}
+impl Trait for S {
+    fn foo(&self) {}
+}
+"#,
+        );
+        check_assist_not_applicable(
+            move_method_to_trait_impl,
+            r#"
+trait Trait {
+    fn foo(&self);
+}
+struct S;
+struct T;
+impl S {
+    fn $0foo(&self) {}
+}
+impl Trait for T {}
+"#,
+        );
+    }
+}
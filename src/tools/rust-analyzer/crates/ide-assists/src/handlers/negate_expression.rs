@@ -0,0 +1,172 @@
+use syntax::{
+    ast::{self, make, AstNode, BinaryOp, LogicOp},
+    T,
+};
+
+use crate::{utils::invert_boolean_expression, AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: negate_expression
+//
+// Negates a boolean expression, pushing the negation inwards where possible.
+//
+// ```
+// fn main() {
+//     if $0x > 0 && !y$0 {}
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     if x <= 0 || y {}
+// }
+// ```
+pub(crate) fn negate_expression(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let selection = ctx.selection_trimmed();
+    let mut exprs = ctx.covering_element().ancestors().filter_map(ast::Expr::cast);
+    let expr = if selection.is_empty() {
+        exprs.find(|expr| is_bool(ctx, expr))?
+    } else {
+        let expr = exprs.find(|expr| expr.syntax().text_range() == selection)?;
+        if !is_bool(ctx, &expr) {
+            return None;
+        }
+        expr
+    };
+
+    let target = expr.syntax().text_range();
+    acc.add(
+        AssistId("negate_expression", AssistKind::RefactorRewrite),
+        "Negate expression",
+        target,
+        |builder| builder.replace(target, negate(&expr).to_string()),
+    )
+}
+
+fn is_bool(ctx: &AssistContext<'_>, expr: &ast::Expr) -> bool {
+    ctx.sema.type_of_expr(expr).map_or(false, |ty| ty.original.is_bool())
+}
+
+fn negate(expr: &ast::Expr) -> ast::Expr {
+    match expr {
+        ast::Expr::BinExpr(bin) => {
+            let (lhs, rhs) = match (bin.lhs(), bin.rhs()) {
+                (Some(lhs), Some(rhs)) => (lhs, rhs),
+                _ => return invert_boolean_expression(expr.clone()),
+            };
+            let op = match bin.op_kind() {
+                Some(BinaryOp::LogicOp(LogicOp::And)) => LogicOp::Or,
+                Some(BinaryOp::LogicOp(LogicOp::Or)) => LogicOp::And,
+                _ => return invert_boolean_expression(expr.clone()),
+            };
+            let operand = |expr: &ast::Expr| {
+                let negated = negate(expr);
+                // `||` binds weaker than `&&`, every other negated operand binds stronger.
+                let is_or = matches!(
+                    &negated,
+                    ast::Expr::BinExpr(it) if it.op_kind() == Some(BinaryOp::LogicOp(LogicOp::Or))
+                );
+                if op == LogicOp::And && is_or {
+                    make::expr_paren(negated)
+                } else {
+                    negated
+                }
+            };
+            make::expr_bin_op(operand(&lhs), BinaryOp::LogicOp(op), operand(&rhs))
+        }
+        ast::Expr::ParenExpr(paren) => match paren.expr() {
+            Some(inner) => match negate(&inner) {
+                negated @ ast::Expr::BinExpr(_) => make::expr_paren(negated),
+                negated => negated,
+            },
+            None => invert_boolean_expression(expr.clone()),
+        },
+        ast::Expr::MethodCallExpr(_)
+        | ast::Expr::PrefixExpr(_)
+        | ast::Expr::Literal(_)
+        | ast::Expr::PathExpr(_)
+        | ast::Expr::CallExpr(_)
+        | ast::Expr::FieldExpr(_)
+        | ast::Expr::IndexExpr(_)
+        | ast::Expr::MacroExpr(_)
+        | ast::Expr::TryExpr(_)
+        | ast::Expr::AwaitExpr(_) => invert_boolean_expression(expr.clone()),
+        _ => make::expr_prefix(T![!], make::expr_paren(expr.clone())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    fn check(before: &str, after: &str) {
+        check_assist(
+            negate_expression,
+            &format!("fn f(a: bool, b: bool, c: bool, x: i32, y: i32) {{ let _ = {before}; }}"),
+            &format!("fn f(a: bool, b: bool, c: bool, x: i32, y: i32) {{ let _ = {after}; }}"),
+        );
+    }
+
+    #[test]
+    fn negate_and() {
+        check("$0a && b$0", "!a || !b");
+    }
+
+    #[test]
+    fn negate_or() {
+        check("$0a || b$0", "!a && !b");
+    }
+
+    #[test]
+    fn negate_not() {
+        check("$0!a$0", "a");
+        check("$0!(a && b)$0", "a && b");
+    }
+
+    #[test]
+    fn negate_comparisons() {
+        check("$0x == y$0", "x != y");
+        check("$0x != y$0", "x == y");
+        check("$0x < y$0", "x >= y");
+        check("$0x <= y$0", "x > y");
+        check("$0x > y$0", "x <= y");
+        check("$0x >= y$0", "x < y");
+    }
+
+    #[test]
+    fn negate_mixed_logic() {
+        check("$0a && !b && x < y$0", "!a || b || x >= y");
+        check("$0a || !b && c$0", "!a && (b || !c)");
+        check("$0(a || b) && c$0", "(!a && !b) || !c");
+    }
+
+    #[test]
+    fn negate_literals_and_paths() {
+        check("$0true$0", "false");
+        check("a && $0b$0", "a && !b");
+    }
+
+    #[test]
+    fn negate_falls_back_to_parens() {
+        check("$0if a { b } else { c }$0", "!(if a { b } else { c })");
+        check("$0x as u8 == 0 as u8$0", "x as u8 != 0 as u8");
+        check("$0(x as u8 != 0) as bool$0", "!((x as u8 != 0) as bool)");
+    }
+
+    #[test]
+    fn negate_innermost_bool_under_cursor() {
+        check("a && x $0< y", "a && x >= y");
+    }
+
+    #[test]
+    fn not_applicable_to_non_bool() {
+        check_assist_not_applicable(negate_expression, "fn f(x: i32) { let _ = $0x + 1$0; }");
+        check_assist_not_applicable(negate_expression, "fn f(x: i32) { let _ = x $0+ 1; }");
+    }
+
+    #[test]
+    fn not_applicable_to_partial_selection() {
+        check_assist_not_applicable(negate_expression, "fn f(a: bool) { let _ = $0a &$0& a; }");
+    }
+}
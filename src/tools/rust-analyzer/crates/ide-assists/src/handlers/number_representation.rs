@@ -23,12 +23,13 @@ pub(crate) fn reformat_number_literal(acc: &mut Assists, ctx: &AssistContext<'_>
     };
 
     let text = literal.text();
-    if text.contains('_') {
-        return remove_separators(acc, literal);
+    let has_separators = text.contains('_');
+    if has_separators {
+        remove_separators(acc, literal.clone());
     }
 
     let (prefix, value, suffix) = literal.split_into_parts();
-    if value.len() < MIN_NUMBER_OF_DIGITS_TO_FORMAT {
+    if value.chars().filter(|&ch| ch != '_').count() < MIN_NUMBER_OF_DIGITS_TO_FORMAT {
         return None;
     }
 
@@ -36,6 +37,11 @@ pub(crate) fn reformat_number_literal(acc: &mut Assists, ctx: &AssistContext<'_>
     let mut converted = prefix.to_string();
     converted.push_str(&add_group_separators(value, group_size(radix)));
     converted.push_str(suffix);
+    // Only regroup literals whose separators are not where we would put them.
+    if has_separators && converted == text {
+        cov_mark::hit!(number_literal_already_grouped);
+        return None;
+    }
 
     let group_id = GroupLabel("Reformat number literal".into());
     let label = format!("Convert {literal} to {converted}");
@@ -173,6 +179,17 @@ fn labels() {
                 "Convert 10000i32 to 10_000i32",
             ),
             ("const _: i32 = 1_0_0_0_i32$0;", "const _: i32 = 1000i32;", "Remove digit separators"),
+            (
+                "const _: i32 = 1_0000_000$0;",
+                "const _: i32 = 10_000_000;",
+                "Convert 1_0000_000 to 10_000_000",
+            ),
+            ("const _: i32 = 1_0000_000$0;", "const _: i32 = 10000000;", "Remove digit separators"),
+            (
+                "const _: u64 = 0xFF_FF_FFu64$0;",
+                "const _: u64 = 0xFF_FFFFu64;",
+                "Convert 0xFF_FF_FFu64 to 0xFF_FFFFu64",
+            ),
         ];
 
         for case in cases {
@@ -180,4 +197,15 @@ fn labels() {
             check_assist_by_label(reformat_number_literal, before, after, label);
         }
     }
+
+    #[test]
+    fn already_grouped() {
+        cov_mark::check!(number_literal_already_grouped);
+        check_assist_by_label(
+            reformat_number_literal,
+            "const _: u64 = 1_000_000u64$0;",
+            "const _: u64 = 1000000u64;",
+            "Remove digit separators",
+        );
+    }
 }
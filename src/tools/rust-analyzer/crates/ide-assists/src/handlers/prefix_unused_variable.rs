@@ -0,0 +1,195 @@
+use ide_db::defs::Definition;
+use syntax::{
+    ast::{self, AstToken, HasName},
+    AstNode, SyntaxKind,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: prefix_unused_variable
+//
+// Prefixes the name of an unused variable with an underscore.
+//
+// ```
+// fn main() {
+//     let (a, b$0) = (1, 2);
+//     a;
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let (a, _b) = (1, 2);
+//     a;
+// }
+// ```
+pub(crate) fn prefix_unused_variable(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let ident_pat = ctx.find_node_at_offset::<ast::IdentPat>()?;
+    let name = ident_pat.name()?;
+    let text = name.text().to_string();
+    if text.starts_with('_') {
+        return None;
+    }
+    // All alternatives of an or-pattern have to bind the same names.
+    if ident_pat
+        .syntax()
+        .ancestors()
+        .take_while(|it| ast::Pat::can_cast(it.kind()))
+        .any(|it| it.kind() == SyntaxKind::OR_PAT)
+    {
+        return None;
+    }
+    let local = ctx.sema.to_def(&ident_pat)?;
+    if Definition::Local(local).usages(&ctx.sema).at_least_one()
+        || is_captured_by_format_string(&ident_pat, &text)
+    {
+        cov_mark::hit!(prefix_unused_variable_used);
+        return None;
+    }
+
+    let target = name.syntax().text_range();
+    acc.add(
+        AssistId("prefix_unused_variable", AssistKind::QuickFix),
+        format!("Prefix `{text}` with an underscore"),
+        target,
+        |builder| {
+            let is_shorthand = ident_pat
+                .syntax()
+                .parent()
+                .and_then(ast::RecordPatField::cast)
+                .map_or(false, |it| it.colon_token().is_none());
+            if is_shorthand {
+                builder.insert(ident_pat.syntax().text_range().start(), format!("{text}: "));
+            }
+            builder.replace(target, format!("_{text}"));
+        },
+    )
+}
+
+/// The usage search doesn't look into format strings, so this checks whether any format string
+/// in the same item might capture `name`, as in `println!("{name}")`.
+fn is_captured_by_format_string(ident_pat: &ast::IdentPat, name: &str) -> bool {
+    let item = match ident_pat.syntax().ancestors().find(|it| ast::Item::can_cast(it.kind())) {
+        Some(it) => it,
+        None => return false,
+    };
+    item.descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|it| it.parent().map_or(false, |it| ast::TokenTree::can_cast(it.kind())))
+        .filter_map(ast::String::cast)
+        .any(|it| {
+            let text = it.text();
+            text.contains(&format!("{{{name}}}")) || text.contains(&format!("{{{name}:"))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn prefix_let_binding() {
+        check_assist(
+            prefix_unused_variable,
+            r#"
+fn main() {
+    let mut x$0 = 1;
+}
+"#,
+            r#"
+fn main() {
+    let mut _x = 1;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn prefix_param() {
+        check_assist(
+            prefix_unused_variable,
+            r#"
+fn foo($0x: u32, y: u32) -> u32 { y }
+"#,
+            r#"
+fn foo(_x: u32, y: u32) -> u32 { y }
+"#,
+        );
+    }
+
+    #[test]
+    fn prefix_shorthand_field_pattern() {
+        check_assist(
+            prefix_unused_variable,
+            r#"
+struct S { a: u32, b: u32 }
+fn foo(s: S) -> u32 {
+    let S { a, ref b$0 } = s;
+    a
+}
+"#,
+            r#"
+struct S { a: u32, b: u32 }
+fn foo(s: S) -> u32 {
+    let S { a, b: ref _b } = s;
+    a
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_used_variable() {
+        cov_mark::check!(prefix_unused_variable_used);
+        check_assist_not_applicable(
+            prefix_unused_variable,
+            r#"
+fn main() {
+    let (a$0, b) = (1, 2);
+    a;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_format_string_capture() {
+        check_assist_not_applicable(
+            prefix_unused_variable,
+            r#"
+macro_rules! format_args {
+    ($lit:literal $($tt:tt)*) => { 0 },
+}
+fn main() {
+    let a$0 = 1;
+    format_args!("{a:?}");
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_in_or_pattern_or_with_underscore() {
+        check_assist_not_applicable(
+            prefix_unused_variable,
+            r#"
+enum E { A(u32), B(u32) }
+fn foo(e: E) {
+    match e {
+        E::A(x$0) | E::B(x) => {}
+    }
+}
+"#,
+        );
+        check_assist_not_applicable(
+            prefix_unused_variable,
+            r#"
+fn main() {
+    let _x$0 = 1;
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,339 @@
+use ide_db::defs::Definition;
+use syntax::{
+    ast::{self, edit::IndentLevel, AstNode, HasGenericParams, HasName},
+    match_ast, TextRange,
+};
+
+use crate::{
+    handlers::remove_unused_param::range_to_remove, AssistContext, AssistId, AssistKind, Assists,
+};
+
+// Assist: promote_param_to_field
+//
+// Turns a parameter of a method into a field of the struct, which the method reads through
+// `self`. Callers and constructors are left for the user to update.
+//
+// ```
+// # //- minicore: copy
+// struct Counter {
+//     count: u32,
+// }
+//
+// impl Counter {
+//     fn next(&mut self, $0step: u32) -> u32 {
+//         self.count += step;
+//         self.count
+//     }
+// }
+// ```
+// ->
+// ```
+// struct Counter {
+//     count: u32,
+//     step: u32,
+// }
+//
+// impl Counter {
+//     fn next(&mut self) -> u32 {
+//         self.count += self.step;
+//         self.count
+//     }
+// }
+// ```
+pub(crate) fn promote_param_to_field(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let param = ctx.find_node_at_offset::<ast::Param>()?;
+    let ident_pat = match param.pat()? {
+        ast::Pat::IdentPat(it) if it.is_simple_ident() => it,
+        _ => return None,
+    };
+    let name = ident_pat.name()?;
+    let ty = param.ty()?;
+    let func = param.syntax().ancestors().find_map(ast::Fn::cast)?;
+    let self_param = func.param_list()?.self_param()?;
+    let impl_ = func.syntax().parent()?.parent().and_then(ast::Impl::cast)?;
+    // The signature of trait methods can't be changed.
+    if impl_.trait_().is_some() {
+        return None;
+    }
+
+    let strukt = match ctx.sema.to_def(&impl_)?.self_ty(ctx.db()).as_adt()? {
+        hir::Adt::Struct(it) => it,
+        _ => return None,
+    };
+    if strukt
+        .fields(ctx.db())
+        .iter()
+        .any(|it| it.name(ctx.db()).to_smol_str() == name.text().as_str())
+    {
+        cov_mark::hit!(promote_param_to_field_name_taken);
+        return None;
+    }
+    let strukt_src = ctx.sema.source(strukt)?;
+    if matches!(strukt_src.value.kind(), ast::StructKind::Tuple(_)) {
+        return None;
+    }
+    let strukt_file = strukt_src.file_id.original_file(ctx.db());
+    if strukt_src.file_id.is_macro() {
+        return None;
+    }
+    let strukt_ast = strukt_src.value;
+
+    // The type has to make sense in the struct, where nothing is inferred and the generics of the
+    // method aren't in scope.
+    let method_generics: Vec<_> = func
+        .generic_param_list()
+        .into_iter()
+        .flat_map(|it| it.generic_params())
+        .filter_map(|it| match it {
+            ast::GenericParam::TypeParam(it) => Some(it.name()?.to_string()),
+            ast::GenericParam::ConstParam(it) => Some(it.name()?.to_string()),
+            ast::GenericParam::LifetimeParam(it) => Some(it.lifetime()?.to_string()),
+        })
+        .collect();
+    let is_method_generic = |text: &str| method_generics.iter().any(|it| it == text);
+    let is_unnameable = ty.syntax().descendants().any(|node| {
+        match_ast! {
+            match node {
+                ast::ImplTraitType(_) => true,
+                ast::RefType(it) => it.lifetime().map_or(true, |it| it.text() == "'_"),
+                ast::NameRef(it) => is_method_generic(it.text().as_str()),
+                ast::Lifetime(it) => it.text() == "'_" || is_method_generic(it.text().as_str()),
+                _ => false,
+            }
+        }
+    });
+    if is_unnameable {
+        cov_mark::hit!(promote_param_to_field_unnameable_type);
+        return None;
+    }
+
+    let local = ctx.sema.to_def(&ident_pat)?;
+    // Reading the field through a reference can't move out of it.
+    if self_param.amp_token().is_some() && !local.ty(ctx.db()).is_copy(ctx.db()) {
+        cov_mark::hit!(promote_param_to_field_not_copy);
+        return None;
+    }
+    let target = param.syntax().text_range();
+    acc.add(
+        AssistId("promote_param_to_field", AssistKind::RefactorRewrite),
+        format!("Promote `{name}` to a field"),
+        target,
+        |builder| {
+            builder.delete(range_to_remove(param.syntax()));
+            let usages = Definition::Local(local).usages(&ctx.sema).all();
+            for reference in usages.iter().flat_map(|(_, refs)| refs) {
+                let shorthand = reference
+                    .name
+                    .as_name_ref()
+                    .and_then(ast::RecordExprField::for_field_name)
+                    .map_or(false, |it| it.name_ref().is_none());
+                let replacement = match shorthand {
+                    true => format!("{name}: self.{name}"),
+                    false => format!("self.{name}"),
+                };
+                builder.replace(reference.range, replacement);
+            }
+
+            builder.edit_file(strukt_file);
+            let (range, text) = field_insertion(&strukt_ast, &format!("{name}: {ty}"));
+            builder.replace(range, text);
+        },
+    )
+}
+
+/// Computes the edit adding `field` to the fields of `strukt`, which is either a record or a unit
+/// struct.
+fn field_insertion(strukt: &ast::Struct, field: &str) -> (TextRange, String) {
+    let field_list = match strukt.field_list() {
+        Some(ast::FieldList::RecordFieldList(it)) => it,
+        _ => {
+            // `struct S;` becomes `struct S { field }`.
+            let range = strukt.semicolon_token().map_or_else(
+                || TextRange::empty(strukt.syntax().text_range().end()),
+                |it| it.text_range(),
+            );
+            return (range, format!(" {{ {field} }}"));
+        }
+    };
+    let is_multiline = field_list.syntax().text().contains_char('\n');
+    let last_field = field_list.fields().last();
+    let comma = last_field.as_ref().and_then(|it| {
+        it.syntax()
+            .siblings_with_tokens(syntax::Direction::Next)
+            .filter_map(|it| it.into_token())
+            .find(|it| it.kind() == syntax::T![,])
+    });
+    let (offset, needs_comma) = match (&last_field, &comma) {
+        (_, Some(comma)) => (comma.text_range().end(), false),
+        (Some(last), None) => (last.syntax().text_range().end(), true),
+        (None, None) => match field_list.l_curly_token() {
+            Some(it) => (it.text_range().end(), false),
+            None => (field_list.syntax().text_range().start(), false),
+        },
+    };
+    let comma = if needs_comma { "," } else { "" };
+    let text = match (is_multiline, &last_field) {
+        (true, Some(last)) => format!("{comma}\n{}{field},", IndentLevel::from_node(last.syntax())),
+        (true, None) => {
+            format!("\n{}{field},", IndentLevel::from_node(strukt.syntax()) + 1)
+        }
+        (false, Some(_)) => format!("{comma} {field}"),
+        (false, None) => format!(" {field} "),
+    };
+    (TextRange::empty(offset), text)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn promote_into_single_line_struct_with_shorthand_use() {
+        check_assist(
+            promote_param_to_field,
+            r#"
+//- minicore: copy
+struct P { x: u32 }
+struct Q { x: u32, y: u32 }
+impl P {
+    fn q(&self, y$0: u32, z: u32) -> Q {
+        Q { x: self.x + z, y }
+    }
+}
+"#,
+            r#"
+struct P { x: u32, y: u32 }
+struct Q { x: u32, y: u32 }
+impl P {
+    fn q(&self, z: u32) -> Q {
+        Q { x: self.x + z, y: self.y }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn promote_into_unit_struct() {
+        check_assist(
+            promote_param_to_field,
+            r#"
+struct S;
+impl S {
+    fn get(self, $0v: Vec<u8>) -> Vec<u8> {
+        v
+    }
+}
+"#,
+            r#"
+struct S { v: Vec<u8> }
+impl S {
+    fn get(self) -> Vec<u8> {
+        self.v
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_field_exists() {
+        cov_mark::check!(promote_param_to_field_name_taken);
+        check_assist_not_applicable(
+            promote_param_to_field,
+            r#"
+struct S { x: u32 }
+impl S {
+    fn foo(&self, $0x: u32) {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_outside_inherent_methods() {
+        check_assist_not_applicable(
+            promote_param_to_field,
+            r#"
+struct S { x: u32 }
+impl S {
+    fn foo($0y: u32) {}
+}
+"#,
+        );
+        check_assist_not_applicable(
+            promote_param_to_field,
+            r#"
+struct S { x: u32 }
+trait T { fn foo(&self, y: u32); }
+impl T for S {
+    fn foo(&self, $0y: u32) {}
+}
+"#,
+        );
+        check_assist_not_applicable(
+            promote_param_to_field,
+            r#"
+struct S(u32);
+impl S {
+    fn foo(&self, $0y: u32) {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_unnameable_types() {
+        cov_mark::check_count!(promote_param_to_field_unnameable_type, 4);
+        for param in ["$0s: &str", "$0s: &'_ str", "$0t: impl Clone", "$0t: Vec<T>"] {
+            check_assist_not_applicable(
+                promote_param_to_field,
+                &format!(
+                    r#"
+struct S {{ x: u32 }}
+impl S {{
+    fn foo<T>(self, {param}) {{}}
+}}
+"#
+                ),
+            );
+        }
+    }
+
+    #[test]
+    fn not_applicable_to_moves_out_of_borrowed_self() {
+        cov_mark::check!(promote_param_to_field_not_copy);
+        check_assist_not_applicable(
+            promote_param_to_field,
+            r#"
+//- minicore: copy
+struct S { x: u32 }
+impl S {
+    fn foo(&self, $0v: Vec<u8>) -> Vec<u8> { v }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn promote_reference_with_named_lifetime() {
+        check_assist(
+            promote_param_to_field,
+            r#"
+//- minicore: copy
+struct S<'a> { x: &'a str }
+impl<'a> S<'a> {
+    fn foo(&self, $0y: &'a str) -> &'a str { y }
+}
+"#,
+            r#"
+struct S<'a> { x: &'a str, y: &'a str }
+impl<'a> S<'a> {
+    fn foo(&self) -> &'a str { self.y }
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,500 @@
+use either::Either;
+use ide_db::{
+    base_db::FileRange,
+    defs::{Definition, NameRefClass},
+    search::SearchScope,
+};
+use syntax::{
+    algo::find_node_at_range,
+    ast::{self, edit_in_place::Removable, make},
+    ted, AstNode, TextRange,
+};
+
+use crate::{
+    assist_context::SourceChangeBuilder,
+    handlers::{remove_unused_import::removal_target, unmerge_use::resolve_full_path},
+    AssistContext, AssistId, AssistKind, Assists,
+};
+
+// Assist: qualify_imported_name
+//
+// Replaces an imported name with the path it is imported from, and removes the import once
+// nothing refers to it anymore.
+//
+// ```
+// mod foo {
+//     pub struct Bar;
+// }
+// use foo::Bar;
+//
+// fn main() {
+//     let a: Bar$0 = Bar;
+// }
+// ```
+// ->
+// ```
+// mod foo {
+//     pub struct Bar;
+// }
+// use foo::Bar;
+//
+// fn main() {
+//     let a: foo::Bar = Bar;
+// }
+// ```
+pub(crate) fn qualify_imported_name(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let name_ref = ctx.find_node_at_offset::<ast::NameRef>()?;
+    let usage = unqualified_path(&name_ref)?;
+    let import = Import::find(ctx, &name_ref)?;
+
+    let remaining = import.references(ctx).into_iter().filter(|&range| range != usage.range);
+    let remove_import = remaining.count() == 0;
+
+    let target = name_ref.syntax().text_range();
+    acc.add(
+        AssistId("qualify_imported_name", AssistKind::RefactorRewrite),
+        format!("Qualify `{name_ref}` as `{}`", import.qualified),
+        target,
+        |builder| import.apply(builder, vec![usage.path], remove_import),
+    )
+}
+
+// Assist: qualify_all_imported_names
+//
+// Replaces every use of an imported name in the current file with the path it is imported from,
+// and removes the import.
+//
+// ```
+// mod foo {
+//     pub struct Bar;
+// }
+// use foo::Bar;
+//
+// fn main() {
+//     let a: Bar$0 = Bar;
+// }
+// ```
+// ->
+// ```
+// mod foo {
+//     pub struct Bar;
+// }
+//
+// fn main() {
+//     let a: foo::Bar = foo::Bar;
+// }
+// ```
+pub(crate) fn qualify_all_imported_names(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let name_ref = ctx.find_node_at_offset::<ast::NameRef>()?;
+    unqualified_path(&name_ref)?;
+    let import = Import::find(ctx, &name_ref)?;
+
+    let source_file = ctx.source_file().syntax();
+    let mut usages = Vec::new();
+    let mut remove_import = true;
+    for range in import.references(ctx) {
+        let usage = find_node_at_range::<ast::NameRef>(source_file, range)
+            .filter(|it| import.is_imported_at(ctx, it))
+            .and_then(|it| unqualified_path(&it));
+        match usage {
+            Some(usage) => usages.push(usage.path),
+            // Qualified paths and uses inside macro calls may still go through the import.
+            None => remove_import = false,
+        }
+    }
+    if usages.len() < 2 {
+        cov_mark::hit!(qualify_all_imported_names_single_usage);
+        return None;
+    }
+
+    let target = name_ref.syntax().text_range();
+    acc.add(
+        AssistId("qualify_all_imported_names", AssistKind::RefactorRewrite),
+        format!("Qualify all uses of `{name_ref}` as `{}`", import.qualified),
+        target,
+        |builder| import.apply(builder, usages, remove_import),
+    )
+}
+
+struct Usage {
+    path: ast::Path,
+    range: TextRange,
+}
+
+/// Returns the path `name_ref` starts, if it is a plain name that an import could provide.
+fn unqualified_path(name_ref: &ast::NameRef) -> Option<Usage> {
+    let segment = name_ref.syntax().parent().and_then(ast::PathSegment::cast)?;
+    let path = segment.parent_path();
+    if path.qualifier().is_some()
+        || path.syntax().ancestors().any(|it| ast::Use::can_cast(it.kind()))
+    {
+        return None;
+    }
+    Some(Usage { path, range: name_ref.syntax().text_range() })
+}
+
+struct Import {
+    use_: ast::Use,
+    tree: ast::UseTree,
+    qualified: ast::Path,
+    def: Definition,
+}
+
+impl Import {
+    /// Finds the leaf of a use tree that brings the item `name_ref` refers to into scope.
+    fn find(ctx: &AssistContext<'_>, name_ref: &ast::NameRef) -> Option<Import> {
+        let def = match NameRefClass::classify(&ctx.sema, name_ref)? {
+            NameRefClass::Definition(def) => def,
+            NameRefClass::FieldShorthand { .. } => return None,
+        };
+        let module = ctx.sema.scope(name_ref.syntax())?.module();
+        let usage_range = name_ref.syntax().text_range();
+
+        let (use_, tree) = ctx
+            .source_file()
+            .syntax()
+            .descendants()
+            .filter_map(ast::UseTree::cast)
+            .filter(|tree| {
+                tree.use_tree_list().is_none()
+                    && tree.star_token().is_none()
+                    && tree.rename().is_none()
+            })
+            .filter_map(|tree| {
+                let leaf = tree.path()?.segment()?.name_ref()?;
+                if leaf.text() != name_ref.text() {
+                    return None;
+                }
+                let use_ = tree.syntax().ancestors().find_map(ast::Use::cast)?;
+                // The import has to be in scope at the usage, items in nested modules don't see
+                // it.
+                if !use_.syntax().parent()?.text_range().contains_range(usage_range)
+                    || ctx.sema.scope(use_.syntax())?.module() != module
+                {
+                    return None;
+                }
+                match NameRefClass::classify(&ctx.sema, &leaf)? {
+                    NameRefClass::Definition(it) if it == def => Some((use_, tree)),
+                    _ => None,
+                }
+            })
+            // Prefer the innermost import, it shadows the ones further out.
+            .min_by_key(|(use_, _)| use_.syntax().parent().map(|it| it.text_range().len()))?;
+
+        let qualified = resolve_full_path(&tree)?;
+        // `use foo;` has nothing to qualify the name with.
+        qualified.qualifier()?;
+        Some(Import { use_, tree, qualified, def })
+    }
+
+    /// Whether `name_ref` would be resolved through this import.
+    fn is_imported_at(&self, ctx: &AssistContext<'_>, name_ref: &ast::NameRef) -> bool {
+        let in_scope = self
+            .use_
+            .syntax()
+            .parent()
+            .map_or(false, |it| it.text_range().contains_range(name_ref.syntax().text_range()));
+        in_scope
+            && ctx.sema.scope(name_ref.syntax()).map(|it| it.module())
+                == ctx.sema.scope(self.use_.syntax()).map(|it| it.module())
+    }
+
+    /// Ranges of all references to the imported item in the scope of the import, except for the
+    /// one in the import itself.
+    fn references(&self, ctx: &AssistContext<'_>) -> Vec<TextRange> {
+        let scope = match self.use_.syntax().parent() {
+            Some(it) => SearchScope::file_range(FileRange {
+                file_id: ctx.file_id(),
+                range: it.text_range(),
+            }),
+            None => return Vec::new(),
+        };
+        let tree_range = self.tree.syntax().text_range();
+        self.def
+            .usages(&ctx.sema)
+            .in_scope(scope)
+            .all()
+            .iter()
+            .flat_map(|(_, refs)| refs.iter().map(|it| it.range))
+            .filter(|range| !tree_range.contains_range(*range))
+            .collect()
+    }
+
+    fn apply(&self, builder: &mut SourceChangeBuilder, usages: Vec<ast::Path>, remove: bool) {
+        let qualifier = match self.qualified.qualifier() {
+            Some(it) => it,
+            None => return,
+        };
+        let replacements: Vec<_> = usages
+            .into_iter()
+            .filter_map(|path| {
+                let segment = path.segment()?;
+                let qualified =
+                    make::path_concat(qualifier.clone(), make::path_unqualified(segment));
+                Some((builder.make_mut(path), qualified.clone_for_update()))
+            })
+            .collect();
+        let removal = remove.then(|| match removal_target(self.use_.clone(), self.tree.clone()) {
+            Either::Left(use_) => Either::Left(builder.make_mut(use_)),
+            Either::Right(tree) => Either::Right(builder.make_mut(tree)),
+        });
+
+        for (path, qualified) in replacements {
+            ted::replace(path.syntax(), qualified.syntax());
+        }
+        if let Some(removal) = removal {
+            removal.either(|it| it.remove(), |it| it.remove());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn qualify_keeps_import_with_other_usages() {
+        check_assist(
+            qualify_imported_name,
+            r#"
+mod foo {
+    pub struct Bar;
+}
+use foo::Bar;
+fn f() {
+    let a: Bar$0 = Bar;
+}
+"#,
+            r#"
+mod foo {
+    pub struct Bar;
+}
+use foo::Bar;
+fn f() {
+    let a: foo::Bar = Bar;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn qualify_removes_import_after_last_usage() {
+        check_assist(
+            qualify_imported_name,
+            r#"
+mod foo {
+    pub struct Bar;
+}
+use foo::Bar;
+fn f() {
+    let _ = Bar$0;
+}
+"#,
+            r#"
+mod foo {
+    pub struct Bar;
+}
+fn f() {
+    let _ = foo::Bar;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn qualify_removes_name_from_use_list() {
+        check_assist(
+            qualify_imported_name,
+            r#"
+mod foo {
+    pub struct Bar;
+    pub struct Baz;
+}
+use foo::{Bar, Baz};
+fn f() {
+    let _ = (Bar$0, Baz);
+}
+"#,
+            r#"
+mod foo {
+    pub struct Bar;
+    pub struct Baz;
+}
+use foo::{Baz};
+fn f() {
+    let _ = (foo::Bar, Baz);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn qualify_keeps_generic_args() {
+        check_assist(
+            qualify_imported_name,
+            r#"
+mod foo {
+    pub struct Wrapper<T>(pub T);
+}
+use foo::Wrapper;
+fn f(w: Wrapper$0<u8>) {}
+"#,
+            r#"
+mod foo {
+    pub struct Wrapper<T>(pub T);
+}
+fn f(w: foo::Wrapper<u8>) {}
+"#,
+        );
+    }
+
+    #[test]
+    fn qualify_path_start() {
+        check_assist(
+            qualify_imported_name,
+            r#"
+mod foo {
+    pub mod bar {
+        pub fn baz() {}
+    }
+}
+use foo::bar;
+fn f() {
+    bar$0::baz();
+}
+"#,
+            r#"
+mod foo {
+    pub mod bar {
+        pub fn baz() {}
+    }
+}
+fn f() {
+    foo::bar::baz();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn qualify_not_applicable_for_qualified_path() {
+        check_assist_not_applicable(
+            qualify_imported_name,
+            r#"
+mod foo {
+    pub struct Bar;
+}
+fn f() {
+    let _ = foo::Bar$0;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn qualify_not_applicable_for_local_item() {
+        check_assist_not_applicable(
+            qualify_imported_name,
+            r#"
+struct Bar;
+fn f() {
+    let _ = Bar$0;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn qualify_not_applicable_for_renamed_import() {
+        check_assist_not_applicable(
+            qualify_imported_name,
+            r#"
+mod foo {
+    pub struct Bar;
+}
+use foo::Bar as Baz;
+fn f() {
+    let _ = Baz$0;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn qualify_all() {
+        check_assist(
+            qualify_all_imported_names,
+            r#"
+mod foo {
+    pub struct Bar;
+}
+use foo::Bar;
+fn f(b: Bar) -> Bar {
+    Bar$0
+}
+"#,
+            r#"
+mod foo {
+    pub struct Bar;
+}
+fn f(b: foo::Bar) -> foo::Bar {
+    foo::Bar
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn qualify_all_keeps_import_used_from_child_module() {
+        check_assist(
+            qualify_all_imported_names,
+            r#"
+mod foo {
+    pub struct Bar;
+}
+use foo::Bar;
+fn f(b: Bar) -> Bar {
+    Bar$0
+}
+mod child {
+    fn g() {
+        let _ = super::Bar;
+    }
+}
+"#,
+            r#"
+mod foo {
+    pub struct Bar;
+}
+use foo::Bar;
+fn f(b: foo::Bar) -> foo::Bar {
+    foo::Bar
+}
+mod child {
+    fn g() {
+        let _ = super::Bar;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn qualify_all_not_applicable_for_single_usage() {
+        cov_mark::check!(qualify_all_imported_names_single_usage);
+        check_assist_not_applicable(
+            qualify_all_imported_names,
+            r#"
+mod foo {
+    pub struct Bar;
+}
+use foo::Bar;
+fn f() {
+    let _ = Bar$0;
+}
+"#,
+        );
+    }
+}
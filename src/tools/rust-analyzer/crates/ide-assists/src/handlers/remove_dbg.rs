@@ -1,3 +1,4 @@
+use ide_db::famous_defs::FamousDefs;
 use itertools::Itertools;
 use syntax::{
     ast::{self, AstNode, AstToken},
@@ -8,7 +9,8 @@
 
 // Assist: remove_dbg
 //
-// Removes `dbg!()` macro call.
+// Removes `dbg!()` macro call. When a range is selected, this removes all `dbg!()` calls in it,
+// as well as `print!()`, `println!()`, `eprint!()` and `eprintln!()` statements.
 //
 // ```
 // fn main() {
@@ -22,26 +24,43 @@
 // }
 // ```
 pub(crate) fn remove_dbg(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
-    let macro_calls = if ctx.has_empty_selection() {
-        vec![ctx.find_node_at_offset::<ast::MacroCall>()?]
+    let (macro_calls, remove_prints) = if ctx.has_empty_selection() {
+        (vec![ctx.find_node_at_offset::<ast::MacroCall>()?], false)
     } else {
-        ctx.covering_element()
+        let macro_calls = ctx
+            .covering_element()
             .as_node()?
             .descendants()
             .filter(|node| ctx.selection_trimmed().contains_range(node.text_range()))
             .filter_map(ast::MacroCall::cast)
-            .collect()
+            .collect();
+        (macro_calls, true)
     };
 
-    let replacements =
-        macro_calls.into_iter().filter_map(compute_dbg_replacement).collect::<Vec<_>>();
+    let mut removes_prints = false;
+    let replacements = macro_calls
+        .into_iter()
+        .filter(|it| it.excl_token().is_some() && is_from_std(ctx, it))
+        .filter_map(|it| {
+            let name = it.path()?.segment()?.name_ref()?;
+            match name.text().as_str() {
+                "dbg" => compute_dbg_replacement(it),
+                "print" | "println" | "eprint" | "eprintln" if remove_prints => {
+                    let range = statement_range(&ast::MacroExpr::cast(it.syntax().parent()?)?)?;
+                    removes_prints = true;
+                    Some((range, String::new()))
+                }
+                _ => None,
+            }
+        })
+        .collect::<Vec<_>>();
     if replacements.is_empty() {
         return None;
     }
 
     acc.add(
         AssistId("remove_dbg", AssistKind::Refactor),
-        "Remove dbg!()",
+        if removes_prints { "Remove debug statements" } else { "Remove dbg!()" },
         ctx.selection_trimmed(),
         |builder| {
             for (range, text) in replacements {
@@ -51,14 +70,28 @@ pub(crate) fn remove_dbg(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<(
     )
 }
 
+/// Checks that `macro_call` doesn't call a macro defined outside of the standard library, like a
+/// `dbg!` of the user's own. Unresolved calls are assumed to be standard library macros.
+fn is_from_std(ctx: &AssistContext<'_>, macro_call: &ast::MacroCall) -> bool {
+    let mac = match ctx.sema.resolve_macro_call(macro_call) {
+        Some(it) => it,
+        None => return true,
+    };
+    let krate = match ctx.sema.scope(macro_call.syntax()) {
+        Some(it) => it.krate(),
+        None => return false,
+    };
+    let is_from_std =
+        FamousDefs(&ctx.sema, krate).builtin_crates().any(|it| it == mac.module(ctx.db()).krate());
+    if !is_from_std {
+        cov_mark::hit!(remove_dbg_user_defined_macro);
+    }
+    is_from_std
+}
+
 fn compute_dbg_replacement(macro_call: ast::MacroCall) -> Option<(TextRange, String)> {
     let tt = macro_call.token_tree()?;
     let r_delim = NodeOrToken::Token(tt.right_delimiter_token()?);
-    if macro_call.path()?.segment()?.name_ref()?.text() != "dbg"
-        || macro_call.excl_token().is_none()
-    {
-        return None;
-    }
 
     let mac_input = tt.syntax().children_with_tokens().skip(1).take_while(|it| *it != r_delim);
     let input_expressions = mac_input.group_by(|tok| tok.kind() == T![,]);
@@ -72,29 +105,10 @@ fn compute_dbg_replacement(macro_call: ast::MacroCall) -> Option<(TextRange, Str
     let parent = macro_expr.syntax().parent()?;
     Some(match &*input_expressions {
         // dbg!()
-        [] => {
-            match_ast! {
-                match parent {
-                    ast::StmtList(__) => {
-                        let range = macro_expr.syntax().text_range();
-                        let range = match whitespace_start(macro_expr.syntax().prev_sibling_or_token()) {
-                            Some(start) => range.cover_offset(start),
-                            None => range,
-                        };
-                        (range, String::new())
-                    },
-                    ast::ExprStmt(it) => {
-                        let range = it.syntax().text_range();
-                        let range = match whitespace_start(it.syntax().prev_sibling_or_token()) {
-                            Some(start) => range.cover_offset(start),
-                            None => range,
-                        };
-                        (range, String::new())
-                    },
-                    _ => (macro_call.syntax().text_range(), "()".to_owned())
-                }
-            }
-        }
+        [] => match statement_range(&macro_expr) {
+            Some(range) => (range, String::new()),
+            None => (macro_call.syntax().text_range(), "()".to_owned()),
+        },
         // dbg!(expr0)
         [expr] => {
             let wrap = match ast::Expr::cast(parent) {
@@ -139,6 +153,24 @@ fn compute_dbg_replacement(macro_call: ast::MacroCall) -> Option<(TextRange, Str
     })
 }
 
+/// Returns the range to delete to remove `macro_expr` along with its preceding whitespace, if
+/// it's a statement of its own.
+fn statement_range(macro_expr: &ast::MacroExpr) -> Option<TextRange> {
+    let parent = macro_expr.syntax().parent()?;
+    let stmt = match_ast! {
+        match parent {
+            ast::StmtList(__) => macro_expr.syntax().clone(),
+            ast::ExprStmt(it) => it.syntax().clone(),
+            _ => return None,
+        }
+    };
+    let range = stmt.text_range();
+    Some(match whitespace_start(stmt.prev_sibling_or_token()) {
+        Some(start) => range.cover_offset(start),
+        None => range,
+    })
+}
+
 fn whitespace_start(it: Option<SyntaxElement>) -> Option<TextSize> {
     Some(it?.into_token().and_then(ast::Whitespace::cast)?.syntax().text_range().start())
 }
@@ -287,4 +319,46 @@ fn test_range_partial() {
         check_assist_not_applicable(remove_dbg, r#"$0dbg$0!(0)"#);
         check_assist_not_applicable(remove_dbg, r#"$0dbg!(0$0)"#);
     }
+
+    #[test]
+    fn test_range_removes_prints() {
+        check(
+            r#"
+fn f() {
+    $0println!("x = {}", x);
+    let y = dbg!(x) + 1;
+    eprint!("y");$0
+    println!("kept");
+}
+"#,
+            r#"
+fn f() {
+    let y = x + 1;
+    println!("kept");
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_prints_need_selection() {
+        check_assist_not_applicable(remove_dbg, r#"fn f() { $0println!("x"); }"#);
+        check_assist_not_applicable(remove_dbg, r#"fn f() { let x = $0println!("x"); }"#);
+    }
+
+    #[test]
+    fn test_user_defined_dbg_not_applicable() {
+        cov_mark::check!(remove_dbg_user_defined_macro);
+        check_assist_not_applicable(
+            remove_dbg,
+            r#"
+macro_rules! dbg {
+    ($e:expr) => { $e };
+}
+fn f() {
+    $0dbg!(1);
+}
+"#,
+        );
+    }
 }
@@ -0,0 +1,241 @@
+use hir::{CallableKind, Function};
+use ide_db::RootDatabase;
+use syntax::{
+    ast::{self, AstNode},
+    match_ast, TextRange,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: remove_explicit_type
+//
+// Removes the type annotation of a let binding when it doesn't change what gets inferred.
+//
+// ```
+// fn main() {
+//     let x$0: bool = true;
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let x = true;
+// }
+// ```
+pub(crate) fn remove_explicit_type(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let let_stmt = ctx.find_node_at_offset::<ast::LetStmt>()?;
+    let eq_range = let_stmt.eq_token()?.text_range();
+    if ctx.offset() > eq_range.start() {
+        return None;
+    }
+    let pat = let_stmt.pat()?;
+    let ty = let_stmt.ty()?;
+    let initializer = let_stmt.initializer()?;
+
+    let ascribed_ty = ctx.sema.resolve_type(&ty)?;
+    let init_ty = ctx.sema.type_of_expr(&initializer)?;
+    if ascribed_ty.contains_unknown() || init_ty.original.contains_unknown() {
+        return None;
+    }
+    // The annotation may be what makes the initializer coerce, e.g. to a slice or a trait object.
+    if init_ty.has_adjustment() || !init_ty.original.could_unify_with(ctx.db(), &ascribed_ty) {
+        cov_mark::hit!(remove_explicit_type_not_applicable_if_coerced);
+        return None;
+    }
+    if is_inferred_from_annotation(ctx, &initializer) {
+        cov_mark::hit!(remove_explicit_type_not_applicable_if_needed_for_inference);
+        return None;
+    }
+
+    let target = ty.syntax().text_range();
+    acc.add(
+        AssistId("remove_explicit_type", AssistKind::RefactorRewrite),
+        "Remove explicit type",
+        target,
+        |builder| {
+            let pat_end = pat.syntax().text_range().end();
+            builder.delete(TextRange::new(pat_end, ty.syntax().text_range().end()));
+        },
+    )
+}
+
+/// Whether the type of `expr` might only be known thanks to the annotation: unsuffixed number
+/// literals would fall back to their default type, and calls to functions returning a generic
+/// type that none of their arguments pin down would become ambiguous.
+fn is_inferred_from_annotation(ctx: &AssistContext<'_>, expr: &ast::Expr) -> bool {
+    let db = ctx.db();
+    expr.syntax().descendants().any(|node| {
+        match_ast! {
+            match node {
+                ast::Literal(it) => match it.kind() {
+                    ast::LiteralKind::IntNumber(num) => num.suffix().is_none(),
+                    ast::LiteralKind::FloatNumber(num) => num.suffix().is_none(),
+                    _ => false,
+                },
+                ast::CallExpr(it) => {
+                    let callee = match it.expr() {
+                        Some(it) => it,
+                        None => return false,
+                    };
+                    let has_turbofish = callee
+                        .syntax()
+                        .descendants()
+                        .any(|it| ast::GenericArgList::can_cast(it.kind()));
+                    let func = ctx
+                        .sema
+                        .type_of_expr(&callee)
+                        .and_then(|ty| ty.original.as_callable(db))
+                        .and_then(|callable| match callable.kind() {
+                            CallableKind::Function(func) => Some(func),
+                            _ => None,
+                        });
+                    !has_turbofish && func.map_or(false, |func| has_unconstrained_return(db, func))
+                },
+                ast::MethodCallExpr(it) => {
+                    it.generic_arg_list().is_none()
+                        && ctx
+                            .sema
+                            .resolve_method_call(&it)
+                            .map_or(false, |func| has_unconstrained_return(db, func))
+                },
+                _ => false,
+            }
+        }
+    })
+}
+
+/// Whether the return type of `func` mentions a generic parameter that doesn't appear in any of
+/// its parameters, like `T` in `Vec::<T>::new` or in `Default::default`.
+fn has_unconstrained_return(db: &RootDatabase, func: Function) -> bool {
+    let constrained =
+        func.assoc_fn_params(db).iter().flat_map(|it| it.ty().type_params(db)).collect::<Vec<_>>();
+    func.ret_type(db).type_params(db).iter().any(|it| !constrained.contains(it))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::{check_assist, check_assist_not_applicable, check_assist_target};
+
+    #[test]
+    fn remove_explicit_type_target() {
+        check_assist_target(remove_explicit_type, r#"fn f() { let a$0: bool = true; }"#, "bool");
+    }
+
+    #[test]
+    fn remove_explicit_type_simple() {
+        check_assist(
+            remove_explicit_type,
+            r#"fn f() { let a$0: bool = true; }"#,
+            r#"fn f() { let a = true; }"#,
+        );
+    }
+
+    #[test]
+    fn remove_explicit_type_suffixed_literal() {
+        check_assist(
+            remove_explicit_type,
+            r#"fn f() { let a$0: u8 = 1u8; }"#,
+            r#"fn f() { let a = 1u8; }"#,
+        );
+    }
+
+    #[test]
+    fn remove_explicit_type_generic_constrained_by_argument() {
+        check_assist(
+            remove_explicit_type,
+            r#"
+struct Wrapper<T>(T);
+fn wrap<T>(t: T) -> Wrapper<T> { Wrapper(t) }
+fn f() {
+    let w$0: Wrapper<bool> = wrap(true);
+}
+"#,
+            r#"
+struct Wrapper<T>(T);
+fn wrap<T>(t: T) -> Wrapper<T> { Wrapper(t) }
+fn f() {
+    let w = wrap(true);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn remove_explicit_type_with_turbofish() {
+        check_assist(
+            remove_explicit_type,
+            r#"
+struct Vec<T> { t: T }
+impl<T> Vec<T> {
+    fn new() -> Self {
+        loop {}
+    }
+}
+fn f() {
+    let v$0: Vec<bool> = Vec::<bool>::new();
+}
+"#,
+            r#"
+struct Vec<T> { t: T }
+impl<T> Vec<T> {
+    fn new() -> Self {
+        loop {}
+    }
+}
+fn f() {
+    let v = Vec::<bool>::new();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn remove_explicit_type_not_applicable_without_type() {
+        check_assist_not_applicable(remove_explicit_type, r#"fn f() { let a$0 = true; }"#);
+    }
+
+    #[test]
+    fn remove_explicit_type_not_applicable_cursor_after_equals() {
+        check_assist_not_applicable(remove_explicit_type, r#"fn f() { let a: bool = $0true; }"#);
+    }
+
+    #[test]
+    fn remove_explicit_type_not_applicable_for_literal_defaults() {
+        cov_mark::check!(remove_explicit_type_not_applicable_if_needed_for_inference);
+        check_assist_not_applicable(remove_explicit_type, r#"fn f() { let a$0: u8 = 1; }"#);
+    }
+
+    #[test]
+    fn remove_explicit_type_not_applicable_for_unconstrained_generic() {
+        cov_mark::check!(remove_explicit_type_not_applicable_if_needed_for_inference);
+        check_assist_not_applicable(
+            remove_explicit_type,
+            r#"
+struct Vec<T> { t: T }
+impl<T> Vec<T> {
+    fn new() -> Self {
+        loop {}
+    }
+}
+fn f() {
+    let v$0: Vec<bool> = Vec::new();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn remove_explicit_type_not_applicable_if_coerced() {
+        cov_mark::check!(remove_explicit_type_not_applicable_if_coerced);
+        check_assist_not_applicable(
+            remove_explicit_type,
+            r#"
+fn f() {
+    let a$0: &[bool] = &[true, false];
+}
+"#,
+        );
+    }
+}
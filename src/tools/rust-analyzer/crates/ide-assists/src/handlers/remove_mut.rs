@@ -1,10 +1,15 @@
-use syntax::{SyntaxKind, TextRange, T};
+use ide_db::defs::Definition;
+use syntax::{
+    ast::{self, AstNode},
+    match_ast, SyntaxKind, TextRange, T,
+};
 
 use crate::{AssistContext, AssistId, AssistKind, Assists};
 
 // Assist: remove_mut
 //
-// Removes the `mut` keyword.
+// Removes the `mut` keyword. For bindings and `self` parameters, this is only offered when nothing
+// mutates them.
 //
 // ```
 // impl Walrus {
@@ -19,6 +24,25 @@
 // ```
 pub(crate) fn remove_mut(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
     let mut_token = ctx.find_token_syntax_at_offset(T![mut])?;
+    let local = match_ast! {
+        match (mut_token.parent()?) {
+            ast::IdentPat(it) => Some(ctx.sema.to_def(&it)?),
+            ast::SelfParam(it) => {
+                if it.amp_token().is_some() && is_in_trait_or_trait_impl(&it) {
+                    cov_mark::hit!(remove_mut_trait_receiver);
+                    return None;
+                }
+                Some(ctx.sema.to_def(&it)?)
+            },
+            _ => None,
+        }
+    };
+    if let Some(local) = local {
+        if is_mutated(ctx, local) {
+            cov_mark::hit!(remove_mut_binding_is_mutated);
+            return None;
+        }
+    }
     let delete_from = mut_token.text_range().start();
     let delete_to = match mut_token.next_token() {
         Some(it) if it.kind() == SyntaxKind::WHITESPACE => it.text_range().end(),
@@ -35,3 +59,243 @@ pub(crate) fn remove_mut(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<(
         },
     )
 }
+
+/// Whether the receiver belongs to a method of a trait or trait impl, whose signature the trait
+/// dictates.
+pub(super) fn is_in_trait_or_trait_impl(self_param: &ast::SelfParam) -> bool {
+    let container = self_param
+        .syntax()
+        .ancestors()
+        .find_map(ast::Fn::cast)
+        .and_then(|it| it.syntax().parent())
+        .and_then(ast::AssocItemList::cast)
+        .and_then(|it| it.syntax().parent());
+    match container {
+        Some(it) => match_ast! {
+            match it {
+                ast::Trait(_) => true,
+                ast::Impl(it) => it.trait_().is_some(),
+                _ => false,
+            }
+        },
+        None => false,
+    }
+}
+
+/// Checks whether any use of `local` needs it to be `mut`, or for `&mut self`, to be a mutable
+/// reference. This errs on the side of reporting uses as mutations.
+fn is_mutated(ctx: &AssistContext<'_>, local: hir::Local) -> bool {
+    // Passing `&mut self` on, rather than using a place behind it, needs a mutable reference.
+    let is_mut_receiver = local.is_self(ctx.db()) && local.ty(ctx.db()).is_mutable_reference();
+    let usages = Definition::Local(local).usages(&ctx.sema).all();
+    let is_mutated = usages.iter().flat_map(|(_, refs)| refs).any(|usage| {
+        let path_expr = usage
+            .name
+            .as_name_ref()
+            .and_then(|it| it.syntax().ancestors().find_map(ast::PathExpr::cast));
+        match path_expr {
+            Some(it) => is_mutable_use(ctx, ast::Expr::PathExpr(it), is_mut_receiver),
+            None => true,
+        }
+    });
+    is_mutated
+}
+
+fn is_mutable_use(ctx: &AssistContext<'_>, mut expr: ast::Expr, is_mut_receiver: bool) -> bool {
+    // Any other use of a mutable reference might need it to stay one.
+    let is_mut_ref = |expr: &ast::Expr| {
+        is_mut_receiver
+            && ctx.sema.type_of_expr(expr).map_or(true, |it| it.original.is_mutable_reference())
+    };
+    // Walks up the place `expr` is the base of, like `x.field[0]` for `x`.
+    loop {
+        let parent = match expr.syntax().parent() {
+            Some(it) => it,
+            None => return is_mut_ref(&expr),
+        };
+        expr = match_ast! {
+            match parent {
+                ast::RefExpr(it) => return it.mut_token().is_some(),
+                ast::BinExpr(it) => {
+                    return matches!(it.op_kind(), Some(ast::BinaryOp::Assignment { .. }))
+                        && it.lhs().as_ref() == Some(&expr);
+                },
+                // Calling a closure might need it to be mutable.
+                ast::CallExpr(it) => return it.expr().as_ref() == Some(&expr),
+                // Methods taking `&mut self` borrow the receiver mutably.
+                ast::MethodCallExpr(it) => {
+                    return it.receiver().as_ref() == Some(&expr)
+                        && ctx
+                            .sema
+                            .type_of_expr(&expr)
+                            .map_or(true, |it| it.adjusted().is_mutable_reference());
+                },
+                ast::FieldExpr(it) => it.into(),
+                ast::IndexExpr(it) => {
+                    if it.base().as_ref() != Some(&expr) {
+                        return false;
+                    }
+                    it.into()
+                },
+                ast::ParenExpr(it) => it.into(),
+                ast::PrefixExpr(it) => {
+                    if it.op_kind() != Some(ast::UnaryOp::Deref) {
+                        return false;
+                    }
+                    it.into()
+                },
+                _ => return is_mut_ref(&expr),
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn remove_from_unmutated_binding() {
+        check_assist(
+            remove_mut,
+            r#"
+fn main() {
+    let $0mut x = 1;
+    let y = &x;
+    x + 1;
+}
+"#,
+            r#"
+fn main() {
+    let x = 1;
+    let y = &x;
+    x + 1;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn remove_from_self_param() {
+        check_assist(
+            remove_mut,
+            r#"
+struct S { x: u32 }
+impl S {
+    fn get(&mut$0 self) -> u32 { self.x }
+}
+"#,
+            r#"
+struct S { x: u32 }
+impl S {
+    fn get(&self) -> u32 { self.x }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_mutated_binding() {
+        cov_mark::check!(remove_mut_binding_is_mutated);
+        check_assist_not_applicable(
+            remove_mut,
+            r#"
+struct S { x: [u32; 2] }
+fn foo(mut$0 s: S) {
+    s.x[0] += 1;
+}
+"#,
+        );
+        check_assist_not_applicable(
+            remove_mut,
+            r#"
+fn main() {
+    let mut$0 x = 1;
+    let y = &mut (x);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_self_with_mutating_method_call() {
+        check_assist_not_applicable(
+            remove_mut,
+            r#"
+struct S;
+impl S {
+    fn set(&mut self) {}
+    fn foo(&$0mut self) {
+        self.set();
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_self_passed_on() {
+        cov_mark::check_count!(remove_mut_binding_is_mutated, 3);
+        check_assist_not_applicable(
+            remove_mut,
+            r#"
+struct S;
+fn helper(s: &mut S) {}
+impl S {
+    fn foo(&$0mut self) {
+        helper(self);
+    }
+}
+"#,
+        );
+        check_assist_not_applicable(
+            remove_mut,
+            r#"
+struct S;
+impl S {
+    fn foo(&$0mut self) {
+        let r = self;
+    }
+}
+"#,
+        );
+        check_assist_not_applicable(
+            remove_mut,
+            r#"
+struct S;
+impl S {
+    fn foo(&$0mut self) -> &mut S {
+        return self;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_trait_receivers() {
+        cov_mark::check_count!(remove_mut_trait_receiver, 2);
+        check_assist_not_applicable(
+            remove_mut,
+            r#"
+trait T {
+    fn foo(&$0mut self);
+}
+"#,
+        );
+        check_assist_not_applicable(
+            remove_mut,
+            r#"
+trait T {
+    fn foo(&mut self);
+}
+struct S;
+impl T for S {
+    fn foo(&$0mut self) {}
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use hir::AsAssocItem;
+use ide_db::famous_defs::FamousDefs;
+use syntax::ast::{self, AstNode, HasArgList};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: remove_unnecessary_clone
+//
+// Borrows a value instead of cloning it, when only a reference to the clone is passed on.
+//
+// ```
+// # //- minicore: clone
+// struct S;
+// impl Clone for S { fn clone(&self) -> Self { S } }
+// fn print(s: &S) {}
+// fn main() {
+//     let s = S;
+//     print(&s.clo$0ne());
+// }
+// ```
+// ->
+// ```
+// struct S;
+// impl Clone for S { fn clone(&self) -> Self { S } }
+// fn print(s: &S) {}
+// fn main() {
+//     let s = S;
+//     print(&s);
+// }
+// ```
+pub(crate) fn remove_unnecessary_clone(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let call = ctx.find_node_at_offset::<ast::MethodCallExpr>()?;
+    if call.name_ref()?.text() != "clone" || call.arg_list()?.args().next().is_some() {
+        return None;
+    }
+    // Only `&x.clone()` passed as an argument is known to be just read, every other use might
+    // move or mutate the clone.
+    let ref_expr = call.syntax().parent().and_then(ast::RefExpr::cast)?;
+    if ref_expr.mut_token().is_some() || ref_expr.raw_token().is_some() {
+        return None;
+    }
+    if !ref_expr.syntax().parent().map_or(false, |it| ast::ArgList::can_cast(it.kind())) {
+        return None;
+    }
+    if !is_clone_call(ctx, &call) {
+        cov_mark::hit!(remove_unnecessary_clone_other_method);
+        return None;
+    }
+
+    let receiver = call.receiver()?;
+    // `&x.clone()` where `x: &T` already is the reference to pass.
+    let receiver_is_ref = ctx.sema.type_of_expr(&receiver)?.original.is_reference();
+    let target = ref_expr.syntax().text_range();
+    acc.add(
+        AssistId("remove_unnecessary_clone", AssistKind::RefactorRewrite),
+        "Remove unnecessary `clone()`",
+        target,
+        |builder| {
+            let replacement = match receiver_is_ref {
+                true => receiver.to_string(),
+                false => format!("&{receiver}"),
+            };
+            builder.replace(target, replacement)
+        },
+    )
+}
+
+/// Checks that `call` resolves to `Clone::clone`, or to its implementation for some type.
+fn is_clone_call(ctx: &AssistContext<'_>, call: &ast::MethodCallExpr) -> bool {
+    (|| {
+        let func = ctx.sema.resolve_method_call(call)?;
+        let krate = ctx.sema.scope(call.syntax())?.krate();
+        let clone_trait = FamousDefs(&ctx.sema, krate).core_clone_Clone()?;
+        let trait_ = match func.as_assoc_item(ctx.db())?.container(ctx.db()) {
+            hir::AssocItemContainer::Trait(it) => Some(it),
+            hir::AssocItemContainer::Impl(it) => it.trait_(ctx.db()),
+        };
+        Some(trait_ == Some(clone_trait))
+    })()
+    .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn remove_clone_of_reference() {
+        check_assist(
+            remove_unnecessary_clone,
+            r#"
+//- minicore: clone
+struct S;
+impl Clone for S { fn clone(&self) -> Self { S } }
+struct V;
+impl V { fn push(&mut self, s: &S) {} }
+fn foo(v: &mut V, s: &S) {
+    v.push(&s.$0clone());
+}
+"#,
+            r#"
+struct S;
+impl Clone for S { fn clone(&self) -> Self { S } }
+struct V;
+impl V { fn push(&mut self, s: &S) {} }
+fn foo(v: &mut V, s: &S) {
+    v.push(s);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_clone_is_moved() {
+        check_assist_not_applicable(
+            remove_unnecessary_clone,
+            r#"
+//- minicore: clone
+struct S;
+impl Clone for S { fn clone(&self) -> Self { S } }
+fn take(s: S) {}
+fn main() {
+    let s = S;
+    take(s.clo$0ne());
+    let r = &s.clone();
+}
+"#,
+        );
+        check_assist_not_applicable(
+            remove_unnecessary_clone,
+            r#"
+//- minicore: clone
+struct S;
+impl Clone for S { fn clone(&self) -> Self { S } }
+fn change(s: &mut S) {}
+fn main() {
+    let s = S;
+    change(&mut s.clo$0ne());
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_clone_methods() {
+        cov_mark::check!(remove_unnecessary_clone_other_method);
+        check_assist_not_applicable(
+            remove_unnecessary_clone,
+            r#"
+//- minicore: clone
+struct S;
+impl S { fn clone(&self) -> Self { S } }
+fn print(s: &S) {}
+fn main() {
+    let s = S;
+    print(&s.clo$0ne());
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,305 @@
+use either::Either;
+use ide_db::{
+    defs::{Definition, NameRefClass},
+    search::SearchScope,
+};
+use syntax::{
+    ast::{self, edit_in_place::Removable, HasVisibility},
+    AstNode,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: remove_unused_import
+//
+// Removes an import whose name is not used anywhere in its module.
+//
+// ```
+// mod foo {
+//     pub struct Bar;
+//     pub struct Baz;
+// }
+//
+// use foo::{Bar, Baz$0};
+//
+// fn main() {
+//     let _ = Bar;
+// }
+// ```
+// ->
+// ```
+// mod foo {
+//     pub struct Bar;
+//     pub struct Baz;
+// }
+//
+// use foo::{Bar};
+//
+// fn main() {
+//     let _ = Bar;
+// }
+// ```
+pub(crate) fn remove_unused_import(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let tree = ctx.find_node_at_offset::<ast::UseTree>()?;
+    if tree.use_tree_list().is_some() {
+        return None;
+    }
+    if tree.star_token().is_some() {
+        cov_mark::hit!(remove_unused_import_glob);
+        return None;
+    }
+    // Usages are searched by the name of the imported item, so renamed imports would always
+    // look unused.
+    if tree.rename().is_some() {
+        return None;
+    }
+    let use_ = tree.syntax().ancestors().find_map(ast::Use::cast)?;
+    if use_.visibility().is_some() {
+        cov_mark::hit!(remove_unused_import_reexport);
+        return None;
+    }
+
+    let name_ref = tree.path()?.segment()?.name_ref()?;
+    let def = match NameRefClass::classify(&ctx.sema, &name_ref)? {
+        NameRefClass::Definition(def) => def,
+        NameRefClass::FieldShorthand { .. } => return None,
+    };
+    // Trait imports are used by method resolution without their name ever being mentioned.
+    if let Definition::Trait(_) = def {
+        cov_mark::hit!(remove_unused_import_trait);
+        return None;
+    }
+
+    let module = ctx.sema.scope(use_.syntax())?.module();
+    let scope = SearchScope::module_and_children(ctx.db(), module);
+    let tree_range = tree.syntax().text_range();
+    let is_used = def.usages(&ctx.sema).in_scope(scope).all().iter().any(|(&file_id, refs)| {
+        file_id != ctx.file_id() || refs.iter().any(|it| !tree_range.contains_range(it.range))
+    });
+    if is_used {
+        cov_mark::hit!(remove_unused_import_used);
+        return None;
+    }
+
+    acc.add(
+        AssistId("remove_unused_import", AssistKind::Refactor),
+        "Remove unused import",
+        tree_range,
+        |builder| match removal_target(use_, tree) {
+            Either::Left(use_) => builder.make_mut(use_).remove(),
+            Either::Right(tree) => builder.make_mut(tree).remove(),
+        },
+    )
+}
+
+/// Finds the outermost node that only contains `tree`, so that no empty braces are left behind.
+pub(super) fn removal_target(
+    use_: ast::Use,
+    mut tree: ast::UseTree,
+) -> Either<ast::Use, ast::UseTree> {
+    loop {
+        match tree.syntax().parent().and_then(ast::UseTreeList::cast) {
+            Some(list) if list.use_trees().nth(1).is_some() => return Either::Right(tree),
+            Some(list) => tree = list.parent_use_tree(),
+            None => return Either::Left(use_),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn remove_whole_use() {
+        check_assist(
+            remove_unused_import,
+            r#"
+mod foo {
+    pub struct Bar;
+}
+use foo::Bar$0;
+fn main() {}
+"#,
+            r#"
+mod foo {
+    pub struct Bar;
+}
+fn main() {}
+"#,
+        );
+    }
+
+    #[test]
+    fn remove_name_from_list() {
+        check_assist(
+            remove_unused_import,
+            r#"
+mod foo {
+    pub struct Bar;
+    pub struct Baz;
+    pub struct Qux;
+}
+use foo::{Bar, Baz$0, Qux};
+fn main() {
+    let _ = (Bar, Qux);
+}
+"#,
+            r#"
+mod foo {
+    pub struct Bar;
+    pub struct Baz;
+    pub struct Qux;
+}
+use foo::{Bar, Qux};
+fn main() {
+    let _ = (Bar, Qux);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn remove_last_name_from_list() {
+        check_assist(
+            remove_unused_import,
+            r#"
+mod foo {
+    pub struct Bar;
+    pub struct Baz;
+}
+use foo::{Bar, Baz$0};
+fn main() {
+    let _ = Bar;
+}
+"#,
+            r#"
+mod foo {
+    pub struct Bar;
+    pub struct Baz;
+}
+use foo::{Bar};
+fn main() {
+    let _ = Bar;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn remove_nested_list_left_empty() {
+        check_assist(
+            remove_unused_import,
+            r#"
+mod foo {
+    pub struct Bar;
+    pub mod inner {
+        pub struct Baz;
+    }
+}
+use foo::{Bar, inner::{Baz$0}};
+fn main() {
+    let _ = Bar;
+}
+"#,
+            r#"
+mod foo {
+    pub struct Bar;
+    pub mod inner {
+        pub struct Baz;
+    }
+}
+use foo::{Bar};
+fn main() {
+    let _ = Bar;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_used() {
+        cov_mark::check!(remove_unused_import_used);
+        check_assist_not_applicable(
+            remove_unused_import,
+            r#"
+mod foo {
+    pub struct Bar;
+}
+use foo::Bar$0;
+fn main() {
+    let _ = Bar;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_used_in_child_module() {
+        cov_mark::check!(remove_unused_import_used);
+        check_assist_not_applicable(
+            remove_unused_import,
+            r#"
+mod foo {
+    pub struct Bar;
+}
+use foo::Bar$0;
+mod child {
+    fn f() {
+        let _ = super::Bar;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_for_glob() {
+        cov_mark::check!(remove_unused_import_glob);
+        check_assist_not_applicable(
+            remove_unused_import,
+            r#"
+mod foo {
+    pub struct Bar;
+}
+use foo::*$0;
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_for_reexport() {
+        cov_mark::check!(remove_unused_import_reexport);
+        check_assist_not_applicable(
+            remove_unused_import,
+            r#"
+mod foo {
+    pub struct Bar;
+}
+pub use foo::Bar$0;
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_for_trait() {
+        cov_mark::check!(remove_unused_import_trait);
+        check_assist_not_applicable(
+            remove_unused_import,
+            r#"
+mod foo {
+    pub trait Tr {
+        fn f(&self) {}
+    }
+    impl Tr for () {}
+}
+use foo::Tr$0;
+fn main() {
+    ().f();
+}
+"#,
+        );
+    }
+}
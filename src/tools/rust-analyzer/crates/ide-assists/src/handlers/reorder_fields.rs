@@ -25,8 +25,16 @@ pub(crate) fn reorder_fields(acc: &mut Assists, ctx: &AssistContext<'_>) -> Opti
         .map(Either::Left)
         .or_else(|| ctx.find_node_at_offset::<ast::RecordPat>().map(Either::Right))?;
 
-    let path = record.as_ref().either(|it| it.path(), |it| it.path())?;
-    let ranks = compute_fields_ranks(&path, ctx)?;
+    let variant = match &record {
+        Either::Left(it) => ctx.sema.resolve_variant(it.clone())?,
+        // Patterns whose fields are all elided have nothing to reorder.
+        Either::Right(it) => it
+            .record_pat_field_list()?
+            .fields()
+            .find_map(|field| ctx.sema.resolve_record_pat_field(&field))?
+            .parent_def(ctx.db()),
+    };
+    let ranks = compute_fields_ranks(variant, ctx);
     let get_rank_of_field =
         |of: Option<_>| *ranks.get(&of.unwrap_or_default()).unwrap_or(&usize::MAX);
 
@@ -87,22 +95,15 @@ fn replace<T: AstNode + PartialEq>(
 }
 
 fn compute_fields_ranks(
-    path: &ast::Path,
+    variant: hir::VariantDef,
     ctx: &AssistContext<'_>,
-) -> Option<FxHashMap<String, usize>> {
-    let strukt = match ctx.sema.resolve_path(path) {
-        Some(hir::PathResolution::Def(hir::ModuleDef::Adt(hir::Adt::Struct(it)))) => it,
-        _ => return None,
-    };
-
-    let res = strukt
+) -> FxHashMap<String, usize> {
+    variant
         .fields(ctx.db())
         .into_iter()
         .enumerate()
         .map(|(idx, field)| (field.name(ctx.db()).to_string(), idx))
-        .collect();
-
-    Some(res)
+        .collect()
 }
 
 #[cfg(test)]
@@ -206,6 +207,84 @@ fn new() -> Foo {
         }
     }
 }
+"#,
+        )
+    }
+
+    #[test]
+    fn reorder_keeps_functional_update_and_shorthand() {
+        check_assist(
+            reorder_fields,
+            r#"
+struct Foo { foo: i32, bar: i32, baz: i32 }
+fn f(rest: Foo) -> Foo {
+    let bar = 1;
+    $0Foo { baz: 2, bar, ..rest }
+}
+"#,
+            r#"
+struct Foo { foo: i32, bar: i32, baz: i32 }
+fn f(rest: Foo) -> Foo {
+    let bar = 1;
+    Foo { bar, baz: 2, ..rest }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn reorder_enum_variant_fields() {
+        check_assist(
+            reorder_fields,
+            r#"
+enum E { V { foo: i32, bar: i32 } }
+const test: E = $0E::V { bar: 0, foo: 1 };
+"#,
+            r#"
+enum E { V { foo: i32, bar: i32 } }
+const test: E = E::V { foo: 1, bar: 0 };
+"#,
+        )
+    }
+
+    #[test]
+    fn reorder_self_literal() {
+        check_assist(
+            reorder_fields,
+            r#"
+struct Foo { foo: i32, bar: i32 }
+impl Foo {
+    fn new() -> Self {
+        $0Self { bar: 0, foo: 1 }
+    }
+}
+"#,
+            r#"
+struct Foo { foo: i32, bar: i32 }
+impl Foo {
+    fn new() -> Self {
+        Self { foo: 1, bar: 0 }
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn reorder_enum_variant_pattern() {
+        check_assist(
+            reorder_fields,
+            r#"
+enum E { V { foo: i32, bar: i32 } }
+fn f(e: E) {
+    let $0E::V { bar, foo } = e;
+}
+"#,
+            r#"
+enum E { V { foo: i32, bar: i32 } }
+fn f(e: E) {
+    let E::V { foo, bar } = e;
+}
 "#,
         )
     }
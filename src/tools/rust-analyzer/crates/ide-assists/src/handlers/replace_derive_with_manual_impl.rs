@@ -1,8 +1,5 @@
 use hir::{InFile, ModuleDef};
-use ide_db::{
-    helpers::mod_path_to_ast, imports::import_assets::NameToImport, items_locator,
-    syntax_helpers::insert_whitespace_into_node::insert_ws_into,
-};
+use ide_db::{helpers::mod_path_to_ast, imports::import_assets::NameToImport, items_locator};
 use itertools::Itertools;
 use syntax::{
     ast::{self, AstNode, HasName},
@@ -11,10 +8,7 @@
 
 use crate::{
     assist_context::{AssistContext, Assists, SourceChangeBuilder},
-    utils::{
-        add_trait_assoc_items_to_impl, filter_assoc_items, gen_trait_fn_body,
-        generate_trait_impl_text, render_snippet, Cursor, DefaultMethods,
-    },
+    utils::{generate_trait_impl_text, impl_def_from_trait, render_snippet, Cursor},
     AssistId, AssistKind,
 };
 
@@ -133,7 +127,7 @@ fn add_assist(
         |builder| {
             let insert_pos = adt.syntax().text_range().end();
             let impl_def_with_items =
-                impl_def_from_trait(&ctx.sema, adt, &annotated_name, trait_, replace_trait_path);
+                impl_def_from_trait(ctx, adt, &annotated_name, trait_, replace_trait_path);
             update_attribute(builder, old_derives, old_tree, old_trait_path, attr);
             let trait_path = replace_trait_path.to_string();
             match (ctx.config.snippet_cap, impl_def_with_items) {
@@ -166,60 +160,6 @@ fn add_assist(
     )
 }
 
-fn impl_def_from_trait(
-    sema: &hir::Semantics<'_, ide_db::RootDatabase>,
-    adt: &ast::Adt,
-    annotated_name: &ast::Name,
-    trait_: Option<hir::Trait>,
-    trait_path: &ast::Path,
-) -> Option<(ast::Impl, ast::AssocItem)> {
-    let trait_ = trait_?;
-    let target_scope = sema.scope(annotated_name.syntax())?;
-    let trait_items = filter_assoc_items(sema, &trait_.items(sema.db), DefaultMethods::No);
-    if trait_items.is_empty() {
-        return None;
-    }
-    let impl_def = {
-        use syntax::ast::Impl;
-        let text = generate_trait_impl_text(adt, trait_path.to_string().as_str(), "");
-        let parse = syntax::SourceFile::parse(&text);
-        let node = match parse.tree().syntax().descendants().find_map(Impl::cast) {
-            Some(it) => it,
-            None => {
-                panic!(
-                    "Failed to make ast node `{}` from text {}",
-                    std::any::type_name::<Impl>(),
-                    text
-                )
-            }
-        };
-        let node = node.clone_subtree();
-        assert_eq!(node.syntax().text_range().start(), 0.into());
-        node
-    };
-
-    let trait_items = trait_items
-        .into_iter()
-        .map(|it| {
-            if sema.hir_file_for(it.syntax()).is_macro() {
-                if let Some(it) = ast::AssocItem::cast(insert_ws_into(it.syntax().clone())) {
-                    return it;
-                }
-            }
-            it.clone_for_update()
-        })
-        .collect();
-    let (impl_def, first_assoc_item) =
-        add_trait_assoc_items_to_impl(sema, trait_items, trait_, impl_def, target_scope);
-
-    // Generate a default `impl` function body for the derived trait.
-    if let ast::AssocItem::Fn(ref func) = first_assoc_item {
-        let _ = gen_trait_fn_body(func, trait_path, adt);
-    };
-
-    Some((impl_def, first_assoc_item))
-}
-
 fn update_attribute(
     builder: &mut SourceChangeBuilder,
     old_derives: &[ast::Path],
@@ -1137,9 +1077,9 @@ struct Foo {
 }
 
 impl foo::Bar for Foo {
-    $0type Qux;
+    $0type Qux = ();
 
-    const Fez: usize;
+    const Fez: usize = todo!();
 
     fn foo() {
         todo!()
@@ -17,7 +17,10 @@
 };
 
 use crate::{
-    utils::{does_nested_pattern, does_pat_match_variant, unwrap_trivial_block},
+    utils::{
+        does_nested_pattern, does_pat_match_variant, invert_boolean_expression,
+        unwrap_trivial_block,
+    },
     AssistContext, AssistId, AssistKind, Assists,
 };
 
@@ -122,18 +125,19 @@ pub(crate) fn replace_if_let_with_match(acc: &mut Assists, ctx: &AssistContext<'
                     }
                 };
                 let arms = cond_bodies.into_iter().map(make_match_arm).chain(iter::once(else_arm));
-                let match_expr = make::expr_match(scrutinee_to_be_expr, make::match_arm_list(arms));
-                match_expr.indent(IndentLevel::from_node(if_expr.syntax()))
+                make::expr_match(scrutinee_to_be_expr, make::match_arm_list(arms))
             };
 
             let has_preceding_if_expr =
                 if_expr.syntax().parent().map_or(false, |it| ast::IfExpr::can_cast(it.kind()));
             let expr = if has_preceding_if_expr {
                 // make sure we replace the `else if let ...` with a block so we don't end up with `else expr`
-                make::block_expr(None, Some(match_expr)).into()
+                // `make::block_expr` only indents the first line of its tail
+                make::block_expr(None, Some(match_expr.indent(IndentLevel(1)))).into()
             } else {
                 match_expr
             };
+            let expr = expr.indent(IndentLevel::from_node(if_expr.syntax()));
             edit.replace_ast::<ast::Expr>(if_expr.into(), expr);
         },
     )
@@ -165,6 +169,7 @@ fn make_else_arm(
             }
             None => make::wildcard_pat().into(),
         };
+        let else_block = else_block.reset_indent().indent(IndentLevel(1));
         make::match_arm(iter::once(pattern), None, unwrap_trivial_block(else_block))
     } else {
         make::match_arm(iter::once(make::wildcard_pat().into()), None, make::expr_unit())
@@ -214,11 +219,23 @@ pub(crate) fn replace_match_with_if_let(acc: &mut Assists, ctx: &AssistContext<'
         second_arm.expr()?,
     )?;
     let scrutinee = match_expr.expr()?;
+    // `match b { true => .., false => .. }` is a plain `if`.
+    let bool_pat = match &if_let_pat {
+        ast::Pat::LiteralPat(pat) => match pat.literal()?.kind() {
+            ast::LiteralKind::Bool(it) => Some(it),
+            _ => None,
+        },
+        _ => None,
+    };
+    let bool_pat = bool_pat
+        .filter(|_| ctx.sema.type_of_expr(&scrutinee).map_or(false, |it| it.original.is_bool()));
 
     let target = match_expr.syntax().text_range();
+    let label =
+        if bool_pat.is_some() { "Replace match with if" } else { "Replace match with if let" };
     acc.add(
         AssistId("replace_match_with_if_let", AssistKind::RefactorRewrite),
-        "Replace match with if let",
+        label,
         target,
         move |edit| {
             fn make_block_expr(expr: ast::Expr) -> ast::BlockExpr {
@@ -231,11 +248,19 @@ fn make_block_expr(expr: ast::Expr) -> ast::BlockExpr {
                 }
             }
 
-            let condition = make::expr_let(if_let_pat, scrutinee);
+            let (condition, then_expr, else_expr) = match bool_pat {
+                Some(true) => (scrutinee, then_expr, else_expr),
+                // Keep the `true` arm first, unless it's the empty one.
+                Some(false) if is_empty_expr(&else_expr) => {
+                    (invert_boolean_expression(scrutinee), then_expr, else_expr)
+                }
+                Some(false) => (scrutinee, else_expr, then_expr),
+                None => (make::expr_let(if_let_pat, scrutinee).into(), then_expr, else_expr),
+            };
             let then_block = make_block_expr(then_expr.reset_indent());
             let else_expr = if is_empty_expr(&else_expr) { None } else { Some(else_expr) };
             let if_let_expr = make::expr_if(
-                condition.into(),
+                condition,
                 then_block,
                 else_expr.map(make_block_expr).map(ast::ElseBranch::Block),
             )
@@ -428,11 +453,42 @@ pub fn is_struct(&self) -> bool {
             VariantData::Tuple(..) => false,
             _ if cond() => true,
             _ => {
-                    bar(
-                        123
-                    )
-                }
+                bar(
+                    123
+                )
+            }
+        }
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn test_if_let_with_match_else_if_let_ladder_without_else() {
+        check_assist(
+            replace_if_let_with_match,
+            r#"
+enum E { A(i32), B { x: i32 }, C }
+fn foo(e: E) {
+    $0if let E::A(a) = e {
+        bar(a);
+    } else if let E::B { x } = e {
+        bar(x);
+    }
+}
+"#,
+            r#"
+enum E { A(i32), B { x: i32 }, C }
+fn foo(e: E) {
+    match e {
+        E::A(a) => {
+            bar(a);
+        }
+        E::B { x } => {
+            bar(x);
         }
+        _ => (),
     }
 }
 "#,
@@ -462,11 +518,11 @@ pub fn is_struct(&self) -> bool {
         if let VariantData::Struct(..) = *self {
             true
         } else {
-    match *self {
-            VariantData::Tuple(..) => false,
-            _ => false,
+            match *self {
+                VariantData::Tuple(..) => false,
+                _ => false,
+            }
         }
-}
     }
 }
 "#,
@@ -996,4 +1052,101 @@ fn main() {
 "#,
         )
     }
+
+    #[test]
+    fn test_replace_match_with_if_let_not_applicable_for_more_than_two_arms() {
+        check_assist_not_applicable(
+            replace_match_with_if_let,
+            r#"
+//- minicore: option
+fn foo(x: Option<i32>) {
+    match $0x {
+        Some(0) => zero(),
+        Some(n) => bar(n),
+        _ => (),
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_replace_match_with_if_let_not_applicable_with_guard() {
+        check_assist_not_applicable(
+            replace_match_with_if_let,
+            r#"
+//- minicore: option
+fn foo(x: Option<i32>) {
+    match $0x {
+        Some(n) if n > 0 => bar(n),
+        _ => (),
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn replace_bool_match_with_if() {
+        check_assist(
+            replace_match_with_if_let,
+            r#"
+fn foo(b: bool) {
+    match $0b {
+        false => bar(),
+        true => baz(),
+    }
+}
+"#,
+            r#"
+fn foo(b: bool) {
+    if b {
+        baz()
+    } else {
+        bar()
+    }
+}
+"#,
+        );
+        check_assist(
+            replace_match_with_if_let,
+            r#"
+fn foo(b: bool) {
+    match $0b {
+        true => bar(),
+        _ => (),
+    }
+}
+"#,
+            r#"
+fn foo(b: bool) {
+    if b {
+        bar()
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn replace_bool_match_with_empty_true_arm() {
+        check_assist(
+            replace_match_with_if_let,
+            r#"
+fn foo(x: u32) {
+    match $0x == 0 {
+        true => {}
+        false => bar(),
+    }
+}
+"#,
+            r#"
+fn foo(x: u32) {
+    if x != 0 {
+        bar()
+    }
+}
+"#,
+        );
+    }
 }
@@ -0,0 +1,253 @@
+use ide_db::ty_filter::TryEnum;
+use syntax::{
+    ast::{self, edit::IndentLevel, make, HasArgList},
+    AstNode,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: replace_unwrap_with_match
+//
+// Replaces a call to `unwrap` on an `Option` or a `Result` with a `match` expression.
+//
+// ```
+// # //- minicore: option
+// fn handle(x: Option<i32>) {
+//     let v = x.unwr$0ap();
+// }
+// ```
+// ->
+// ```
+// fn handle(x: Option<i32>) {
+//     let v = match x {
+//         Some(it) => it,
+//         None => ${0:panic!()},
+//     };
+// }
+// ```
+pub(crate) fn replace_unwrap_with_match(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let name_ref = ctx.find_node_at_offset::<ast::NameRef>()?;
+    let call = name_ref.syntax().parent().and_then(ast::MethodCallExpr::cast)?;
+    if name_ref.text() != "unwrap" || call.arg_list()?.args().next().is_some() {
+        return None;
+    }
+    let receiver = call.receiver()?;
+    let ty = ctx.sema.type_of_expr(&receiver)?.original;
+    let try_enum = TryEnum::from_ty(&ctx.sema, &ty)?;
+
+    let call = ast::Expr::MethodCallExpr(call);
+    let target = call.syntax().text_range();
+    acc.add(
+        AssistId("replace_unwrap_with_match", AssistKind::RefactorRewrite),
+        "Replace unwrap with match",
+        target,
+        |builder| {
+            let indent = IndentLevel::from_node(call.syntax());
+            let arm_indent = indent + 1;
+            let happy = try_enum.happy_pattern(make::ext::simple_ident_pat(make::name("it")).into());
+            let sad = try_enum.sad_pattern();
+            let panic = match ctx.config.snippet_cap {
+                Some(_) => "${0:panic!()}",
+                None => "panic!()",
+            };
+            let mut buf = format!(
+                "match {receiver} {{\n{arm_indent}{happy} => it,\n{arm_indent}{sad} => {panic},\n{indent}}}"
+            );
+            if starts_expr_stmt(&call) {
+                cov_mark::hit!(replace_unwrap_with_match_parens);
+                buf = format!("({buf})");
+            }
+            match ctx.config.snippet_cap {
+                Some(cap) => builder.replace_snippet(cap, target, buf),
+                None => builder.replace(target, buf),
+            }
+        },
+    )
+}
+
+/// Returns `true` if `expr` is the leftmost part of a larger expression that forms a statement, in
+/// which case a `match` in its place would end the statement early, as in `match x {}.len();`.
+fn starts_expr_stmt(expr: &ast::Expr) -> bool {
+    let start = expr.syntax().text_range().start();
+    let outermost = expr
+        .syntax()
+        .ancestors()
+        .take_while(|it| ast::Expr::can_cast(it.kind()) && it.text_range().start() == start)
+        .last();
+    match outermost {
+        Some(outermost) if outermost != *expr.syntax() => outermost.parent().map_or(false, |it| {
+            ast::ExprStmt::can_cast(it.kind()) || ast::StmtList::can_cast(it.kind())
+        }),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn replace_option_unwrap() {
+        check_assist(
+            replace_unwrap_with_match,
+            r#"
+//- minicore: option
+fn f(x: Option<i32>) {
+    let v = x.unwrap$0();
+}
+"#,
+            r#"
+fn f(x: Option<i32>) {
+    let v = match x {
+        Some(it) => it,
+        None => ${0:panic!()},
+    };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn replace_result_unwrap() {
+        check_assist(
+            replace_unwrap_with_match,
+            r#"
+//- minicore: result
+fn f(x: Result<i32, ()>) -> i32 {
+    x.$0unwrap()
+}
+"#,
+            r#"
+fn f(x: Result<i32, ()>) -> i32 {
+    match x {
+        Ok(it) => it,
+        Err(_) => ${0:panic!()},
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn replace_unwrap_in_method_chain() {
+        check_assist(
+            replace_unwrap_with_match,
+            r#"
+//- minicore: option
+struct S;
+impl S {
+    fn get(&self) -> Option<S> { None }
+    fn len(&self) -> usize { 0 }
+}
+fn f(s: S) -> usize {
+    s.get().unwrap$0().len()
+}
+"#,
+            r#"
+struct S;
+impl S {
+    fn get(&self) -> Option<S> { None }
+    fn len(&self) -> usize { 0 }
+}
+fn f(s: S) -> usize {
+    (match s.get() {
+        Some(it) => it,
+        None => ${0:panic!()},
+    }).len()
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn replace_unwrap_in_method_chain_statement() {
+        cov_mark::check!(replace_unwrap_with_match_parens);
+        check_assist(
+            replace_unwrap_with_match,
+            r#"
+//- minicore: option
+struct S;
+impl S {
+    fn get(&self) -> Option<S> { None }
+    fn len(&self) -> usize { 0 }
+}
+fn f(s: S) {
+    s.get().unwrap$0().len();
+}
+"#,
+            r#"
+struct S;
+impl S {
+    fn get(&self) -> Option<S> { None }
+    fn len(&self) -> usize { 0 }
+}
+fn f(s: S) {
+    (match s.get() {
+        Some(it) => it,
+        None => ${0:panic!()},
+    }).len();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn replace_unwrap_as_receiver_in_let() {
+        check_assist(
+            replace_unwrap_with_match,
+            r#"
+//- minicore: option
+struct S;
+impl S {
+    fn len(&self) -> usize { 0 }
+}
+fn f(s: Option<S>) {
+    let n = s.unwrap$0().len();
+}
+"#,
+            r#"
+struct S;
+impl S {
+    fn len(&self) -> usize { 0 }
+}
+fn f(s: Option<S>) {
+    let n = match s {
+        Some(it) => it,
+        None => ${0:panic!()},
+    }.len();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_types() {
+        check_assist_not_applicable(
+            replace_unwrap_with_match,
+            r#"
+struct S;
+impl S {
+    fn unwrap(self) -> i32 { 0 }
+}
+fn f(s: S) {
+    s.unwrap$0();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_methods() {
+        check_assist_not_applicable(
+            replace_unwrap_with_match,
+            r#"
+//- minicore: option
+fn f(x: Option<i32>) {
+    x.unwrap_or$0(0);
+}
+"#,
+        );
+    }
+}
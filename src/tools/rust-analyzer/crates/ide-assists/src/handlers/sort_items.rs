@@ -180,6 +180,11 @@ fn add_sort_fields_assist(
 
 fn add_sort_variants_assist(acc: &mut Assists, variant_list: ast::VariantList) -> Option<()> {
     let variants: Vec<_> = variant_list.variants().collect();
+    // Reordering would change the values of the variants following an explicit discriminant.
+    if variants.iter().any(|it| it.expr().is_some()) {
+        cov_mark::hit!(not_applicable_if_enum_has_discriminants);
+        return None;
+    }
     let sorted = sort_by_name(&variants);
 
     if variants == sorted {
@@ -530,7 +535,7 @@ fn sort_enum() {
             r#"
 $0enum Bar $0{
     d{ first: u32, second: usize},
-    b = 14,
+    b,
     a,
     c(u32, usize),
 }
@@ -538,7 +543,7 @@ fn sort_enum() {
             r#"
 enum Bar {
     a,
-    b = 14,
+    b,
     c(u32, usize),
     d{ first: u32, second: usize},
 }
@@ -546,6 +551,50 @@ enum Bar {
         )
     }
 
+    #[test]
+    fn sort_enum_keeps_attributes_and_docs() {
+        check_assist(
+            sort_items,
+            r#"
+enum $0Config$0 {
+    /// The verbose one.
+    #[default]
+    Verbose,
+    #[cfg(test)]
+    Quiet,
+    // plain comment
+    Fast,
+}
+        "#,
+            r#"
+enum Config {
+    // plain comment
+    Fast,
+    #[cfg(test)]
+    Quiet,
+    /// The verbose one.
+    #[default]
+    Verbose,
+}
+        "#,
+        )
+    }
+
+    #[test]
+    fn not_applicable_if_enum_has_discriminants() {
+        cov_mark::check!(not_applicable_if_enum_has_discriminants);
+
+        check_assist_not_applicable(
+            sort_items,
+            r#"
+enum $0Bar$0 {
+    b = 1,
+    a,
+}
+        "#,
+        )
+    }
+
     #[test]
     fn sort_struct_enum_variant_fields() {
         check_assist(
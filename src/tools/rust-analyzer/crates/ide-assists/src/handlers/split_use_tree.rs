@@ -0,0 +1,207 @@
+use stdx::format_to;
+use syntax::{
+    ast::{self, edit::IndentLevel, make, HasAttrs, HasVisibility},
+    AstNode, TextRange,
+};
+
+use crate::{
+    assist_context::{AssistContext, Assists},
+    AssistId, AssistKind,
+};
+
+// Assist: split_use_tree
+//
+// Splits a use tree into one `use` item per imported name.
+//
+// ```
+// use std::$0fmt::{Debug, Display, Write};
+// ```
+// ->
+// ```
+// use std::fmt::Debug;
+// use std::fmt::Display;
+// use std::fmt::Write;
+// ```
+pub(crate) fn split_use_tree(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let use_ = ctx.find_node_at_offset::<ast::Use>()?;
+    let tree = use_.use_tree()?;
+
+    let mut imports = Vec::new();
+    flatten(None, &tree, &mut imports);
+    if imports.len() < 2 {
+        cov_mark::hit!(split_use_tree_single_import);
+        return None;
+    }
+
+    let vis = use_.visibility();
+    let use_token = use_.use_token()?;
+    // Attributes and doc comments in front of the item stay in place, the former are repeated on
+    // every new item.
+    let start = match &vis {
+        Some(vis) => vis.syntax().text_range().start(),
+        None => use_token.text_range().start(),
+    };
+    let range = TextRange::new(start, use_.syntax().text_range().end());
+
+    let target = tree.syntax().text_range();
+    acc.add(
+        AssistId("split_use_tree", AssistKind::RefactorRewrite),
+        "Split use tree into separate imports",
+        target,
+        |builder| {
+            let indent = IndentLevel::from_node(use_.syntax());
+            let vis = vis.map(|it| format!("{it} ")).unwrap_or_default();
+            let mut attrs = String::new();
+            for attr in use_.attrs() {
+                format_to!(attrs, "{attr}\n{indent}");
+            }
+
+            let mut buf = String::new();
+            for (i, import) in imports.iter().enumerate() {
+                if i > 0 {
+                    format_to!(buf, "\n{indent}{attrs}");
+                }
+                format_to!(buf, "{vis}use {import};");
+            }
+            builder.replace(range, buf);
+        },
+    )
+}
+
+/// Collects the fully qualified use trees of all names imported by `tree`.
+fn flatten(prefix: Option<&ast::Path>, tree: &ast::UseTree, acc: &mut Vec<String>) {
+    let path = match (prefix, tree.path()) {
+        // `a::{self}` imports `a` itself.
+        (Some(prefix), Some(path)) if is_self(&path) => Some(prefix.clone()),
+        (Some(prefix), Some(path)) => Some(make::path_concat(prefix.clone(), path)),
+        (prefix, path) => prefix.cloned().or(path),
+    };
+
+    if let Some(use_tree_list) = tree.use_tree_list() {
+        for tree in use_tree_list.use_trees() {
+            flatten(path.as_ref(), &tree, acc);
+        }
+        return;
+    }
+
+    let mut buf = path.as_ref().map(ToString::to_string).unwrap_or_default();
+    if tree.star_token().is_some() {
+        if path.is_some() {
+            buf.push_str("::");
+        }
+        buf.push('*');
+    }
+    if let Some(rename) = tree.rename() {
+        format_to!(buf, " {rename}");
+    }
+    acc.push(buf);
+}
+
+fn is_self(path: &ast::Path) -> bool {
+    path.qualifier().is_none() && path.segment().map_or(false, |it| it.self_token().is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn split_simple_list() {
+        check_assist(
+            split_use_tree,
+            r"
+use std::fmt::{Debug, Display$0, Write};
+",
+            r"
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Write;
+",
+        );
+    }
+
+    #[test]
+    fn split_flattens_nested_lists() {
+        check_assist(
+            split_use_tree,
+            r"
+use $0a::{b, c::{d, e}};
+",
+            r"
+use a::b;
+use a::c::d;
+use a::c::e;
+",
+        );
+    }
+
+    #[test]
+    fn split_keeps_visibility_and_renames() {
+        check_assist(
+            split_use_tree,
+            r"
+pub(crate) use std::fmt::{$0Debug as Dbg, Display};
+",
+            r"
+pub(crate) use std::fmt::Debug as Dbg;
+pub(crate) use std::fmt::Display;
+",
+        );
+    }
+
+    #[test]
+    fn split_self_and_glob() {
+        check_assist(
+            split_use_tree,
+            r"
+use std::$0io::{self, prelude::*, Read as _};
+",
+            r"
+use std::io;
+use std::io::prelude::*;
+use std::io::Read as _;
+",
+        );
+    }
+
+    #[test]
+    fn split_keeps_indentation_and_attributes() {
+        check_assist(
+            split_use_tree,
+            r"
+mod foo {
+    /// Docs.
+    #[cfg(test)]
+    use $0a::{b, c};
+
+    fn f() {}
+}
+",
+            r"
+mod foo {
+    /// Docs.
+    #[cfg(test)]
+    use a::b;
+    #[cfg(test)]
+    use a::c;
+
+    fn f() {}
+}
+",
+        );
+    }
+
+    #[test]
+    fn split_not_applicable_for_single_import() {
+        cov_mark::check!(split_use_tree_single_import);
+        check_assist_not_applicable(split_use_tree, "use std::fmt::{Debug$0};");
+    }
+
+    #[test]
+    fn split_not_applicable_for_simple_path() {
+        cov_mark::check!(split_use_tree_single_import);
+        check_assist_not_applicable(split_use_tree, "use std::fmt$0::Debug;");
+    }
+}
@@ -0,0 +1,359 @@
+use ide_db::{assists::GroupLabel, defs::Definition};
+use syntax::{
+    ast::{self, edit::IndentLevel, AstNode},
+    SyntaxKind, SyntaxNode, TextRange, WalkEvent,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: surround_with_block
+//
+// Wraps the selected statements in a block.
+//
+// ```
+// fn main() {
+//     $0let x = 1;
+//     foo(x);$0
+//     bar();
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     {
+//         let x = 1;
+//         foo(x);
+//     }
+//     bar();
+// }
+// ```
+
+// Assist: surround_with_if
+//
+// Wraps the selected statements in an `if`.
+//
+// ```
+// fn main() {
+//     $0foo();
+//     bar();$0
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     if ${0:true} {
+//         foo();
+//         bar();
+//     }
+// }
+// ```
+
+// Assist: surround_with_loop
+//
+// Wraps the selected statements in a `loop`.
+//
+// ```
+// fn main() {
+//     $0foo();
+//     bar();$0
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     loop {
+//         foo();
+//         bar();
+//     }
+// }
+// ```
+pub(crate) fn surround_with(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    if ctx.has_empty_selection() {
+        return None;
+    }
+    let selection = ctx.selection_trimmed();
+    let stmt_list = ctx.covering_element().ancestors().find_map(ast::StmtList::cast)?;
+    let tail = stmt_list.tail_expr();
+    let items: Vec<_> = stmt_list
+        .statements()
+        .map(|it| it.syntax().clone())
+        .chain(tail.as_ref().map(|it| it.syntax().clone()))
+        .filter(|it| selection.contains_range(it.text_range()))
+        .collect();
+    let range = items.first()?.text_range().cover(items.last()?.text_range());
+    // Only whole statements are wrapped.
+    if range != selection {
+        return None;
+    }
+    if declares_locals_used_after(ctx, &items, range) {
+        cov_mark::hit!(surround_with_locals_used_after);
+        return None;
+    }
+    let has_value_tail = tail.as_ref().map_or(false, |tail| {
+        range.contains_range(tail.syntax().text_range())
+            && ctx.sema.type_of_expr(tail).map_or(true, |it| !it.original.is_unit())
+    });
+
+    let indent = IndentLevel::from_node(items.first()?);
+    let text = stmt_list.syntax().text().slice(range - stmt_list.syntax().text_range().start());
+    let body = text
+        .to_string()
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| match idx {
+            0 => format!("{}{line}", indent + 1),
+            _ if line.trim().is_empty() => String::new(),
+            _ => format!("{}{line}", IndentLevel(1)),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let group = GroupLabel("Surround with...".to_owned());
+    let mut add = |id: &'static str, label: &str, keyword: &str, placeholder: Option<&str>| {
+        acc.add_group(&group, AssistId(id, AssistKind::Refactor), label, range, |builder| {
+            let wrap = |head: &str| format!("{keyword}{head}{{\n{body}\n{indent}}}");
+            match (ctx.config.snippet_cap, placeholder) {
+                (Some(cap), Some(it)) => {
+                    builder.replace_snippet(cap, range, wrap(&format!("${{0:{it}}} ")))
+                }
+                (None, Some(it)) => builder.replace(range, wrap(&format!("{it} "))),
+                (_, None) => builder.replace(range, wrap("")),
+            }
+        })
+    };
+    add("surround_with_block", "Surround with block", "", None);
+    // The bodies of `loop`s and of `if`s without an `else` have to evaluate to `()`.
+    if has_value_tail {
+        return None;
+    }
+    add("surround_with_if", "Surround with `if`", "if ", Some("true"));
+    // A new loop would capture the `break`s and `continue`s aimed at the one around it.
+    if has_unlabeled_break_or_continue(&items) {
+        cov_mark::hit!(surround_with_loop_captures_break);
+        return None;
+    }
+    add("surround_with_loop", "Surround with `loop`", "loop ", None)
+}
+
+/// Checks whether `items` contain a `break` or `continue` without a label that doesn't belong to
+/// a loop within them.
+fn has_unlabeled_break_or_continue(items: &[SyntaxNode]) -> bool {
+    items.iter().any(|item| {
+        let mut preorder = item.preorder();
+        while let Some(event) = preorder.next() {
+            let WalkEvent::Enter(node) = event else { continue };
+            let is_unlabeled = match node.kind() {
+                SyntaxKind::LOOP_EXPR
+                | SyntaxKind::WHILE_EXPR
+                | SyntaxKind::FOR_EXPR
+                | SyntaxKind::CLOSURE_EXPR => {
+                    preorder.skip_subtree();
+                    false
+                }
+                kind if ast::Item::can_cast(kind) => {
+                    preorder.skip_subtree();
+                    false
+                }
+                SyntaxKind::BREAK_EXPR => {
+                    ast::BreakExpr::cast(node).map_or(false, |it| it.lifetime().is_none())
+                }
+                SyntaxKind::CONTINUE_EXPR => {
+                    ast::ContinueExpr::cast(node).map_or(false, |it| it.lifetime().is_none())
+                }
+                _ => false,
+            };
+            if is_unlabeled {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// Checks whether any of the `let` statements in `items` binds a variable that's used outside of
+/// `range`, where it would be out of scope once wrapped.
+fn declares_locals_used_after(
+    ctx: &AssistContext<'_>,
+    items: &[SyntaxNode],
+    range: TextRange,
+) -> bool {
+    items
+        .iter()
+        .filter_map(|it| ast::LetStmt::cast(it.clone())?.pat())
+        .flat_map(|pat| pat.syntax().descendants().filter_map(ast::IdentPat::cast))
+        .filter_map(|it| ctx.sema.to_def(&it))
+        .any(|local| {
+            let usages = Definition::Local(local).usages(&ctx.sema).all();
+            let is_used_after =
+                usages.iter().flat_map(|(_, refs)| refs).any(|it| !range.contains_range(it.range));
+            is_used_after
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{
+        check_assist, check_assist_by_label, check_assist_not_applicable,
+        check_assist_not_applicable_by_label,
+    };
+
+    use super::*;
+
+    #[test]
+    fn surround_with_nested_lines() {
+        check_assist_by_label(
+            surround_with,
+            r#"
+fn main() {
+    if true {
+        $0foo(
+            1,
+        );
+
+        // comment
+        let x = 1;
+        bar(x)$0
+    }
+}
+"#,
+            r#"
+fn main() {
+    if true {
+        loop {
+            foo(
+                1,
+            );
+
+            // comment
+            let x = 1;
+            bar(x)
+        }
+    }
+}
+"#,
+            "Surround with `loop`",
+        );
+    }
+
+    #[test]
+    fn surround_tail_with_block() {
+        check_assist_by_label(
+            surround_with,
+            r#"
+fn main() -> u32 {
+    $0let x = 1;
+    x + 1$0
+}
+"#,
+            r#"
+fn main() -> u32 {
+    {
+        let x = 1;
+        x + 1
+    }
+}
+"#,
+            "Surround with block",
+        );
+    }
+
+    #[test]
+    fn only_block_around_value_tail() {
+        check_assist(
+            surround_with,
+            r#"
+fn main() -> u32 {
+    $0foo();
+    1$0
+}
+"#,
+            r#"
+fn main() -> u32 {
+    {
+        foo();
+        1
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_partial_statements() {
+        check_assist_not_applicable(
+            surround_with,
+            r#"
+fn main() {
+    foo($01);
+    bar();$0
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_locals_used_after() {
+        cov_mark::check!(surround_with_locals_used_after);
+        check_assist_not_applicable(
+            surround_with,
+            r#"
+fn main() {
+    $0let x = 1;
+    foo(x);$0
+    bar(x);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_loop_around_unlabeled_break() {
+        cov_mark::check!(surround_with_loop_captures_break);
+        check_assist_not_applicable_by_label(
+            surround_with,
+            r#"
+fn main() {
+    loop {
+        $0foo();
+        if done() {
+            break;
+        }$0
+    }
+}
+"#,
+            "Surround with `loop`",
+        );
+    }
+
+    #[test]
+    fn surround_with_loop_around_inner_loop() {
+        check_assist_by_label(
+            surround_with,
+            r#"
+fn main() {
+    'outer: loop {
+        $0for x in xs {
+            continue;
+        }
+        let f = || loop { break; };
+        break 'outer;$0
+    }
+}
+"#,
+            r#"
+fn main() {
+    'outer: loop {
+        loop {
+            for x in xs {
+                continue;
+            }
+            let f = || loop { break; };
+            break 'outer;
+        }
+    }
+}
+"#,
+            "Surround with `loop`",
+        );
+    }
+}
@@ -51,9 +51,21 @@ pub(crate) fn unqualify_method_call(acc: &mut Assists, ctx: &AssistContext<'_>)
     let delete_path =
         TextRange::new(path.syntax().text_range().start(), l_paren.text_range().end());
 
+    // `&expr` -> `expr`, if autoref gets us the same receiver back
+    let receiver = match &first_arg {
+        ast::Expr::RefExpr(ref_expr) if is_autoref_of_receiver(ctx, fun, ref_expr) => {
+            ref_expr.expr()?
+        }
+        _ => first_arg.clone(),
+    };
+    let delete_ref = TextRange::new(
+        first_arg.syntax().text_range().start(),
+        receiver.syntax().text_range().start(),
+    );
+
     // Parens around `expr` if needed
-    let parens = needs_parens_as_receiver(&first_arg).then(|| {
-        let range = first_arg.syntax().text_range();
+    let parens = needs_parens_as_receiver(&receiver).then(|| {
+        let range = receiver.syntax().text_range();
         (range.start(), range.end())
     });
 
@@ -71,6 +83,9 @@ pub(crate) fn unqualify_method_call(acc: &mut Assists, ctx: &AssistContext<'_>)
         call.syntax().text_range(),
         |edit| {
             edit.delete(delete_path);
+            if !delete_ref.is_empty() {
+                edit.delete(delete_ref);
+            }
             if let Some((open, close)) = parens {
                 edit.insert(open, "(");
                 edit.insert(close, ")");
@@ -80,6 +95,30 @@ pub(crate) fn unqualify_method_call(acc: &mut Assists, ctx: &AssistContext<'_>)
     )
 }
 
+/// Checks whether `ref_expr` borrows the receiver the way method call autoref would, that is
+/// whether it matches the kind of `self` of `fun` and doesn't borrow a reference.
+fn is_autoref_of_receiver(
+    ctx: &AssistContext<'_>,
+    fun: hir::Function,
+    ref_expr: &ast::RefExpr,
+) -> bool {
+    let access = match fun.self_param(ctx.db()) {
+        Some(it) => it.access(ctx.db()),
+        None => return false,
+    };
+    let matches_access = match access {
+        hir::Access::Shared => ref_expr.mut_token().is_none(),
+        hir::Access::Exclusive => ref_expr.mut_token().is_some(),
+        hir::Access::Owned => false,
+    };
+    matches_access
+        && ref_expr.raw_token().is_none()
+        && ref_expr
+            .expr()
+            .and_then(|it| ctx.sema.type_of_expr(&it))
+            .map_or(false, |it| !it.original.is_reference())
+}
+
 fn needs_parens_as_receiver(expr: &ast::Expr) -> bool {
     // Make `(expr).dummy()`
     let dummy_call = make::expr_method_call(
@@ -184,7 +223,48 @@ impl core::ops::Deref for S {
     type Target = S;
     fn deref(&self) -> &S { self }
 }
-fn f() { (&S).deref(); }"#,
+fn f() { S.deref(); }"#,
+        );
+    }
+
+    #[test]
+    fn unqualify_method_call_autoref() {
+        check_assist(
+            unqualify_method_call,
+            r#"
+struct S;
+impl S { fn f(&self) {} fn g(&mut self, x: u32) {} }
+fn f(mut s: S) { S::$0f(&s); }"#,
+            r#"
+struct S;
+impl S { fn f(&self) {} fn g(&mut self, x: u32) {} }
+fn f(mut s: S) { s.f(); }"#,
+        );
+        check_assist(
+            unqualify_method_call,
+            r#"
+struct S;
+impl S { fn f(&self) {} fn g(&mut self, x: u32) {} }
+fn f(mut s: S) { S::$0g(&mut s, 0); }"#,
+            r#"
+struct S;
+impl S { fn f(&self) {} fn g(&mut self, x: u32) {} }
+fn f(mut s: S) { s.g(0); }"#,
+        );
+    }
+
+    #[test]
+    fn unqualify_method_call_keeps_ref_not_matching_self() {
+        check_assist(
+            unqualify_method_call,
+            r#"
+struct S;
+impl S { fn f(&self) {} }
+fn f(mut s: S) { S::$0f(&mut s); }"#,
+            r#"
+struct S;
+impl S { fn f(&self) {} }
+fn f(mut s: S) { (&mut s).f(); }"#,
         );
     }
 
@@ -208,4 +288,26 @@ impl S { fn assoc(S: S, S: S) {} }
 fn f() { S::assoc$0(S, S); }"#,
         );
     }
+
+    #[test]
+    fn unqualify_method_call_keeps_borrow_of_reference() {
+        check_assist(
+            unqualify_method_call,
+            r#"
+//- minicore: deref
+struct S;
+impl core::ops::Deref for S {
+    type Target = S;
+    fn deref(&self) -> &S { self }
+}
+fn f(s: &S) { core::ops::Deref::$0deref(&s); }"#,
+            r#"
+struct S;
+impl core::ops::Deref for S {
+    type Target = S;
+    fn deref(&self) -> &S { self }
+}
+fn f(s: &S) { (&s).deref(); }"#,
+        );
+    }
 }
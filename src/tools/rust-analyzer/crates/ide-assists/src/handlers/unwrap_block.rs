@@ -1,7 +1,9 @@
+use ide_db::FxHashSet;
 use syntax::{
     ast::{
         self,
         edit::{AstNodeEdit, IndentLevel},
+        HasName,
     },
     AstNode, SyntaxKind, TextRange, T,
 };
@@ -36,6 +38,10 @@ pub(crate) fn unwrap_block(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option
     if ast::MatchArm::can_cast(parent.kind()) {
         parent = parent.ancestors().find(|it| ast::MatchExpr::can_cast(it.kind()))?
     }
+    if shadows_later_names(&block) {
+        cov_mark::hit!(unwrap_block_shadows_names);
+        return None;
+    }
 
     if matches!(parent.kind(), SyntaxKind::STMT_LIST | SyntaxKind::EXPR_STMT | SyntaxKind::LET_STMT)
     {
@@ -94,6 +100,37 @@ pub(crate) fn unwrap_block(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option
     })
 }
 
+/// Checks whether a variable declared in `block` has the same name as something used after it,
+/// which the variable would shadow once the block is gone.
+fn shadows_later_names(block: &ast::BlockExpr) -> bool {
+    let names: FxHashSet<_> = block
+        .statements()
+        .filter_map(|stmt| match stmt {
+            ast::Stmt::LetStmt(it) => it.pat(),
+            _ => None,
+        })
+        .flat_map(|pat| pat.syntax().descendants().filter_map(ast::IdentPat::cast))
+        .filter_map(|it| Some(it.name()?.text().to_string()))
+        .collect();
+    if names.is_empty() {
+        return false;
+    }
+    let stmt_list = match block.syntax().parent() {
+        Some(parent) => match parent.ancestors().find_map(ast::StmtList::cast) {
+            Some(it) => it,
+            None => return false,
+        },
+        None => return false,
+    };
+    let end = block.syntax().text_range().end();
+    stmt_list
+        .syntax()
+        .descendants()
+        .filter_map(ast::NameRef::cast)
+        .filter(|it| it.syntax().text_range().start() >= end)
+        .any(|it| names.contains(it.text().as_str()))
+}
+
 fn update_expr_string(expr_string: String) -> String {
     update_expr_string_with_pat(expr_string, &[' ', '\n'])
 }
@@ -758,6 +795,61 @@ fn main() {
     let a = 1;
     let x = foo;
 }
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_shadowing_later_names() {
+        cov_mark::check!(unwrap_block_shadows_names);
+        check_assist_not_applicable(
+            unwrap_block,
+            r#"
+fn main() {
+    let x = 1;
+    if true {$0
+        let x = 2;
+        foo(x);
+    }
+    bar(x);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn unwrap_with_names_used_only_inside() {
+        check_assist(
+            unwrap_block,
+            r#"
+fn main() {
+    let x = 1;
+    $0{
+        let y = 2;
+        foo(x, y);
+    }
+    bar(x);
+}
+"#,
+            r#"
+fn main() {
+    let x = 1;
+    let y = 2;
+    foo(x, y);
+    bar(x);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_fn_body() {
+        check_assist_not_applicable(
+            unwrap_block,
+            r#"
+fn main() {$0
+    foo();
+}
 "#,
         );
     }
@@ -0,0 +1,275 @@
+use hir::{Adt, ModuleDef, PathResolution};
+use ide_db::{
+    assists::GroupLabel,
+    famous_defs::FamousDefs,
+    helpers::mod_path_to_ast,
+    imports::insert_use::{insert_use, ImportScope},
+};
+use syntax::{
+    ast::{self, make, AstNode},
+    ted,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: wrap_field_type
+//
+// Wraps the type of a field in `Option`, `Box` or `Rc`.
+//
+// ```
+// # //- minicore: option
+// struct User {
+//     name: String$0,
+// }
+// ```
+// ->
+// ```
+// struct User {
+//     name: Option<String>,
+// }
+// ```
+
+// Assist: unwrap_field_type
+//
+// Unwraps the type of a field from `Option`, `Box` or `Rc`.
+//
+// ```
+// # //- minicore: option
+// struct User {
+//     name: Option<String$0>,
+// }
+// ```
+// ->
+// ```
+// struct User {
+//     name: String,
+// }
+// ```
+pub(crate) fn wrap_field_type(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let field_ty = ctx
+        .find_node_at_offset::<ast::RecordField>()
+        .and_then(|it| it.ty())
+        .or_else(|| ctx.find_node_at_offset::<ast::TupleField>()?.ty())?;
+    if !field_ty.syntax().text_range().contains_range(ctx.selection_trimmed()) {
+        return None;
+    }
+
+    let scope = ctx.sema.scope(field_ty.syntax())?;
+    let famous_defs = FamousDefs(&ctx.sema, scope.krate());
+    let wrappers = [
+        famous_defs.core_option_Option().map(Adt::from),
+        famous_defs.alloc_boxed_Box().map(Adt::from),
+        famous_defs.alloc_rc_Rc().map(Adt::from),
+    ];
+    let wrappers = wrappers.into_iter().flatten().collect::<Vec<_>>();
+
+    let target = field_ty.syntax().text_range();
+    let wrapped = wrapped_type(ctx, &wrappers, &field_ty);
+    let group = GroupLabel("Wrap field type".to_owned());
+    for &wrapper in &wrappers {
+        if wrapped.as_ref().map_or(false, |(it, _)| *it == wrapper) {
+            continue;
+        }
+        let name = wrapper.name(ctx.db()).to_smol_str();
+        // Wrappers that aren't in scope, like `Rc`, get imported, unless their name is taken.
+        let import = match scope.speculative_resolve(&make::ext::ident_path(&name)) {
+            Some(PathResolution::Def(ModuleDef::Adt(it))) if it == wrapper => None,
+            Some(_) => {
+                cov_mark::hit!(wrap_field_type_name_taken);
+                continue;
+            }
+            None => {
+                let Some(path) = scope.module().find_use_path_prefixed(
+                    ctx.db(),
+                    ModuleDef::Adt(wrapper),
+                    ctx.config.insert_use.prefix_kind,
+                    ctx.config.prefer_no_std,
+                ) else {
+                    continue;
+                };
+                let Some(import_scope) =
+                    ImportScope::find_insert_use_container(field_ty.syntax(), &ctx.sema)
+                else {
+                    continue;
+                };
+                Some((import_scope, mod_path_to_ast(&path)))
+            }
+        };
+        acc.add_group(
+            &group,
+            AssistId("wrap_field_type", AssistKind::RefactorRewrite),
+            format!("Wrap in `{name}`"),
+            target,
+            |builder| {
+                let scope = import.as_ref().map(|(scope, _)| match scope.clone() {
+                    ImportScope::File(it) => ImportScope::File(builder.make_mut(it)),
+                    ImportScope::Module(it) => ImportScope::Module(builder.make_mut(it)),
+                    ImportScope::Block(it) => ImportScope::Block(builder.make_mut(it)),
+                });
+                let field_ty = builder.make_mut(field_ty.clone());
+                let new_ty = make::ty(&format!("{name}<{field_ty}>")).clone_for_update();
+                ted::replace(field_ty.syntax(), new_ty.syntax());
+                if let (Some(scope), Some((_, path))) = (scope, import) {
+                    insert_use(&scope, path, &ctx.config.insert_use);
+                }
+            },
+        );
+    }
+
+    let (wrapper, inner) = wrapped?;
+    acc.add_group(
+        &group,
+        AssistId("unwrap_field_type", AssistKind::RefactorRewrite),
+        format!("Unwrap from `{}`", wrapper.name(ctx.db())),
+        target,
+        |builder| builder.replace(target, inner.to_string()),
+    )
+}
+
+/// Splits a type that resolves to one of `wrappers`, written as `Wrapper<T>`, into the wrapper
+/// and `T`.
+fn wrapped_type(
+    ctx: &AssistContext<'_>,
+    wrappers: &[Adt],
+    ty: &ast::Type,
+) -> Option<(Adt, ast::Type)> {
+    let segment = match ty {
+        ast::Type::PathType(it) => it.path()?.segment()?,
+        _ => return None,
+    };
+    let mut args = segment.generic_arg_list()?.generic_args();
+    let inner = match (args.next()?, args.next()) {
+        (ast::GenericArg::TypeArg(it), None) => it.ty()?,
+        _ => return None,
+    };
+    let wrapper = ctx.sema.resolve_type(ty)?.as_adt()?;
+    wrappers.contains(&wrapper).then_some((wrapper, inner))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{
+        check_assist_by_label, check_assist_not_applicable, check_assist_not_applicable_by_label,
+    };
+
+    use super::*;
+
+    #[test]
+    fn wrap_tuple_field_in_option() {
+        check_assist_by_label(
+            wrap_field_type,
+            r#"
+//- minicore: option
+struct S(u32, Vec<$0u8>);
+"#,
+            "struct S(u32, Option<Vec<u8>>);\n",
+            "Wrap in `Option`",
+        );
+    }
+
+    #[test]
+    fn wrap_in_rc_adds_import() {
+        check_assist_by_label(
+            wrap_field_type,
+            r#"
+//- minicore: rc
+struct S {
+    a: String$0,
+}
+"#,
+            r#"
+use core::rc::Rc;
+
+struct S {
+    a: Rc<String>,
+}
+"#,
+            "Wrap in `Rc`",
+        );
+    }
+
+    #[test]
+    fn wrap_in_rc_already_in_scope() {
+        check_assist_by_label(
+            wrap_field_type,
+            r#"
+//- minicore: rc
+use core::rc::Rc;
+enum E { V { a: u$032 } }
+"#,
+            r#"
+use core::rc::Rc;
+enum E { V { a: Rc<u32> } }
+"#,
+            "Wrap in `Rc`",
+        );
+    }
+
+    #[test]
+    fn no_wrap_in_rc_when_name_is_taken() {
+        cov_mark::check!(wrap_field_type_name_taken);
+        check_assist_not_applicable(
+            wrap_field_type,
+            r#"
+//- minicore: rc
+mod rc { pub struct Rc<T>(T); }
+use rc::Rc;
+struct S { a: u$032 }
+"#,
+        );
+    }
+
+    #[test]
+    fn unwrap_box() {
+        check_assist_by_label(
+            wrap_field_type,
+            r#"
+//- minicore: box, option
+struct S { a: Box<[u8; 4]$0> }
+"#,
+            "struct S { a: [u8; 4] }\n",
+            "Unwrap from `Box`",
+        );
+        check_assist_by_label(
+            wrap_field_type,
+            r#"
+//- minicore: box, option
+struct S { a: Box<u32$0> }
+"#,
+            "struct S { a: Option<Box<u32>> }\n",
+            "Wrap in `Option`",
+        );
+    }
+
+    #[test]
+    fn no_unwrap_from_other_types() {
+        check_assist_by_label(
+            wrap_field_type,
+            r#"
+//- minicore: box
+mod m { pub struct Box<T>(T); }
+struct S { a: m::Box<u32$0> }
+"#,
+            r#"
+mod m { pub struct Box<T>(T); }
+struct S { a: Box<m::Box<u32>> }
+"#,
+            "Wrap in `Box`",
+        );
+        check_assist_not_applicable_by_label(
+            wrap_field_type,
+            r#"
+//- minicore: box
+mod m { pub struct Box<T>(T); }
+struct S { a: m::Box<u32$0> }
+"#,
+            "Unwrap from `Box`",
+        );
+    }
+
+    #[test]
+    fn not_applicable_outside_field_type() {
+        check_assist_not_applicable(wrap_field_type, "struct S { a$0: u32 }");
+        check_assist_not_applicable(wrap_field_type, "fn foo(a: u32$0) {}");
+    }
+}
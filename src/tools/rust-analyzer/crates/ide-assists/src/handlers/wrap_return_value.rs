@@ -0,0 +1,229 @@
+use ide_db::{famous_defs::FamousDefs, syntax_helpers::node_ext::for_each_tail_expr};
+use syntax::ast::{self, AstNode};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: wrap_return_value
+//
+// Wraps the tail expression of a function returning `Result` or `Option` in `Ok` or `Some`.
+//
+// ```
+// # //- minicore: result
+// fn foo() -> Result<i32, ()> {
+//     4$02
+// }
+// ```
+// ->
+// ```
+// fn foo() -> Result<i32, ()> {
+//     Ok(42)
+// }
+// ```
+pub(crate) fn wrap_return_value(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let func = ctx
+        .token_at_offset()
+        .next()?
+        .parent_ancestors()
+        .find(|it| ast::Fn::can_cast(it.kind()) || ast::ClosureExpr::can_cast(it.kind()))
+        // The return type of closures is usually inferred from their body.
+        .and_then(ast::Fn::cast)?;
+    let body = ast::Expr::BlockExpr(func.body()?);
+
+    let mut tail = None;
+    for_each_tail_expr(&body, &mut |expr| {
+        if !matches!(expr, ast::Expr::BreakExpr(_))
+            && expr.syntax().text_range().contains_range(ctx.selection_trimmed())
+        {
+            tail = Some(expr.clone());
+        }
+    });
+    let tail = tail?;
+
+    let db = ctx.db();
+    let ret_ty = ctx.sema.to_def(&func)?.ret_type(db);
+    let ret_enum = match ret_ty.as_adt()? {
+        hir::Adt::Enum(it) => it,
+        _ => return None,
+    };
+    let famous_defs = FamousDefs(&ctx.sema, ctx.sema.scope(func.syntax())?.krate());
+    let wrapper = if Some(ret_enum) == famous_defs.core_result_Result() {
+        "Ok"
+    } else if Some(ret_enum) == famous_defs.core_option_Option() {
+        "Some"
+    } else {
+        return None;
+    };
+
+    let inner_ty = ret_ty.type_arguments().next()?;
+    let tail_ty = ctx.sema.type_of_expr(&tail)?.original;
+    if tail_ty.contains_unknown() {
+        return None;
+    }
+    if tail_ty.could_unify_with(db, &ret_ty) {
+        cov_mark::hit!(wrap_return_value_already_wrapped);
+        return None;
+    }
+    // Unsuffixed number literals that don't fit the return type fall back to `i32` or `f64`.
+    let is_unsuffixed_number = match &tail {
+        ast::Expr::Literal(it) => match it.kind() {
+            ast::LiteralKind::IntNumber(num) => num.suffix().is_none() && inner_ty.is_int_or_uint(),
+            ast::LiteralKind::FloatNumber(num) => {
+                num.suffix().is_none() && inner_ty.as_builtin().map_or(false, |it| it.is_float())
+            }
+            _ => false,
+        },
+        _ => false,
+    };
+    if !is_unsuffixed_number && !tail_ty.could_unify_with(db, &inner_ty) {
+        return None;
+    }
+
+    let target = tail.syntax().text_range();
+    acc.add(
+        AssistId("wrap_return_value", AssistKind::RefactorRewrite),
+        format!("Wrap return value in `{wrapper}`"),
+        target,
+        |builder| builder.replace(target, format!("{wrapper}({tail})")),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn wrap_in_ok() {
+        check_assist(
+            wrap_return_value,
+            r#"
+//- minicore: result
+fn foo() -> Result<i32, ()> {
+    let x = 1;
+    x + $01
+}
+"#,
+            r#"
+fn foo() -> Result<i32, ()> {
+    let x = 1;
+    Ok(x + 1)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn wrap_in_some() {
+        check_assist(
+            wrap_return_value,
+            r#"
+//- minicore: option
+fn foo(x: &str) -> Option<&str> {
+    x$0
+}
+"#,
+            r#"
+fn foo(x: &str) -> Option<&str> {
+    Some(x)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn wrap_branch_of_if() {
+        check_assist(
+            wrap_return_value,
+            r#"
+//- minicore: option
+fn foo(b: bool) -> Option<u8> {
+    if b {
+        None
+    } else {
+        $01
+    }
+}
+"#,
+            r#"
+fn foo(b: bool) -> Option<u8> {
+    if b {
+        None
+    } else {
+        Some(1)
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_already_result() {
+        cov_mark::check!(wrap_return_value_already_wrapped);
+        check_assist_not_applicable(
+            wrap_return_value,
+            r#"
+//- minicore: result
+fn bar() -> Result<i32, ()> {
+    Ok(1)
+}
+fn foo() -> Result<i32, ()> {
+    bar$0()
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_for_mismatched_type() {
+        check_assist_not_applicable(
+            wrap_return_value,
+            r#"
+//- minicore: result
+fn foo() -> Result<i32, ()> {
+    "no"$0
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_for_other_return_types() {
+        check_assist_not_applicable(
+            wrap_return_value,
+            r#"
+fn foo() -> i32 {
+    4$02
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_outside_tail() {
+        check_assist_not_applicable(
+            wrap_return_value,
+            r#"
+//- minicore: option
+fn foo() -> Option<i32> {
+    let x$0 = 1;
+    Some(x)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_in_closure() {
+        check_assist_not_applicable(
+            wrap_return_value,
+            r#"
+//- minicore: option
+fn foo() -> Option<i32> {
+    let f = || 4$02;
+    None
+}
+"#,
+        );
+    }
+}
@@ -77,7 +77,7 @@ macro_rules! eprintln {
 
 pub(crate) use crate::assist_context::{AssistContext, Assists};
 
-pub use assist_config::AssistConfig;
+pub use assist_config::{AssistConfig, PlaceholderMacro};
 pub use ide_db::assists::{
     Assist, AssistId, AssistKind, AssistResolveStrategy, GroupLabel, SingleResolve,
 };
@@ -106,16 +106,22 @@ mod handlers {
 
     pub(crate) type Handler = fn(&mut Assists, &AssistContext<'_>) -> Option<()>;
 
+    mod add_explicit_return;
     mod add_explicit_type;
     mod add_label_to_loop;
     mod add_lifetime_to_type;
     mod add_missing_impl_members;
+    mod add_must_use;
+    mod add_mut;
     mod add_turbo_fish;
     mod apply_demorgan;
     mod auto_import;
     mod change_visibility;
+    mod closure_braces;
     mod convert_bool_then;
+    mod convert_closure_to_fn;
     mod convert_comment_block;
+    mod convert_for_range_to_while;
     mod convert_integer_literal;
     mod convert_into_to_from;
     mod convert_iter_for_each_to_for;
@@ -123,11 +129,17 @@ mod handlers {
     mod convert_match_to_let_else;
     mod convert_tuple_struct_to_named_struct;
     mod convert_named_struct_to_tuple_struct;
+    mod convert_push_loop_to_collect;
+    mod convert_string_concat_to_format;
+    mod convert_to_fallible_fn;
     mod convert_to_guarded_return;
     mod convert_two_arm_bool_match_to_matches_macro;
+    mod convert_while_let_to_loop_match;
     mod convert_while_to_loop;
     mod destructure_tuple_binding;
+    mod expand_field_shorthand;
     mod expand_glob_import;
+    mod extract_constant;
     mod extract_expressions_from_format_string;
     mod extract_function;
     mod extract_module;
@@ -135,11 +147,17 @@ mod handlers {
     mod extract_type_alias;
     mod extract_variable;
     mod add_missing_match_arms;
+    mod fill_record_expr_fields;
+    mod fix_impl_method_signature;
     mod fix_visibility;
     mod flip_binexpr;
     mod flip_comma;
     mod flip_trait_bound;
+    mod generate_as_ref_and_as_mut;
     mod generate_constant;
+    mod generate_debug_impl;
+    mod generate_display_impl;
+    mod generate_default;
     mod generate_default_from_enum_variant;
     mod generate_default_from_new;
     mod generate_deref;
@@ -148,16 +166,24 @@ mod handlers {
     mod generate_enum_is_method;
     mod generate_enum_projection_method;
     mod generate_enum_variant;
+    mod generate_fields_from_params;
     mod generate_from_impl_for_enum;
+    mod generate_from_impl_for_newtype;
     mod generate_function;
-    mod generate_getter;
+    mod generate_getter_setter;
+    mod generate_hash_impl;
     mod generate_impl;
+    mod generate_partial_eq_impl;
+    mod generate_test;
+    mod generate_trait_from_impl;
     mod generate_is_empty_from_len;
+    mod generate_match_from_expr;
     mod generate_new;
-    mod generate_setter;
     mod generate_delegate_methods;
     mod add_return_type;
+    mod hoist_common_let;
     mod inline_call;
+    mod inline_format_args;
     mod inline_local_variable;
     mod inline_macro;
     mod inline_type_alias;
@@ -165,22 +191,31 @@ mod handlers {
     mod invert_if;
     mod merge_imports;
     mod merge_match_arms;
+    mod merge_nested_if;
     mod move_bounds;
     mod move_const_to_impl;
     mod move_guard;
+    mod move_method_between_impls;
     mod move_module_to_file;
     mod move_to_mod_rs;
     mod move_from_mod_rs;
+    mod negate_expression;
     mod number_representation;
+    mod prefix_unused_variable;
     mod promote_local_to_const;
+    mod promote_param_to_field;
     mod pull_assignment_up;
+    mod qualify_imported_name;
     mod qualify_path;
     mod qualify_method_call;
     mod raw_string;
     mod remove_dbg;
+    mod remove_explicit_type;
     mod remove_mut;
+    mod remove_unused_import;
     mod remove_unused_param;
     mod remove_parentheses;
+    mod remove_unnecessary_clone;
     mod reorder_fields;
     mod reorder_impl_items;
     mod replace_try_expr_with_match;
@@ -193,7 +228,10 @@ mod handlers {
     mod replace_qualified_name_with_use;
     mod replace_string_with_char;
     mod replace_turbofish_with_explicit_type;
+    mod replace_unwrap_with_match;
     mod split_import;
+    mod surround_with;
+    mod split_use_tree;
     mod unmerge_match_arm;
     mod unwrap_tuple;
     mod sort_items;
@@ -203,23 +241,34 @@ mod handlers {
     mod unwrap_block;
     mod unwrap_result_return_type;
     mod unqualify_method_call;
+    mod wrap_field_type;
     mod wrap_return_type_in_result;
+    mod wrap_return_value;
 
     pub(crate) fn all() -> &'static [Handler] {
         &[
             // These are alphabetic for the foolish consistency
+            add_explicit_return::add_explicit_return,
+            add_explicit_return::remove_redundant_return,
             add_explicit_type::add_explicit_type,
             add_label_to_loop::add_label_to_loop,
             add_missing_match_arms::add_missing_match_arms,
+            add_must_use::add_must_use,
+            add_mut::add_mut,
             add_lifetime_to_type::add_lifetime_to_type,
             add_return_type::add_return_type,
             add_turbo_fish::add_turbo_fish,
             apply_demorgan::apply_demorgan,
+            apply_demorgan::apply_demorgan_to_negation,
             auto_import::auto_import,
             change_visibility::change_visibility,
+            closure_braces::add_closure_braces,
+            closure_braces::remove_closure_braces,
             convert_bool_then::convert_bool_then_to_if,
             convert_bool_then::convert_if_to_bool_then,
+            convert_closure_to_fn::convert_closure_to_fn,
             convert_comment_block::convert_comment_block,
+            convert_for_range_to_while::convert_for_range_to_while,
             convert_integer_literal::convert_integer_literal,
             convert_into_to_from::convert_into_to_from,
             convert_iter_for_each_to_for::convert_iter_for_each_to_for,
@@ -227,20 +276,36 @@ pub(crate) fn all() -> &'static [Handler] {
             convert_let_else_to_match::convert_let_else_to_match,
             convert_named_struct_to_tuple_struct::convert_named_struct_to_tuple_struct,
             convert_match_to_let_else::convert_match_to_let_else,
+            convert_match_to_let_else::convert_if_let_to_let_else,
+            convert_push_loop_to_collect::convert_push_loop_to_collect,
+            convert_push_loop_to_collect::convert_collect_to_push_loop,
+            convert_string_concat_to_format::convert_string_concat_to_format,
+            convert_to_fallible_fn::convert_to_fallible_fn,
             convert_to_guarded_return::convert_to_guarded_return,
             convert_tuple_struct_to_named_struct::convert_tuple_struct_to_named_struct,
             convert_two_arm_bool_match_to_matches_macro::convert_two_arm_bool_match_to_matches_macro,
+            convert_two_arm_bool_match_to_matches_macro::convert_if_let_bool_to_matches_macro,
+            convert_while_let_to_loop_match::convert_while_let_to_loop_match,
+            convert_while_let_to_loop_match::convert_loop_match_to_while_let,
             convert_while_to_loop::convert_while_to_loop,
             destructure_tuple_binding::destructure_tuple_binding,
+            expand_field_shorthand::expand_field_shorthand,
             expand_glob_import::expand_glob_import,
+            extract_constant::extract_constant,
             extract_expressions_from_format_string::extract_expressions_from_format_string,
             extract_struct_from_enum_variant::extract_struct_from_enum_variant,
             extract_type_alias::extract_type_alias,
+            fill_record_expr_fields::fill_record_expr_fields,
+            fix_impl_method_signature::fix_impl_method_signature,
             fix_visibility::fix_visibility,
             flip_binexpr::flip_binexpr,
             flip_comma::flip_comma,
             flip_trait_bound::flip_trait_bound,
+            generate_as_ref_and_as_mut::generate_as_ref_and_as_mut,
             generate_constant::generate_constant,
+            generate_debug_impl::generate_debug_impl,
+            generate_display_impl::generate_display_impl,
+            generate_default::generate_default,
             generate_default_from_enum_variant::generate_default_from_enum_variant,
             generate_default_from_new::generate_default_from_new,
             generate_derive::generate_derive,
@@ -250,42 +315,65 @@ pub(crate) fn all() -> &'static [Handler] {
             generate_enum_projection_method::generate_enum_as_method,
             generate_enum_projection_method::generate_enum_try_into_method,
             generate_enum_variant::generate_enum_variant,
+            generate_fields_from_params::generate_fields_from_params,
             generate_from_impl_for_enum::generate_from_impl_for_enum,
+            generate_from_impl_for_newtype::generate_from_impl_for_newtype,
             generate_function::generate_function,
+            generate_hash_impl::generate_hash_impl,
             generate_impl::generate_impl,
             generate_impl::generate_trait_impl,
+            generate_partial_eq_impl::generate_partial_eq_impl,
+            generate_test::generate_test,
+            generate_trait_from_impl::generate_trait_from_impl,
             generate_is_empty_from_len::generate_is_empty_from_len,
+            generate_match_from_expr::generate_match_from_expr,
             generate_new::generate_new,
+            hoist_common_let::hoist_common_let,
             inline_call::inline_call,
             inline_call::inline_into_callers,
+            inline_format_args::inline_format_args,
+            inline_format_args::expand_format_args,
             inline_local_variable::inline_local_variable,
             inline_type_alias::inline_type_alias,
             inline_type_alias::inline_type_alias_uses,
             inline_macro::inline_macro,
             introduce_named_generic::introduce_named_generic,
+            introduce_named_generic::replace_named_generic_with_impl,
             introduce_named_lifetime::introduce_named_lifetime,
             invert_if::invert_if,
             merge_imports::merge_imports,
             merge_match_arms::merge_match_arms,
+            merge_nested_if::merge_nested_if,
             move_bounds::move_bounds_to_where_clause,
+            move_bounds::move_where_clause_to_bounds,
             move_const_to_impl::move_const_to_impl,
             move_guard::move_arm_cond_to_match_guard,
             move_guard::move_guard_to_arm_body,
+            move_method_between_impls::move_method_to_inherent_impl,
+            move_method_between_impls::move_method_to_trait_impl,
             move_module_to_file::move_module_to_file,
             move_to_mod_rs::move_to_mod_rs,
             move_from_mod_rs::move_from_mod_rs,
+            negate_expression::negate_expression,
             number_representation::reformat_number_literal,
             pull_assignment_up::pull_assignment_up,
             promote_local_to_const::promote_local_to_const,
+            promote_param_to_field::promote_param_to_field,
+            prefix_unused_variable::prefix_unused_variable,
+            qualify_imported_name::qualify_imported_name,
+            qualify_imported_name::qualify_all_imported_names,
             qualify_path::qualify_path,
             qualify_method_call::qualify_method_call,
             raw_string::add_hash,
             raw_string::make_usual_string,
             raw_string::remove_hash,
             remove_dbg::remove_dbg,
+            remove_explicit_type::remove_explicit_type,
             remove_mut::remove_mut,
+            remove_unused_import::remove_unused_import,
             remove_unused_param::remove_unused_param,
             remove_parentheses::remove_parentheses,
+            remove_unnecessary_clone::remove_unnecessary_clone,
             reorder_fields::reorder_fields,
             reorder_impl_items::reorder_impl_items,
             replace_try_expr_with_match::replace_try_expr_with_match,
@@ -296,12 +384,15 @@ pub(crate) fn all() -> &'static [Handler] {
             replace_or_with_or_else::replace_or_else_with_or,
             replace_or_with_or_else::replace_or_with_or_else,
             replace_turbofish_with_explicit_type::replace_turbofish_with_explicit_type,
+            replace_unwrap_with_match::replace_unwrap_with_match,
             replace_qualified_name_with_use::replace_qualified_name_with_use,
             replace_arith_op::replace_arith_with_wrapping,
             replace_arith_op::replace_arith_with_checked,
             replace_arith_op::replace_arith_with_saturating,
             sort_items::sort_items,
             split_import::split_import,
+            surround_with::surround_with,
+            split_use_tree::split_use_tree,
             toggle_ignore::toggle_ignore,
             unmerge_match_arm::unmerge_match_arm,
             unmerge_use::unmerge_use,
@@ -310,7 +401,9 @@ pub(crate) fn all() -> &'static [Handler] {
             unwrap_result_return_type::unwrap_result_return_type,
             unwrap_tuple::unwrap_tuple,
             unqualify_method_call::unqualify_method_call,
+            wrap_field_type::wrap_field_type,
             wrap_return_type_in_result::wrap_return_type_in_result,
+            wrap_return_value::wrap_return_value,
             // These are manually sorted for better priorities. By default,
             // priority is determined by the size of the target range (smaller
             // target wins). If the ranges are equal, position in this list is
@@ -323,12 +416,13 @@ pub(crate) fn all() -> &'static [Handler] {
             raw_string::make_raw_string,
             //
             extract_variable::extract_variable,
+            extract_variable::extract_variable_all_occurrences,
             extract_function::extract_function,
             extract_module::extract_module,
             //
-            generate_getter::generate_getter,
-            generate_getter::generate_getter_mut,
-            generate_setter::generate_setter,
+            generate_getter_setter::generate_getter,
+            generate_getter_setter::generate_getter_mut,
+            generate_getter_setter::generate_setter,
             generate_delegate_methods::generate_delegate_methods,
             generate_deref::generate_deref,
             // Are you sure you want to add new assist here, and not to the
@@ -15,8 +15,8 @@
 use test_utils::{assert_eq_text, extract_offset};
 
 use crate::{
-    assists, handlers::Handler, Assist, AssistConfig, AssistContext, AssistKind,
-    AssistResolveStrategy, Assists, SingleResolve,
+    assists, handlers::Handler, Assist, AssistConfig, AssistContext, AssistId, AssistKind,
+    AssistResolveStrategy, Assists, GroupLabel, PlaceholderMacro, SingleResolve,
 };
 
 pub(crate) const TEST_CONFIG: AssistConfig = AssistConfig {
@@ -31,18 +31,40 @@
     },
     prefer_no_std: false,
     assist_emit_must_use: false,
+    placeholder_macro: PlaceholderMacro::Todo,
+    max_width: 100,
 };
 
 pub(crate) fn with_single_file(text: &str) -> (RootDatabase, FileId) {
     RootDatabase::with_single_file(text)
 }
 
+/// Checks that `assist` turns `ra_fixture_before` into `ra_fixture_after`.
+///
+/// The cursor is marked with `$0`; a pair of `$0` markers selects the range between them.
 #[track_caller]
 pub(crate) fn check_assist(assist: Handler, ra_fixture_before: &str, ra_fixture_after: &str) {
     let ra_fixture_after = trim_indent(ra_fixture_after);
     check(assist, ra_fixture_before, ExpectedResult::After(&ra_fixture_after), None);
 }
 
+#[track_caller]
+pub(crate) fn check_assist_with_config(
+    assist: Handler,
+    config: AssistConfig,
+    ra_fixture_before: &str,
+    ra_fixture_after: &str,
+) {
+    let ra_fixture_after = trim_indent(ra_fixture_after);
+    check_with_config(
+        config,
+        assist,
+        ra_fixture_before,
+        ExpectedResult::After(&ra_fixture_after),
+        None,
+    );
+}
+
 // There is no way to choose what assist within a group you want to test against,
 // so this is here to allow you choose.
 pub(crate) fn check_assist_by_label(
@@ -68,6 +90,11 @@ pub(crate) fn check_assist_not_applicable(assist: Handler, ra_fixture: &str) {
     check(assist, ra_fixture, ExpectedResult::NotApplicable, None);
 }
 
+#[track_caller]
+pub(crate) fn check_assist_not_applicable_by_label(assist: Handler, ra_fixture: &str, label: &str) {
+    check(assist, ra_fixture, ExpectedResult::NotApplicable, Some(label));
+}
+
 /// Check assist in unresolved state. Useful to check assists for lazy computation.
 #[track_caller]
 pub(crate) fn check_assist_unresolved(assist: Handler, ra_fixture: &str) {
@@ -119,6 +146,17 @@ enum ExpectedResult<'a> {
 
 #[track_caller]
 fn check(handler: Handler, before: &str, expected: ExpectedResult<'_>, assist_label: Option<&str>) {
+    check_with_config(TEST_CONFIG, handler, before, expected, assist_label);
+}
+
+#[track_caller]
+fn check_with_config(
+    config: AssistConfig,
+    handler: Handler,
+    before: &str,
+    expected: ExpectedResult<'_>,
+    assist_label: Option<&str>,
+) {
     let (mut db, file_with_caret_id, range_or_offset) = RootDatabase::with_range_or_offset(before);
     db.set_enable_proc_attr_macros(true);
     let text_without_caret = db.file_text(file_with_caret_id).to_string();
@@ -126,7 +164,6 @@ fn check(handler: Handler, before: &str, expected: ExpectedResult<'_>, assist_la
     let frange = FileRange { file_id: file_with_caret_id, range: range_or_offset.into() };
 
     let sema = Semantics::new(&db);
-    let config = TEST_CONFIG;
     let ctx = AssistContext::new(sema, &config, frange);
     let resolve = match expected {
         ExpectedResult::Unresolved => AssistResolveStrategy::None,
@@ -183,11 +220,22 @@ fn check(handler: Handler, before: &str, expected: ExpectedResult<'_>, assist_la
                 buf.push_str(&contents);
             }
 
-            assert_eq_text!(after, &buf);
+            assert_eq_text!(
+                after,
+                &buf,
+                "Assist `{}` applied at:\n{}",
+                assist.id.0,
+                mark_range(&text_without_caret, frange.range)
+            );
         }
         (Some(assist), ExpectedResult::Target(target)) => {
             let range = assist.target;
-            assert_eq_text!(&text_without_caret[range], target);
+            assert_eq_text!(
+                &text_without_caret[range],
+                target,
+                "Actual target:\n{}",
+                mark_range(&text_without_caret, range)
+            );
         }
         (Some(assist), ExpectedResult::Unresolved) => assert!(
             assist.source_change.is_none(),
@@ -204,6 +252,16 @@ fn check(handler: Handler, before: &str, expected: ExpectedResult<'_>, assist_la
     };
 }
 
+/// Marks `range` in `text` with `$0` markers, for failure messages.
+fn mark_range(text: &str, range: TextRange) -> String {
+    let (start, end) = (usize::from(range.start()), usize::from(range.end()));
+    if range.is_empty() {
+        format!("{}$0{}", &text[..start], &text[start..])
+    } else {
+        format!("{}$0{}$0{}", &text[..start], &text[start..end], &text[end..])
+    }
+}
+
 fn labels(assists: &[Assist]) -> String {
     let mut labels = assists
         .iter()
@@ -229,7 +287,7 @@ fn assist_order_field_struct() {
     let assists = assists(&db, &TEST_CONFIG, AssistResolveStrategy::None, frange);
     let mut assists = assists.iter();
 
-    assert_eq!(assists.next().expect("expected assist").label, "Change visibility to pub(crate)");
+    assert_eq!(assists.next().expect("expected assist").label, "Change visibility to pub");
     assert_eq!(assists.next().expect("expected assist").label, "Generate a getter method");
     assert_eq!(assists.next().expect("expected assist").label, "Generate a mut getter method");
     assert_eq!(assists.next().expect("expected assist").label, "Generate a setter method");
@@ -256,13 +314,139 @@ pub fn test_some_range(a: int) -> bool {
 
     expect![[r#"
         Convert integer base
+        Extract into constant
         Extract into variable
         Extract into function
         Replace if let with match
+        Convert to matches!
     "#]]
     .assert_eq(&expected);
 }
 
+#[test]
+fn assists_are_ordered_by_priority_for_the_same_target() {
+    let (db, frange) = RootDatabase::with_range("fn f() { $0let x = 92;$0 }");
+    let sema = Semantics::new(&db);
+    let config = TEST_CONFIG;
+    let ctx = AssistContext::new(sema, &config, frange);
+    let mut acc = Assists::new(&ctx, AssistResolveStrategy::None);
+    let id = AssistId("test", AssistKind::Generate);
+    acc.add(id, "Whole item", TextRange::up_to(22.into()), |_| ());
+    for label in ["Third", "First", "Second"] {
+        acc.add(id, label, frange.range, |_| ());
+    }
+    for (label, priority) in [("Least relevant", -1), ("Most relevant", 2), ("Relevant", 1)] {
+        let group = GroupLabel(label.to_owned());
+        acc.add_group_with_priority(&group, priority, id, label, frange.range, |_| ());
+    }
+
+    expect![[r#"
+        Most relevant
+        Relevant
+        Third
+        First
+        Second
+        Least relevant
+        Whole item
+    "#]]
+    .assert_eq(&labels(&acc.finish()));
+}
+
+#[test]
+fn imports_are_ranked_above_qualifying_the_path() {
+    let (db, frange) = RootDatabase::with_range(
+        r#"
+mod a {
+    pub mod b {
+        pub struct Foo;
+    }
+}
+mod c {
+    pub struct Foo;
+}
+fn f() {
+    $0Foo$0;
+}
+"#,
+    );
+    let assists = assists(&db, &TEST_CONFIG, AssistResolveStrategy::None, frange);
+    let labels = assists.iter().map(|it| format!("{}\n", it.label)).collect::<String>();
+
+    expect![[r#"
+        Import `c::Foo`
+        Import `a::b::Foo`
+        Qualify as `a::b::Foo`
+        Qualify as `c::Foo`
+    "#]]
+    .assert_eq(&labels);
+}
+
+#[test]
+fn edits_are_only_built_for_resolved_assists() {
+    let (db, frange) = RootDatabase::with_range("fn f() { $0let x = 92;$0 }");
+    let sema = Semantics::new(&db);
+    let config = TEST_CONFIG;
+    let ctx = AssistContext::new(sema, &config, frange);
+    let resolve = AssistResolveStrategy::Single(SingleResolve {
+        assist_id: "resolved".to_string(),
+        assist_kind: AssistKind::Generate,
+    });
+    let mut acc = Assists::new(&ctx, resolve);
+    let mut built = Vec::new();
+    for (id, label) in [("unresolved", "Unresolved"), ("resolved", "Resolved")] {
+        acc.add(AssistId(id, AssistKind::Generate), label, frange.range, |_| built.push(id));
+    }
+
+    assert_eq!(built, vec!["resolved"]);
+    let assists = acc.finish();
+    let resolved =
+        assists.iter().map(|it| (it.id.0, it.source_change.is_some())).collect::<Vec<_>>();
+    assert_eq!(resolved, vec![("unresolved", false), ("resolved", true)]);
+}
+
+#[test]
+fn edits_can_span_several_files() {
+    let (db, file_ids) = RootDatabase::with_many_files(
+        r#"
+//- /main.rs
+mod foo;
+fn main() {}
+//- /foo.rs
+fn foo() {}
+"#,
+    );
+    let (main, foo) = (file_ids[0], file_ids[1]);
+    let frange = FileRange { file_id: main, range: TextRange::empty(0.into()) };
+    let sema = Semantics::new(&db);
+    let config = TEST_CONFIG;
+    let ctx = AssistContext::new(sema, &config, frange);
+    let mut acc = Assists::new(&ctx, AssistResolveStrategy::All);
+    acc.add(AssistId("multi_file", AssistKind::Refactor), "Multi file", frange.range, |builder| {
+        builder.insert(0.into(), "// main\n");
+        builder.insert_in_file(foo, 0.into(), "// foo\n");
+        builder.replace(TextRange::new(0.into(), 3.into()), "pub mod");
+    });
+
+    let source_change = acc.finish().pop().unwrap().source_change.unwrap();
+    let mut edited = source_change
+        .source_file_edits
+        .iter()
+        .map(|(&file_id, edit)| {
+            let mut text = db.file_text(file_id).to_string();
+            edit.apply(&mut text);
+            (file_id == main, text)
+        })
+        .collect::<Vec<_>>();
+    edited.sort();
+    assert_eq!(
+        edited,
+        vec![
+            (false, "// foo\nfn foo() {}\n".to_string()),
+            (true, "// main\npub mod foo;\nfn main() {}\n".to_string()),
+        ]
+    );
+}
+
 #[test]
 fn assist_filter_works() {
     let (db, frange) = RootDatabase::with_range(
@@ -285,9 +469,11 @@ pub fn test_some_range(a: int) -> bool {
 
         expect![[r#"
             Convert integer base
+            Extract into constant
             Extract into variable
             Extract into function
             Replace if let with match
+            Convert to matches!
         "#]]
         .assert_eq(&expected);
     }
@@ -299,6 +485,7 @@ pub fn test_some_range(a: int) -> bool {
         let expected = labels(&assists);
 
         expect![[r#"
+            Extract into constant
             Extract into variable
             Extract into function
         "#]]
@@ -334,9 +521,29 @@ pub fn test_some_range(a: int) -> bool {
 
     {
         let assists = assists(&db, &cfg, AssistResolveStrategy::None, frange);
-        assert_eq!(2, assists.len());
+        assert_eq!(3, assists.len());
         let mut assists = assists.into_iter();
 
+        let extract_into_constant_assist = assists.next().unwrap();
+        expect![[r#"
+            Assist {
+                id: AssistId(
+                    "extract_constant",
+                    RefactorExtract,
+                ),
+                label: "Extract into constant",
+                group: Some(
+                    GroupLabel(
+                        "Extract into constant",
+                    ),
+                ),
+                target: 59..60,
+                source_change: None,
+                trigger_signature_help: false,
+            }
+        "#]]
+        .assert_debug_eq(&extract_into_constant_assist);
+
         let extract_into_variable_assist = assists.next().unwrap();
         expect![[r#"
             Assist {
@@ -380,9 +587,29 @@ pub fn test_some_range(a: int) -> bool {
             }),
             frange,
         );
-        assert_eq!(2, assists.len());
+        assert_eq!(3, assists.len());
         let mut assists = assists.into_iter();
 
+        let extract_into_constant_assist = assists.next().unwrap();
+        expect![[r#"
+            Assist {
+                id: AssistId(
+                    "extract_constant",
+                    RefactorExtract,
+                ),
+                label: "Extract into constant",
+                group: Some(
+                    GroupLabel(
+                        "Extract into constant",
+                    ),
+                ),
+                target: 59..60,
+                source_change: None,
+                trigger_signature_help: false,
+            }
+        "#]]
+        .assert_debug_eq(&extract_into_constant_assist);
+
         let extract_into_variable_assist = assists.next().unwrap();
         expect![[r#"
             Assist {
@@ -426,9 +653,29 @@ pub fn test_some_range(a: int) -> bool {
             }),
             frange,
         );
-        assert_eq!(2, assists.len());
+        assert_eq!(3, assists.len());
         let mut assists = assists.into_iter();
 
+        let extract_into_constant_assist = assists.next().unwrap();
+        expect![[r#"
+            Assist {
+                id: AssistId(
+                    "extract_constant",
+                    RefactorExtract,
+                ),
+                label: "Extract into constant",
+                group: Some(
+                    GroupLabel(
+                        "Extract into constant",
+                    ),
+                ),
+                target: 59..60,
+                source_change: None,
+                trigger_signature_help: false,
+            }
+        "#]]
+        .assert_debug_eq(&extract_into_constant_assist);
+
         let extract_into_variable_assist = assists.next().unwrap();
         expect![[r#"
             Assist {
@@ -485,9 +732,50 @@ pub fn test_some_range(a: int) -> bool {
 
     {
         let assists = assists(&db, &cfg, AssistResolveStrategy::All, frange);
-        assert_eq!(2, assists.len());
+        assert_eq!(3, assists.len());
         let mut assists = assists.into_iter();
 
+        let extract_into_constant_assist = assists.next().unwrap();
+        expect![[r#"
+            Assist {
+                id: AssistId(
+                    "extract_constant",
+                    RefactorExtract,
+                ),
+                label: "Extract into constant",
+                group: Some(
+                    GroupLabel(
+                        "Extract into constant",
+                    ),
+                ),
+                target: 59..60,
+                source_change: Some(
+                    SourceChange {
+                        source_file_edits: {
+                            FileId(
+                                0,
+                            ): TextEdit {
+                                indels: [
+                                    Indel {
+                                        insert: "const $0VALUE: i32 = 5;\n\n",
+                                        delete: 0..0,
+                                    },
+                                    Indel {
+                                        insert: "VALUE",
+                                        delete: 59..60,
+                                    },
+                                ],
+                            },
+                        },
+                        file_system_edits: [],
+                        is_snippet: true,
+                    },
+                ),
+                trigger_signature_help: false,
+            }
+        "#]]
+        .assert_debug_eq(&extract_into_constant_assist);
+
         let extract_into_variable_assist = assists.next().unwrap();
         expect![[r#"
             Assist {
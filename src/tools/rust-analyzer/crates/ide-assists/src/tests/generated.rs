@@ -2,6 +2,40 @@
 
 use super::check_doc_test;
 
+#[test]
+fn doctest_add_closure_braces() {
+    check_doc_test(
+        "add_closure_braces",
+        r#####"
+fn main() {
+    let f = |x$0| x + 1;
+}
+"#####,
+        r#####"
+fn main() {
+    let f = |x| { $0x + 1 };
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_add_explicit_return() {
+    check_doc_test(
+        "add_explicit_return",
+        r#####"
+fn foo(x: u32) -> u32 {
+    x $0+ 1
+}
+"#####,
+        r#####"
+fn foo(x: u32) -> u32 {
+    return x + 1;
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_add_explicit_type() {
     check_doc_test(
@@ -92,7 +126,7 @@ fn bar(&self) {}
 }
 
 impl Trait<u32> for () {
-    $0type X;
+    type X = ${0:()};
 
     fn foo(&self) -> u32 {
         todo!()
@@ -170,6 +204,49 @@ fn handle(action: Action) {
     )
 }
 
+#[test]
+fn doctest_add_must_use() {
+    check_doc_test(
+        "add_must_use",
+        r#####"
+struct Point { x: u32 }
+impl Point {
+    /// Returns the x coordinate.
+    fn $0x(&self) -> u32 {
+        self.x
+    }
+}
+"#####,
+        r#####"
+struct Point { x: u32 }
+impl Point {
+    /// Returns the x coordinate.
+    #[must_use]
+    fn x(&self) -> u32 {
+        self.x
+    }
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_add_mut() {
+    check_doc_test(
+        "add_mut",
+        r#####"
+fn main() {
+    let $0x = Vec::new();
+}
+"#####,
+        r#####"
+fn main() {
+    let mut x = Vec::new();
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_add_return_type() {
     check_doc_test(
@@ -219,6 +296,23 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_apply_demorgan_to_negation() {
+    check_doc_test(
+        "apply_demorgan_to_negation",
+        r#####"
+fn main() {
+    if $0!(x == 4 && y < 3.14) {}
+}
+"#####,
+        r#####"
+fn main() {
+    if x != 4 || y >= 3.14 {}
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_auto_import() {
     check_doc_test(
@@ -248,7 +342,7 @@ fn doctest_change_visibility() {
 $0fn frobnicate() {}
 "#####,
         r#####"
-pub(crate) fn frobnicate() {}
+pub fn frobnicate() {}
 "#####,
     )
 }
@@ -275,6 +369,49 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_convert_closure_to_fn() {
+    check_doc_test(
+        "convert_closure_to_fn",
+        r#####"
+fn main() {
+    let add$0 = |a: i32, b: i32| a + b;
+    add(1, 2);
+}
+"#####,
+        r#####"
+fn main() {
+    add(1, 2);
+}
+
+fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_collect_to_push_loop() {
+    check_doc_test(
+        "convert_collect_to_push_loop",
+        r#####"
+//- minicore: iterators, vec
+fn foo(xs: impl Iterator<Item = u32>) {
+    let v: Vec<_> = $0xs.map(|x| x * 2).collect();
+}
+"#####,
+        r#####"
+fn foo(xs: impl Iterator<Item = u32>) {
+    let mut v = Vec::new();
+    for x in xs {
+        v.push(x * 2);
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_convert_for_loop_with_for_each() {
     check_doc_test(
@@ -298,6 +435,66 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_convert_for_range_to_while() {
+    check_doc_test(
+        "convert_for_range_to_while",
+        r#####"
+fn main() {
+    $0for i in 0..10 {
+        foo(i);
+    }
+}
+"#####,
+        r#####"
+fn main() {
+    let mut i = 0;
+    while i < 10 {
+        foo(i);
+        i += 1;
+    }
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_if_let_bool_to_matches_macro() {
+    check_doc_test(
+        "convert_if_let_bool_to_matches_macro",
+        r#####"
+fn main() {
+    if let Some(val) = scrutinee$0 { true } else { false }
+}
+"#####,
+        r#####"
+fn main() {
+    matches!(scrutinee, Some(val))
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_if_let_to_let_else() {
+    check_doc_test(
+        "convert_if_let_to_let_else",
+        r#####"
+//- minicore: option
+fn foo(opt: Option<i32>) -> i32 {
+    let val = $0if let Some(it) = opt { it } else { return 0 };
+    val + 1
+}
+"#####,
+        r#####"
+fn foo(opt: Option<i32>) -> i32 {
+    let Some(it) = opt else { return 0 };
+    it + 1
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_convert_if_to_bool_then() {
     check_doc_test(
@@ -407,6 +604,30 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_convert_loop_match_to_while_let() {
+    check_doc_test(
+        "convert_loop_match_to_while_let",
+        r#####"
+fn main() {
+    $0loop {
+        match it.next() {
+            Some(x) => foo(x),
+            None => break,
+        }
+    }
+}
+"#####,
+        r#####"
+fn main() {
+    while let Some(x) = it.next() {
+        foo(x)
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_convert_match_to_let_else() {
     check_doc_test(
@@ -469,6 +690,65 @@ pub fn y(&self) -> f32 {
     )
 }
 
+#[test]
+fn doctest_convert_push_loop_to_collect() {
+    check_doc_test(
+        "convert_push_loop_to_collect",
+        r#####"
+//- minicore: vec
+fn main() {
+    let mut v = Vec::new();
+    $0for x in 0..10 {
+        v.push(x * 2);
+    }
+}
+"#####,
+        r#####"
+fn main() {
+    let v: Vec<_> = (0..10).map(|x| x * 2).collect();
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_string_concat_to_format() {
+    check_doc_test(
+        "convert_string_concat_to_format",
+        r#####"
+//- minicore: add, string
+fn greet(greeting: String, name: &str) -> String {
+    greeting +$0 ", " + name + "!"
+}
+"#####,
+        r#####"
+fn greet(greeting: String, name: &str) -> String {
+    format!("{}, {}!", greeting, name)
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_to_fallible_fn() {
+    check_doc_test(
+        "convert_to_fallible_fn",
+        r#####"
+//- minicore: result
+fn parse(s: &str) -> Result<u32, ()> { Ok(0) }
+fn $0sum(a: &str, b: &str) -> u32 {
+    parse(a).unwrap() + parse(b).expect("not a number")
+}
+"#####,
+        r#####"
+fn parse(s: &str) -> Result<u32, ()> { Ok(0) }
+fn sum(a: &str, b: &str) -> Result<u32, ()> {
+    Ok(parse(a)? + parse(b)?)
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_convert_to_guarded_return() {
     check_doc_test(
@@ -554,6 +834,32 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_convert_while_let_to_loop_match() {
+    check_doc_test(
+        "convert_while_let_to_loop_match",
+        r#####"
+fn main() {
+    $0while let Some(x) = it.next() {
+        foo(x);
+    }
+}
+"#####,
+        r#####"
+fn main() {
+    loop {
+        match it.next() {
+            Some(x) => {
+                foo(x);
+            }
+            None => break,
+        }
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_convert_while_to_loop() {
     check_doc_test(
@@ -598,86 +904,184 @@ fn main() {
 }
 
 #[test]
-fn doctest_expand_glob_import() {
+fn doctest_expand_field_shorthand() {
     check_doc_test(
-        "expand_glob_import",
+        "expand_field_shorthand",
         r#####"
-mod foo {
-    pub struct Bar;
-    pub struct Baz;
+struct S { x: i32, y: i32 }
+fn main() {
+    let (x, y) = (1, 2);
+    let s = S$0 { x, y };
 }
-
-use foo::*$0;
-
-fn qux(bar: Bar, baz: Baz) {}
 "#####,
         r#####"
-mod foo {
-    pub struct Bar;
-    pub struct Baz;
+struct S { x: i32, y: i32 }
+fn main() {
+    let (x, y) = (1, 2);
+    let s = S { x: x, y: y };
 }
-
-use foo::{Bar, Baz};
-
-fn qux(bar: Bar, baz: Baz) {}
 "#####,
     )
 }
 
 #[test]
-fn doctest_extract_expressions_from_format_string() {
+fn doctest_expand_format_args() {
     check_doc_test(
-        "extract_expressions_from_format_string",
+        "expand_format_args",
         r#####"
 macro_rules! format_args {
-    ($lit:literal $(tt:tt)*) => { 0 },
+    ($lit:literal $($tt:tt)*) => { 0 },
 }
 macro_rules! print {
     ($($arg:tt)*) => (std::io::_print(format_args!($($arg)*)));
 }
 
 fn main() {
-    print!("{var} {x + 1}$0");
+    let (x, y) = (1, 2);
+    print!("{x} {}$0", y);
 }
 "#####,
         r#####"
 macro_rules! format_args {
-    ($lit:literal $(tt:tt)*) => { 0 },
+    ($lit:literal $($tt:tt)*) => { 0 },
 }
 macro_rules! print {
     ($($arg:tt)*) => (std::io::_print(format_args!($($arg)*)));
 }
 
 fn main() {
-    print!("{var} {}"$0, x + 1);
+    let (x, y) = (1, 2);
+    print!("{} {}", x, y);
 }
 "#####,
     )
 }
 
 #[test]
-fn doctest_extract_function() {
+fn doctest_expand_glob_import() {
     check_doc_test(
-        "extract_function",
+        "expand_glob_import",
         r#####"
-fn main() {
-    let n = 1;
-    $0let m = n + 2;
-    // calculate
-    let k = m + n;$0
-    let g = 3;
+mod foo {
+    pub struct Bar;
+    pub struct Baz;
 }
+
+use foo::*$0;
+
+fn qux(bar: Bar, baz: Baz) {}
 "#####,
         r#####"
-fn main() {
-    let n = 1;
-    fun_name(n);
-    let g = 3;
+mod foo {
+    pub struct Bar;
+    pub struct Baz;
 }
 
-fn $0fun_name(n: i32) {
-    let m = n + 2;
-    // calculate
+use foo::{Bar, Baz};
+
+fn qux(bar: Bar, baz: Baz) {}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_extract_constant() {
+    check_doc_test(
+        "extract_constant",
+        r#####"
+fn area(r: f64) -> f64 {
+    3.14159$0 * r * r
+}
+"#####,
+        r#####"
+const $0VALUE: f64 = 3.14159;
+
+fn area(r: f64) -> f64 {
+    VALUE * r * r
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_extract_constant_all() {
+    check_doc_test(
+        "extract_constant_all",
+        r#####"
+fn area(r: f64) -> f64 {
+    3.14159$0 * r * r
+}
+fn circumference(r: f64) -> f64 {
+    2.0 * 3.14159 * r
+}
+"#####,
+        r#####"
+const $0VALUE: f64 = 3.14159;
+
+fn area(r: f64) -> f64 {
+    VALUE * r * r
+}
+fn circumference(r: f64) -> f64 {
+    2.0 * VALUE * r
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_extract_expressions_from_format_string() {
+    check_doc_test(
+        "extract_expressions_from_format_string",
+        r#####"
+macro_rules! format_args {
+    ($lit:literal $(tt:tt)*) => { 0 },
+}
+macro_rules! print {
+    ($($arg:tt)*) => (std::io::_print(format_args!($($arg)*)));
+}
+
+fn main() {
+    print!("{var} {x + 1}$0");
+}
+"#####,
+        r#####"
+macro_rules! format_args {
+    ($lit:literal $(tt:tt)*) => { 0 },
+}
+macro_rules! print {
+    ($($arg:tt)*) => (std::io::_print(format_args!($($arg)*)));
+}
+
+fn main() {
+    print!("{var} {}"$0, x + 1);
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_extract_function() {
+    check_doc_test(
+        "extract_function",
+        r#####"
+fn main() {
+    let n = 1;
+    $0let m = n + 2;
+    // calculate
+    let k = m + n;$0
+    let g = 3;
+}
+"#####,
+        r#####"
+fn main() {
+    let n = 1;
+    fun_name(n);
+    let g = 3;
+}
+
+fn $0fun_name(n: i32) {
+    let m = n + 2;
+    // calculate
     let k = m + n;
 }
 "#####,
@@ -763,6 +1167,97 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_extract_variable_all_occurrences() {
+    check_doc_test(
+        "extract_variable_all_occurrences",
+        r#####"
+//- minicore: copy
+fn main() {
+    let a = $0(1 + 2)$0 * 4;
+    let b = (1 + 2) * 8;
+}
+"#####,
+        r#####"
+fn main() {
+    let $0var_name = (1 + 2);
+    let a = var_name * 4;
+    let b = var_name * 8;
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_fill_record_expr_fields() {
+    check_doc_test(
+        "fill_record_expr_fields",
+        r#####"
+struct Point { x: i32, y: i32, z: i32 }
+fn main() {
+    let p = Point$0 { x: 1 };
+}
+"#####,
+        r#####"
+struct Point { x: i32, y: i32, z: i32 }
+fn main() {
+    let p = Point { x: 1, y: ${0:todo!()}, z: todo!() };
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_fill_record_expr_fields_with_default() {
+    check_doc_test(
+        "fill_record_expr_fields_with_default",
+        r#####"
+//- minicore: default
+struct Point { x: i32, y: i32, z: i32 }
+impl Default for Point {
+    fn default() -> Self { Point { x: 0, y: 0, z: 0 } }
+}
+fn main() {
+    let p = Point$0 { x: 1 };
+}
+"#####,
+        r#####"
+struct Point { x: i32, y: i32, z: i32 }
+impl Default for Point {
+    fn default() -> Self { Point { x: 0, y: 0, z: 0 } }
+}
+fn main() {
+    let p = Point { x: 1, ..Default::default() };
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_fix_impl_method_signature() {
+    check_doc_test(
+        "fix_impl_method_signature",
+        r#####"
+trait Trait {
+    fn foo(&self, x: u32);
+}
+struct S;
+impl Trait for S {
+    fn $0foo(&self) {}
+}
+"#####,
+        r#####"
+trait Trait {
+    fn foo(&self, x: u32);
+}
+struct S;
+impl Trait for S {
+    fn foo(&self, x: u32) {}
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_fix_visibility() {
     check_doc_test(
@@ -833,6 +1328,32 @@ fn foo<T: Copy + Clone>() { }
     )
 }
 
+#[test]
+fn doctest_generate_as_ref_and_as_mut() {
+    check_doc_test(
+        "generate_as_ref_and_as_mut",
+        r#####"
+//- minicore: as_ref, as_mut
+struct Meters$0(f64);
+"#####,
+        r#####"
+struct Meters(f64);
+
+impl AsRef<f64> for Meters {
+    fn as_ref(&self) -> &f64 {
+        &self.0
+    }
+}
+
+impl AsMut<f64> for Meters {
+    fn as_mut(&mut self) -> &mut f64 {
+        &mut self.0
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_generate_constant() {
     check_doc_test(
@@ -855,6 +1376,60 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_generate_debug_impl() {
+    check_doc_test(
+        "generate_debug_impl",
+        r#####"
+//- minicore: fmt
+enum Shape$0 {
+    Circle { r: f64 },
+    Square(f64),
+}
+"#####,
+        r#####"
+enum Shape {
+    Circle { r: f64 },
+    Square(f64),
+}
+
+impl core::fmt::Debug for Shape {
+    $0fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Circle { r } => f.debug_struct("Circle").field("r", r).finish(),
+            Self::Square(arg0) => f.debug_tuple("Square").field(arg0).finish(),
+        }
+    }
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_generate_default() {
+    check_doc_test(
+        "generate_default",
+        r#####"
+struct Point$0 {
+    x: i32,
+    y: i32,
+}
+"#####,
+        r#####"
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Default for Point {
+    fn default() -> Self {
+        $0Self { x: Default::default(), y: Default::default() }
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_generate_default_from_enum_variant() {
     check_doc_test(
@@ -998,6 +1573,38 @@ struct Point {
     )
 }
 
+#[test]
+fn doctest_generate_display_impl() {
+    check_doc_test(
+        "generate_display_impl",
+        r#####"
+//- minicore: fmt
+enum Shape$0 {
+    Circle { r: f64 },
+    Square(f64),
+    Empty,
+}
+"#####,
+        r#####"
+enum Shape {
+    Circle { r: f64 },
+    Square(f64),
+    Empty,
+}
+
+impl core::fmt::Display for Shape {
+    $0fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Circle { .. } => write!(f, "Circle"),
+            Self::Square(..) => write!(f, "Square"),
+            Self::Empty => write!(f, "Empty"),
+        }
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_generate_doc_example() {
     check_doc_test(
@@ -1168,6 +1775,27 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_generate_fields_from_params() {
+    check_doc_test(
+        "generate_fields_from_params",
+        r#####"
+struct Point { x: i32, y: i32, label: String }
+impl Point {
+    fn new(x: i32, y: i32) -> Self {$0}
+}
+"#####,
+        r#####"
+struct Point { x: i32, y: i32, label: String }
+impl Point {
+    fn new(x: i32, y: i32) -> Self {
+        Self { x, y, label: ${0:todo!()} }
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_generate_from_impl_for_enum() {
     check_doc_test(
@@ -1187,6 +1815,26 @@ fn from(v: u32) -> Self {
     )
 }
 
+#[test]
+fn doctest_generate_from_impl_for_newtype() {
+    check_doc_test(
+        "generate_from_impl_for_newtype",
+        r#####"
+//- minicore: from
+struct $0Meters(f64);
+"#####,
+        r#####"
+struct Meters(f64);
+
+impl From<f64> for Meters {
+    fn from(v: f64) -> Self {
+        Self(v)
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_generate_function() {
     check_doc_test(
@@ -1275,6 +1923,41 @@ fn $0name_mut(&mut self) -> &mut String {
     )
 }
 
+#[test]
+fn doctest_generate_hash_impl() {
+    check_doc_test(
+        "generate_hash_impl",
+        r#####"
+//- minicore: hash
+struct Id;
+impl core::hash::Hash for Id {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {}
+}
+struct Key$0 {
+    id: Id,
+    parent: Id,
+}
+"#####,
+        r#####"
+struct Id;
+impl core::hash::Hash for Id {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {}
+}
+struct Key {
+    id: Id,
+    parent: Id,
+}
+
+impl core::hash::Hash for Key {
+    $0fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.parent.hash(state);
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_generate_impl() {
     check_doc_test(
@@ -1328,6 +2011,31 @@ pub fn is_empty(&self) -> bool {
     )
 }
 
+#[test]
+fn doctest_generate_match_from_expr() {
+    check_doc_test(
+        "generate_match_from_expr",
+        r#####"
+enum Shape { Circle(f64), Rect { w: f64, h: f64 }, Empty }
+
+fn area(s: Shape) -> f64 {
+    $0s
+}
+"#####,
+        r#####"
+enum Shape { Circle(f64), Rect { w: f64, h: f64 }, Empty }
+
+fn area(s: Shape) -> f64 {
+    match s {
+        $0Shape::Circle(..) => todo!(),
+        Shape::Rect { .. } => todo!(),
+        Shape::Empty => todo!(),
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_generate_new() {
     check_doc_test(
@@ -1349,6 +2057,60 @@ fn $0new(data: T) -> Self { Self { data } }
     )
 }
 
+#[test]
+fn doctest_generate_partial_eq_and_eq_impls() {
+    check_doc_test(
+        "generate_partial_eq_and_eq_impls",
+        r#####"
+//- minicore: eq
+struct Point$0 {
+    x: i32,
+    y: i32,
+}
+"#####,
+        r#####"
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl PartialEq for Point {
+    $0fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+impl Eq for Point {}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_generate_partial_eq_impl() {
+    check_doc_test(
+        "generate_partial_eq_impl",
+        r#####"
+//- minicore: eq
+struct Point$0 {
+    x: i32,
+    y: i32,
+}
+"#####,
+        r#####"
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl PartialEq for Point {
+    $0fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_generate_setter() {
     check_doc_test(
@@ -1372,6 +2134,60 @@ fn set_name(&mut self, name: String) {
     )
 }
 
+#[test]
+fn doctest_generate_test() {
+    check_doc_test(
+        "generate_test",
+        r#####"
+fn $0parse(s: &str) -> u32 {
+    s.len() as u32
+}
+"#####,
+        r#####"
+fn parse(s: &str) -> u32 {
+    s.len() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        $0
+    }
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_generate_trait_from_impl() {
+    check_doc_test(
+        "generate_trait_from_impl",
+        r#####"
+struct Foo;
+impl$0 Foo {
+    pub fn bar(&self) -> u32 {
+        92
+    }
+}
+"#####,
+        r#####"
+struct Foo;
+pub trait ${0:FooTrait} {
+    fn bar(&self) -> u32;
+}
+
+impl FooTrait for Foo {
+    fn bar(&self) -> u32 {
+        92
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_generate_trait_impl() {
     check_doc_test(
@@ -1393,6 +2209,40 @@ impl<T: Clone> $0 for Ctx<T> {
     )
 }
 
+#[test]
+fn doctest_hoist_common_let() {
+    check_doc_test(
+        "hoist_common_let",
+        r#####"
+fn foo(x: Option<u32>) -> u32 {
+    $0match x {
+        Some(x) => {
+            let base = 10;
+            base + x
+        }
+        None => {
+            let base = 10;
+            base
+        }
+    }
+}
+"#####,
+        r#####"
+fn foo(x: Option<u32>) -> u32 {
+    let base = 10;
+    match x {
+        Some(x) => {
+            base + x
+        }
+        None => {
+            base
+        }
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_inline_call() {
     check_doc_test(
@@ -1414,6 +2264,39 @@ fn foo(name: Option<&str>) {
     )
 }
 
+#[test]
+fn doctest_inline_format_args() {
+    check_doc_test(
+        "inline_format_args",
+        r#####"
+macro_rules! format_args {
+    ($lit:literal $($tt:tt)*) => { 0 },
+}
+macro_rules! print {
+    ($($arg:tt)*) => (std::io::_print(format_args!($($arg)*)));
+}
+
+fn main() {
+    let (x, y) = (1, 2);
+    print!("{} {:?}$0", x, y);
+}
+"#####,
+        r#####"
+macro_rules! format_args {
+    ($lit:literal $($tt:tt)*) => { 0 },
+}
+macro_rules! print {
+    ($($arg:tt)*) => (std::io::_print(format_args!($($arg)*)));
+}
+
+fn main() {
+    let (x, y) = (1, 2);
+    print!("{x} {y:?}");
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_inline_into_callers() {
     check_doc_test(
@@ -1694,6 +2577,29 @@ fn handle(action: Action) {
     )
 }
 
+#[test]
+fn doctest_merge_nested_if() {
+    check_doc_test(
+        "merge_nested_if",
+        r#####"
+fn main() {
+    i$0f x == 3 {
+        if y == 4 {
+            foo();
+        }
+    }
+}
+"#####,
+        r#####"
+fn main() {
+    if x == 3 && y == 4 {
+        foo();
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_move_arm_cond_to_match_guard() {
     check_doc_test(
@@ -1812,6 +2718,58 @@ fn handle(action: Action) {
     )
 }
 
+#[test]
+fn doctest_move_method_to_inherent_impl() {
+    check_doc_test(
+        "move_method_to_inherent_impl",
+        r#####"
+trait Trait {}
+struct S;
+impl Trait for S {
+    fn $0helper(&self) {}
+}
+"#####,
+        r#####"
+trait Trait {}
+struct S;
+impl Trait for S {
+}
+
+impl S {
+    fn helper(&self) {}
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_move_method_to_trait_impl() {
+    check_doc_test(
+        "move_method_to_trait_impl",
+        r#####"
+trait Trait {
+    fn foo(&self);
+}
+struct S;
+impl S {
+    pub fn $0foo(&self) {}
+}
+impl Trait for S {}
+"#####,
+        r#####"
+trait Trait {
+    fn foo(&self);
+}
+struct S;
+impl S {
+}
+impl Trait for S {
+    fn foo(&self) {}
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_move_module_to_file() {
     check_doc_test(
@@ -1843,29 +2801,113 @@ fn t() {}
     )
 }
 
+#[test]
+fn doctest_move_where_clause_to_bounds() {
+    check_doc_test(
+        "move_where_clause_to_bounds",
+        r#####"
+impl<T> Foo<T> where$0 T: Clone + Debug {
+}
+"#####,
+        r#####"
+impl<T: Clone + Debug> Foo<T> {
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_negate_expression() {
+    check_doc_test(
+        "negate_expression",
+        r#####"
+fn main() {
+    if $0x > 0 && !y$0 {}
+}
+"#####,
+        r#####"
+fn main() {
+    if x <= 0 || y {}
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_prefix_unused_variable() {
+    check_doc_test(
+        "prefix_unused_variable",
+        r#####"
+fn main() {
+    let (a, b$0) = (1, 2);
+    a;
+}
+"#####,
+        r#####"
+fn main() {
+    let (a, _b) = (1, 2);
+    a;
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_promote_local_to_const() {
     check_doc_test(
-        "promote_local_to_const",
+        "promote_local_to_const",
+        r#####"
+fn main() {
+    let foo$0 = true;
+
+    if foo {
+        println!("It's true");
+    } else {
+        println!("It's false");
+    }
+}
+"#####,
+        r#####"
+fn main() {
+    const $0FOO: bool = true;
+
+    if FOO {
+        println!("It's true");
+    } else {
+        println!("It's false");
+    }
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_promote_param_to_field() {
+    check_doc_test(
+        "promote_param_to_field",
         r#####"
-fn main() {
-    let foo$0 = true;
+//- minicore: copy
+struct Counter {
+    count: u32,
+}
 
-    if foo {
-        println!("It's true");
-    } else {
-        println!("It's false");
+impl Counter {
+    fn next(&mut self, $0step: u32) -> u32 {
+        self.count += step;
+        self.count
     }
 }
 "#####,
         r#####"
-fn main() {
-    const $0FOO: bool = true;
+struct Counter {
+    count: u32,
+    step: u32,
+}
 
-    if FOO {
-        println!("It's true");
-    } else {
-        println!("It's false");
+impl Counter {
+    fn next(&mut self) -> u32 {
+        self.count += self.step;
+        self.count
     }
 }
 "#####,
@@ -1901,6 +2943,59 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_qualify_all_imported_names() {
+    check_doc_test(
+        "qualify_all_imported_names",
+        r#####"
+mod foo {
+    pub struct Bar;
+}
+use foo::Bar;
+
+fn main() {
+    let a: Bar$0 = Bar;
+}
+"#####,
+        r#####"
+mod foo {
+    pub struct Bar;
+}
+
+fn main() {
+    let a: foo::Bar = foo::Bar;
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_qualify_imported_name() {
+    check_doc_test(
+        "qualify_imported_name",
+        r#####"
+mod foo {
+    pub struct Bar;
+}
+use foo::Bar;
+
+fn main() {
+    let a: Bar$0 = Bar;
+}
+"#####,
+        r#####"
+mod foo {
+    pub struct Bar;
+}
+use foo::Bar;
+
+fn main() {
+    let a: foo::Bar = Bar;
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_qualify_method_call() {
     check_doc_test(
@@ -1960,6 +3055,23 @@ fn doctest_reformat_number_literal() {
     )
 }
 
+#[test]
+fn doctest_remove_closure_braces() {
+    check_doc_test(
+        "remove_closure_braces",
+        r#####"
+fn main() {
+    let f = |x$0| { x + 1 };
+}
+"#####,
+        r#####"
+fn main() {
+    let f = |x| x + 1;
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_remove_dbg() {
     check_doc_test(
@@ -1977,6 +3089,23 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_remove_explicit_type() {
+    check_doc_test(
+        "remove_explicit_type",
+        r#####"
+fn main() {
+    let x$0: bool = true;
+}
+"#####,
+        r#####"
+fn main() {
+    let x = true;
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_remove_hash() {
     check_doc_test(
@@ -2028,6 +3157,86 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_remove_redundant_return() {
+    check_doc_test(
+        "remove_redundant_return",
+        r#####"
+fn foo(x: u32) -> u32 {
+    if x == 0 {
+        return 1;
+    }
+    $0return x + 1;
+}
+"#####,
+        r#####"
+fn foo(x: u32) -> u32 {
+    if x == 0 {
+        return 1;
+    }
+    x + 1
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_remove_unnecessary_clone() {
+    check_doc_test(
+        "remove_unnecessary_clone",
+        r#####"
+//- minicore: clone
+struct S;
+impl Clone for S { fn clone(&self) -> Self { S } }
+fn print(s: &S) {}
+fn main() {
+    let s = S;
+    print(&s.clo$0ne());
+}
+"#####,
+        r#####"
+struct S;
+impl Clone for S { fn clone(&self) -> Self { S } }
+fn print(s: &S) {}
+fn main() {
+    let s = S;
+    print(&s);
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_remove_unused_import() {
+    check_doc_test(
+        "remove_unused_import",
+        r#####"
+mod foo {
+    pub struct Bar;
+    pub struct Baz;
+}
+
+use foo::{Bar, Baz$0};
+
+fn main() {
+    let _ = Bar;
+}
+"#####,
+        r#####"
+mod foo {
+    pub struct Bar;
+    pub struct Baz;
+}
+
+use foo::{Bar};
+
+fn main() {
+    let _ = Bar;
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_remove_unused_param() {
     check_doc_test(
@@ -2273,6 +3482,19 @@ fn handle(action: Action) {
     )
 }
 
+#[test]
+fn doctest_replace_named_generic_with_impl() {
+    check_doc_test(
+        "replace_named_generic_with_impl",
+        r#####"
+fn foo<$0B: Bar>(bar: B) {}
+"#####,
+        r#####"
+fn foo(bar: impl Bar) {}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_replace_or_else_with_or() {
     check_doc_test(
@@ -2387,6 +3609,27 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_replace_unwrap_with_match() {
+    check_doc_test(
+        "replace_unwrap_with_match",
+        r#####"
+//- minicore: option
+fn handle(x: Option<i32>) {
+    let v = x.unwr$0ap();
+}
+"#####,
+        r#####"
+fn handle(x: Option<i32>) {
+    let v = match x {
+        Some(it) => it,
+        None => ${0:panic!()},
+    };
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_sort_items() {
     check_doc_test(
@@ -2491,6 +3734,86 @@ fn doctest_split_import() {
     )
 }
 
+#[test]
+fn doctest_split_use_tree() {
+    check_doc_test(
+        "split_use_tree",
+        r#####"
+use std::$0fmt::{Debug, Display, Write};
+"#####,
+        r#####"
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Write;
+"#####,
+    )
+}
+
+#[test]
+fn doctest_surround_with_block() {
+    check_doc_test(
+        "surround_with_block",
+        r#####"
+fn main() {
+    $0let x = 1;
+    foo(x);$0
+    bar();
+}
+"#####,
+        r#####"
+fn main() {
+    {
+        let x = 1;
+        foo(x);
+    }
+    bar();
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_surround_with_if() {
+    check_doc_test(
+        "surround_with_if",
+        r#####"
+fn main() {
+    $0foo();
+    bar();$0
+}
+"#####,
+        r#####"
+fn main() {
+    if ${0:true} {
+        foo();
+        bar();
+    }
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_surround_with_loop() {
+    check_doc_test(
+        "surround_with_loop",
+        r#####"
+fn main() {
+    $0foo();
+    bar();$0
+}
+"#####,
+        r#####"
+fn main() {
+    loop {
+        foo();
+        bar();
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_toggle_ignore() {
     check_doc_test(
@@ -2604,6 +3927,24 @@ fn foo() {
     )
 }
 
+#[test]
+fn doctest_unwrap_field_type() {
+    check_doc_test(
+        "unwrap_field_type",
+        r#####"
+//- minicore: option
+struct User {
+    name: Option<String$0>,
+}
+"#####,
+        r#####"
+struct User {
+    name: String,
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_unwrap_result_return_type() {
     check_doc_test(
@@ -2637,6 +3978,24 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_wrap_field_type() {
+    check_doc_test(
+        "wrap_field_type",
+        r#####"
+//- minicore: option
+struct User {
+    name: String$0,
+}
+"#####,
+        r#####"
+struct User {
+    name: Option<String>,
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_wrap_return_type_in_result() {
     check_doc_test(
@@ -2650,3 +4009,21 @@ fn foo() -> Result<i32, ${0:_}> { Ok(42i32) }
 "#####,
     )
 }
+
+#[test]
+fn doctest_wrap_return_value() {
+    check_doc_test(
+        "wrap_return_value",
+        r#####"
+//- minicore: result
+fn foo() -> Result<i32, ()> {
+    4$02
+}
+"#####,
+        r#####"
+fn foo() -> Result<i32, ()> {
+    Ok(42)
+}
+"#####,
+    )
+}
@@ -1,24 +1,30 @@
 //! Assorted functions shared by several assists.
 
-use std::ops;
+use std::{iter, ops};
 
 pub(crate) use gen_trait_fn_body::gen_trait_fn_body;
 use hir::{db::HirDatabase, HirDisplay, Semantics};
-use ide_db::{famous_defs::FamousDefs, path_transform::PathTransform, RootDatabase, SnippetCap};
+use ide_db::{
+    famous_defs::FamousDefs, path_transform::PathTransform,
+    syntax_helpers::insert_whitespace_into_node::insert_ws_into, RootDatabase, SnippetCap,
+};
 use stdx::format_to;
 use syntax::{
     ast::{
         self,
-        edit::{self, AstNodeEdit},
-        edit_in_place::{AttrsOwnerEdit, Removable},
+        edit::{self, AstNodeEdit, IndentLevel},
+        edit_in_place::{self, AttrsOwnerEdit, Removable},
         make, HasArgList, HasAttrs, HasGenericParams, HasName, HasTypeBounds, Whitespace,
     },
-    ted, AstNode, AstToken, Direction, SourceFile,
+    ted, AstNode, AstToken, Direction, SourceFile, SyntaxElement,
     SyntaxKind::*,
-    SyntaxNode, TextRange, TextSize, T,
+    SyntaxNode, SyntaxToken, TextRange, TextSize, T,
 };
 
-use crate::assist_context::{AssistContext, SourceChangeBuilder};
+use crate::{
+    assist_context::{AssistContext, SourceChangeBuilder},
+    AssistConfig, PlaceholderMacro,
+};
 
 pub(crate) mod suggest_name;
 mod gen_trait_fn_body;
@@ -123,6 +129,10 @@ fn has_def_name(item: &ast::AssocItem) -> bool {
                 (default_methods, def.body()),
                 (DefaultMethods::Only, Some(_)) | (DefaultMethods::No, None)
             ),
+            ast::AssocItem::TypeAlias(def) => matches!(
+                (default_methods, def.ty()),
+                (DefaultMethods::Only, Some(_)) | (DefaultMethods::No, None)
+            ),
             _ => default_methods == DefaultMethods::No,
         })
         .collect::<Vec<_>>()
@@ -130,6 +140,7 @@ fn has_def_name(item: &ast::AssocItem) -> bool {
 
 pub fn add_trait_assoc_items_to_impl(
     sema: &Semantics<'_, RootDatabase>,
+    config: &AssistConfig,
     items: Vec<ast::AssocItem>,
     trait_: hir::Trait,
     impl_: ast::Impl,
@@ -139,36 +150,315 @@ pub fn add_trait_assoc_items_to_impl(
 
     let transform = PathTransform::trait_impl(&target_scope, &source_scope, trait_, impl_.clone());
 
+    // All the items come from the trait, so they share its file's style.
+    let source_style = items.first().map(|it| IndentStyle::of_file(it.syntax()));
     let items = items.into_iter().map(|assoc_item| {
         transform.apply(assoc_item.syntax());
         assoc_item.remove_attrs_and_docs();
+        if let Some(source_style) = source_style {
+            source_style.normalize(assoc_item.syntax());
+        }
         assoc_item
     });
 
     let res = impl_.clone_for_update();
 
+    // The new items are built out of `IndentLevel`s, which are always four
+    // spaces wide, and only switched over to the style of the file once they
+    // are in place. The rest of the file is left as it is.
+    let style = IndentStyle::of_file(res.syntax());
+    let impl_indent = style.indent_level(res.syntax());
+    let item_indent = impl_indent + 1;
+
     let assoc_item_list = res.get_or_create_assoc_item_list();
-    let mut first_item = None;
+    let was_empty = assoc_item_list.assoc_items().next().is_none();
+    let mut new_items = Vec::new();
     for item in items {
-        first_item.get_or_insert_with(|| item.clone());
-        match &item {
-            ast::AssocItem::Fn(fn_) if fn_.body().is_none() => {
-                let body = make::block_expr(None, Some(make::ext::expr_todo()))
-                    .indent(edit::IndentLevel(1));
-                ted::replace(fn_.get_or_create_body().syntax(), body.clone_for_update().syntax())
+        build_impl_item_body(&item, config);
+        // The items and their bodies are laid out as if the impl wasn't indented.
+        if impl_indent.0 > 0 {
+            edit_in_place::Indent::indent(&item, impl_indent);
+        }
+        assoc_item_list.add_item(item.clone());
+        // `add_item` measures the indentation of its neighbours in four-space
+        // units, which doesn't work out for files indented with something else.
+        if let Some(ws) = item
+            .syntax()
+            .first_token()
+            .and_then(|it| it.prev_token())
+            .and_then(ast::Whitespace::cast)
+        {
+            let newlines = "\n".repeat(ws.text().matches('\n').count());
+            ted::replace(
+                ws.syntax(),
+                make::tokens::whitespace(&format!("{newlines}{item_indent}")),
+            );
+        }
+        if let ast::AssocItem::Fn(fn_) = &item {
+            wrap_long_signature(fn_, item_indent, style, config.max_width);
+        }
+        new_items.push(item);
+    }
+
+    for item in &new_items {
+        style.denormalize(item.syntax());
+    }
+    if was_empty {
+        let ws_before_r_curly = assoc_item_list
+            .r_curly_token()
+            .and_then(|it| it.prev_token())
+            .and_then(ast::Whitespace::cast);
+        if let Some(ws) = ws_before_r_curly {
+            let indent = style.unit().repeat(impl_indent.0.into());
+            ted::replace(ws.syntax(), make::tokens::whitespace(&format!("\n{indent}")));
+        }
+    }
+
+    (res, new_items.into_iter().next().unwrap())
+}
+
+/// Fills in a placeholder for whatever the impl has to provide for `item`: a
+/// `todo!()` body for functions and constants and `()` for associated types.
+fn build_impl_item_body(item: &ast::AssocItem, config: &AssistConfig) {
+    match item {
+        ast::AssocItem::Fn(fn_) if fn_.body().is_none() => {
+            let body =
+                make::block_expr(None, Some(placeholder_expr(config))).indent(edit::IndentLevel(1));
+            ted::replace(fn_.get_or_create_body().syntax(), body.clone_for_update().syntax())
+        }
+        ast::AssocItem::TypeAlias(type_alias) => {
+            if let Some(type_bound_list) = type_alias.type_bound_list() {
+                type_bound_list.remove()
             }
-            ast::AssocItem::TypeAlias(type_alias) => {
-                if let Some(type_bound_list) = type_alias.type_bound_list() {
-                    type_bound_list.remove()
-                }
+            if type_alias.ty().is_none() {
+                let ty = make::ty_unit().clone_for_update();
+                insert_assignment(item.syntax(), type_alias.semicolon_token(), ty.syntax());
+            }
+        }
+        ast::AssocItem::Const(const_) if const_.body().is_none() => {
+            let body = placeholder_expr(config).clone_for_update();
+            insert_assignment(item.syntax(), const_.semicolon_token(), body.syntax());
+        }
+        _ => {}
+    }
+
+    fn insert_assignment(item: &SyntaxNode, semicolon: Option<SyntaxToken>, value: &SyntaxNode) {
+        let position = match semicolon {
+            Some(semicolon) => ted::Position::before(semicolon),
+            None => ted::Position::last_child_of(item),
+        };
+        ted::insert_all_raw(
+            position,
+            vec![
+                make::tokens::single_space().into(),
+                make::token(T![=]).into(),
+                make::tokens::single_space().into(),
+                value.clone().into(),
+            ],
+        );
+    }
+}
+
+/// Puts every parameter of `fn_` on its own line if the line with its signature
+/// would otherwise be longer than `max_width`, the way rustfmt does.
+fn wrap_long_signature(fn_: &ast::Fn, indent: IndentLevel, style: IndentStyle, max_width: usize) {
+    let (Some(param_list), Some(body)) = (fn_.param_list(), fn_.body()) else { return };
+    let signature_range =
+        TextRange::new(fn_.syntax().text_range().start(), body.syntax().text_range().start());
+    let signature = fn_.syntax().text().slice(signature_range - fn_.syntax().text_range().start());
+    if signature.contains_char('\n')
+        || usize::from(indent.0) * style.unit().len() + usize::from(signature.len()) < max_width
+    {
+        return;
+    }
+
+    let params: Vec<SyntaxNode> = param_list
+        .self_param()
+        .map(|it| it.syntax().clone())
+        .into_iter()
+        .chain(param_list.params().map(|it| it.syntax().clone()))
+        .collect();
+    let (Some(last_param), Some(r_paren)) = (params.last(), param_list.r_paren_token()) else {
+        return;
+    };
+
+    let whitespace: Vec<_> =
+        param_list.syntax().children_with_tokens().filter(|it| it.kind() == WHITESPACE).collect();
+    whitespace.into_iter().for_each(ted::remove);
+
+    let param_indent = indent + 1;
+    for param in &params {
+        let ws = make::tokens::whitespace(&format!("\n{param_indent}"));
+        ted::insert(ted::Position::before(param), ws);
+    }
+    // With the newline before `)` in place, `ted` won't pad the trailing comma with a space.
+    ted::insert(ted::Position::before(&r_paren), make::tokens::whitespace(&format!("\n{indent}")));
+    if last_param.next_sibling_or_token().map(|it| it.kind()) != Some(T![,]) {
+        ted::insert(ted::Position::after(last_param), make::token(T![,]));
+    }
+}
+
+/// The expression used in place of code that is yet to be written.
+pub(crate) fn placeholder_expr(config: &AssistConfig) -> ast::Expr {
+    match config.placeholder_macro {
+        PlaceholderMacro::Todo => make::ext::expr_todo(),
+        PlaceholderMacro::Unimplemented => make::ext::expr_unimplemented(),
+    }
+}
+
+/// The unit of indentation used throughout a file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum IndentStyle {
+    Tabs,
+    Spaces(usize),
+}
+
+impl IndentStyle {
+    const DEFAULT: IndentStyle = IndentStyle::Spaces(4);
+
+    /// Detects the indentation style of the file containing `node`.
+    pub(crate) fn of_file(node: &SyntaxNode) -> IndentStyle {
+        IndentStyle::detect(&file_root(node))
+    }
+
+    /// Guesses the indentation unit from the leading whitespace of the lines in
+    /// `node`.
+    ///
+    /// Tabs win if more lines are indented with tabs than with spaces. The
+    /// width of a space-based unit is the most common change in indentation
+    /// between consecutive lines. Without any indented lines to go by, this
+    /// falls back to four spaces.
+    pub(crate) fn detect(node: &SyntaxNode) -> IndentStyle {
+        let mut tab_lines = 0;
+        let mut space_lines = 0;
+        // `steps[n]` counts how often the indentation changed by `n` spaces.
+        let mut steps = [0usize; 9];
+        let mut prev_width = 0;
+
+        let line_indents = node
+            .descendants_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter_map(ast::Whitespace::cast)
+            .filter_map(|ws| {
+                let text = ws.text();
+                text.rfind('\n').map(|pos| text[pos + 1..].to_owned())
+            });
+        for indent in line_indents {
+            if indent.starts_with('\t') {
+                tab_lines += 1;
+                continue;
+            }
+            let width = indent.chars().take_while(|&c| c == ' ').count();
+            if width > 0 {
+                space_lines += 1;
+            }
+            let step = width.abs_diff(prev_width);
+            if let Some(count) = steps.get_mut(step) {
+                *count += 1;
+            }
+            prev_width = width;
+        }
+
+        if tab_lines > space_lines {
+            return IndentStyle::Tabs;
+        }
+        let mut best = None;
+        for (width, &count) in steps.iter().enumerate().skip(1) {
+            if count > 0 && best.map_or(true, |(_, best_count)| count > best_count) {
+                best = Some((width, count));
+            }
+        }
+        best.map_or(IndentStyle::DEFAULT, |(width, _)| IndentStyle::Spaces(width))
+    }
+
+    /// The indentation of the line `node` starts on, in units of this style.
+    pub(crate) fn indent_level(self, node: &SyntaxNode) -> IndentLevel {
+        let line_indent = node
+            .first_token()
+            .into_iter()
+            .flat_map(|it| iter::successors(it.prev_token(), |it| it.prev_token()))
+            .filter_map(ast::Whitespace::cast)
+            .find_map(|ws| {
+                let text = ws.text();
+                text.rfind('\n').map(|pos| text[pos + 1..].to_owned())
+            });
+        let levels = line_indent.map_or(0, |indent| self.split_indent(&indent).0);
+        IndentLevel(levels.try_into().unwrap_or(u8::MAX))
+    }
+
+    /// Rewrites the indentation of the lines of the mutable `node`, including the
+    /// one it starts on, from this style into the four-space style produced by
+    /// [`edit::IndentLevel`].
+    pub(crate) fn normalize(self, node: &SyntaxNode) {
+        if self != IndentStyle::DEFAULT {
+            self.reindent(node, |indent| {
+                let (levels, rest) = self.split_indent(indent);
+                format!("{}{rest}", IndentStyle::DEFAULT.unit().repeat(levels))
+            })
+        }
+    }
+
+    /// The inverse of [`IndentStyle::normalize`].
+    pub(crate) fn denormalize(self, node: &SyntaxNode) {
+        if self != IndentStyle::DEFAULT {
+            self.reindent(node, |indent| {
+                let (levels, rest) = IndentStyle::DEFAULT.split_indent(indent);
+                format!("{}{rest}", self.unit().repeat(levels))
+            })
+        }
+    }
+
+    /// The text of a single level of indentation.
+    pub(crate) fn unit(self) -> String {
+        match self {
+            IndentStyle::Tabs => "\t".to_owned(),
+            IndentStyle::Spaces(width) => " ".repeat(width),
+        }
+    }
+
+    /// Splits `indent` into the number of whole indentation units it starts
+    /// with and whatever is left over.
+    fn split_indent(self, indent: &str) -> (usize, &str) {
+        match self {
+            IndentStyle::Tabs => {
+                let rest = indent.trim_start_matches('\t');
+                (indent.len() - rest.len(), rest)
+            }
+            IndentStyle::Spaces(width) => {
+                let spaces = indent.len() - indent.trim_start_matches(' ').len();
+                let levels = spaces / width;
+                (levels, &indent[levels * width..])
             }
-            _ => {}
         }
+    }
 
-        assoc_item_list.add_item(item)
+    fn reindent(self, node: &SyntaxNode, convert: impl Fn(&str) -> String) {
+        let whitespace = node
+            .first_token()
+            .and_then(|it| it.prev_token())
+            .into_iter()
+            .chain(node.descendants_with_tokens().filter_map(|it| it.into_token()))
+            .filter_map(ast::Whitespace::cast)
+            .filter(|ws| ws.text().contains('\n'))
+            .collect::<Vec<_>>();
+        for ws in whitespace {
+            let mut lines = ws.text().split('\n').peekable();
+            let mut text = lines.next().unwrap_or_default().to_owned();
+            while let Some(line) = lines.next() {
+                text.push('\n');
+                // Every line but the last one is blank, and stays without any
+                // indentation so that no trailing whitespace is left behind.
+                if lines.peek().is_none() {
+                    text.push_str(&convert(line));
+                }
+            }
+            ted::replace(ws.syntax(), make::tokens::whitespace(&text));
+        }
     }
+}
 
-    (res, first_item.unwrap())
+fn file_root(node: &SyntaxNode) -> SyntaxNode {
+    node.ancestors().last().unwrap_or_else(|| node.clone())
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -283,6 +573,29 @@ fn invert_special_case(expr: &ast::Expr) -> Option<ast::Expr> {
     }
 }
 
+/// Whether evaluating `expr` can't have side effects: literals, paths and the places built from
+/// them, and the built-in operators applied to those.
+pub(crate) fn is_side_effect_free(expr: &ast::Expr) -> bool {
+    let is_free = |it: Option<ast::Expr>| it.map_or(false, |it| is_side_effect_free(&it));
+    match expr {
+        ast::Expr::Literal(_) | ast::Expr::PathExpr(_) => true,
+        ast::Expr::ParenExpr(it) => is_free(it.expr()),
+        ast::Expr::FieldExpr(it) => is_free(it.expr()),
+        ast::Expr::IndexExpr(it) => is_free(it.base()) && is_free(it.index()),
+        ast::Expr::PrefixExpr(it) => is_free(it.expr()),
+        ast::Expr::CastExpr(it) => is_free(it.expr()),
+        ast::Expr::RefExpr(it) => it.mut_token().is_none() && is_free(it.expr()),
+        ast::Expr::BinExpr(it) => {
+            !matches!(it.op_kind(), Some(ast::BinaryOp::Assignment { .. }))
+                && is_free(it.lhs())
+                && is_free(it.rhs())
+        }
+        ast::Expr::TupleExpr(it) => it.fields().all(|it| is_side_effect_free(&it)),
+        ast::Expr::ArrayExpr(it) => it.exprs().all(|it| is_side_effect_free(&it)),
+        _ => false,
+    }
+}
+
 pub(crate) fn next_prev() -> impl Iterator<Item = Direction> {
     [Direction::Next, Direction::Prev].into_iter()
 }
@@ -460,6 +773,99 @@ pub(crate) fn generate_trait_impl_text_intransitive(
     generate_impl_text_inner(adt, Some(trait_text), false, code)
 }
 
+/// Builds an `impl <trait_path> for <adt>` with all of the trait's required items stubbed out,
+/// generating a real body for the first method of well-known derivable traits.
+///
+/// Also returns the first item so that callers can place the cursor on it.
+pub(crate) fn impl_def_from_trait(
+    ctx: &AssistContext<'_>,
+    adt: &ast::Adt,
+    annotated_name: &ast::Name,
+    trait_: Option<hir::Trait>,
+    trait_path: &ast::Path,
+) -> Option<(ast::Impl, ast::AssocItem)> {
+    impl_def_from_trait_inner(ctx, adt, annotated_name, trait_, trait_path, true)
+}
+
+/// Like [`impl_def_from_trait`], but without bounding the generic parameters by the trait, for
+/// impls that don't use the trait on them.
+pub(crate) fn impl_def_from_trait_intransitive(
+    ctx: &AssistContext<'_>,
+    adt: &ast::Adt,
+    annotated_name: &ast::Name,
+    trait_: Option<hir::Trait>,
+    trait_path: &ast::Path,
+) -> Option<(ast::Impl, ast::AssocItem)> {
+    impl_def_from_trait_inner(ctx, adt, annotated_name, trait_, trait_path, false)
+}
+
+fn impl_def_from_trait_inner(
+    ctx: &AssistContext<'_>,
+    adt: &ast::Adt,
+    annotated_name: &ast::Name,
+    trait_: Option<hir::Trait>,
+    trait_path: &ast::Path,
+    trait_is_transitive: bool,
+) -> Option<(ast::Impl, ast::AssocItem)> {
+    let sema = &ctx.sema;
+    let trait_ = trait_?;
+    let target_scope = sema.scope(annotated_name.syntax())?;
+    let trait_items = filter_assoc_items(sema, &trait_.items(sema.db), DefaultMethods::No);
+    if trait_items.is_empty() {
+        return None;
+    }
+    let impl_def = {
+        use syntax::ast::Impl;
+        let text = generate_impl_text_inner(
+            adt,
+            Some(trait_path.to_string().as_str()),
+            trait_is_transitive,
+            "",
+        );
+        let parse = syntax::SourceFile::parse(&text);
+        let node = match parse.tree().syntax().descendants().find_map(Impl::cast) {
+            Some(it) => it,
+            None => {
+                panic!(
+                    "Failed to make ast node `{}` from text {}",
+                    std::any::type_name::<Impl>(),
+                    text
+                )
+            }
+        };
+        let node = node.clone_subtree();
+        assert_eq!(node.syntax().text_range().start(), 0.into());
+        node
+    };
+
+    let trait_items = trait_items
+        .into_iter()
+        .map(|it| {
+            if sema.hir_file_for(it.syntax()).is_macro() {
+                if let Some(it) = ast::AssocItem::cast(insert_ws_into(it.syntax().clone())) {
+                    return it;
+                }
+            }
+            it.clone_for_update()
+        })
+        .collect();
+    let (impl_def, first_assoc_item) = add_trait_assoc_items_to_impl(
+        sema,
+        ctx.config,
+        trait_items,
+        trait_,
+        impl_def,
+        target_scope,
+    );
+
+    // Generate a default `impl` function body for the derived trait.
+    if let ast::AssocItem::Fn(ref func) = first_assoc_item {
+        let _ = gen_trait_fn_body(func, trait_path, adt);
+    };
+
+    Some((impl_def, first_assoc_item))
+}
+
 fn generate_impl_text_inner(
     adt: &ast::Adt,
     trait_text: Option<&str>,
@@ -706,6 +1112,60 @@ fn handle_result_as_ref(
     }
 }
 
+/// Returns the offset to insert a new attribute for `item` at: below its existing attributes, or
+/// after its doc comments if there are none.
+pub(crate) fn attr_insertion_offset(item: &impl HasAttrs) -> Option<TextSize> {
+    let non_ws_child = match item.attrs().last() {
+        Some(attr) => attr
+            .syntax()
+            .siblings_with_tokens(Direction::Next)
+            .skip(1)
+            .find(|it| it.kind() != WHITESPACE)?,
+        None => item
+            .syntax()
+            .children_with_tokens()
+            .find(|it| it.kind() != COMMENT && it.kind() != WHITESPACE)?,
+    };
+    Some(non_ws_child.text_range().start())
+}
+
+/// Puts copies of the attributes of `from` in front of `to`, a mutable node that replaces it.
+pub(crate) fn copy_attrs(from: &impl HasAttrs, to: &SyntaxNode) {
+    let attrs: Vec<SyntaxElement> = from
+        .attrs()
+        .flat_map(|attr| {
+            [attr.syntax().clone_for_update().into(), make::tokens::single_space().into()]
+        })
+        .collect();
+    ted::insert_all_raw(ted::Position::first_child_of(to), attrs);
+}
+
+/// Returns the associated function whose name is under the cursor, together with the `impl` it
+/// is defined in.
+pub(crate) fn impl_fn_at_cursor(ctx: &AssistContext<'_>) -> Option<(ast::Impl, ast::Fn)> {
+    let name = ctx.find_node_at_offset::<ast::Name>()?;
+    let fn_ = ast::Fn::cast(name.syntax().parent()?)?;
+    let impl_ = fn_.syntax().parent().and_then(ast::AssocItemList::cast)?.syntax().parent()?;
+    Some((ast::Impl::cast(impl_)?, fn_))
+}
+
+/// Returns the function of `trait_` called `name`, if there is one.
+pub(crate) fn trait_fn_named(
+    db: &dyn HirDatabase,
+    trait_: hir::Trait,
+    name: &str,
+) -> Option<hir::Function> {
+    trait_.items(db).into_iter().find_map(|item| match item {
+        hir::AssocItem::Function(it) if it.name(db).to_string() == name => Some(it),
+        _ => None,
+    })
+}
+
+/// The kind of `self` parameter and the number of other parameters a function takes.
+pub(crate) fn signature_shape(params: &ast::ParamList) -> (Option<ast::SelfParamKind>, usize) {
+    (params.self_param().map(|it| it.kind()), params.params().count())
+}
+
 pub(crate) fn get_methods(items: &ast::AssocItemList) -> Vec<ast::Fn> {
     items
         .assoc_items()
@@ -758,3 +1218,20 @@ pub(crate) fn convert_param_list_to_arg_list(list: ast::ParamList) -> ast::ArgLi
     }
     make::arg_list(args)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reindent_leaves_blank_lines_empty() {
+        let file = SourceFile::parse("fn f() {\n\tlet x = 1;\n\t\n\n\tx\n}\n").tree();
+        let node = file.syntax().clone_for_update();
+
+        IndentStyle::Tabs.normalize(&node);
+        assert_eq!(node.to_string(), "fn f() {\n    let x = 1;\n\n\n    x\n}\n");
+
+        IndentStyle::Tabs.denormalize(&node);
+        assert_eq!(node.to_string(), "fn f() {\n\tlet x = 1;\n\n\n\tx\n}\n");
+    }
+}
@@ -19,6 +19,7 @@ pub(crate) fn gen_trait_fn_body(
         "Clone" => gen_clone_impl(adt, func),
         "Debug" => gen_debug_impl(adt, func),
         "Default" => gen_default_impl(adt, func),
+        "Display" => gen_display_impl(adt, func),
         "Hash" => gen_hash_impl(adt, func),
         "PartialEq" => gen_partial_eq(adt, func),
         "PartialOrd" => gen_partial_ord(adt, func),
@@ -292,6 +293,47 @@ fn gen_debug_impl(adt: &ast::Adt, func: &ast::Fn) -> Option<()> {
     }
 }
 
+/// Generate a `Display` impl writing the name of each variant, as a placeholder to be filled in.
+fn gen_display_impl(adt: &ast::Adt, func: &ast::Fn) -> Option<()> {
+    // There's no canonical way of displaying a struct.
+    let enum_ = match adt {
+        ast::Adt::Enum(it) => it,
+        _ => return None,
+    };
+
+    // => match self { Self::Variant(..) => write!(f, "Variant") }
+    let mut arms = vec![];
+    for variant in enum_.variant_list()?.variants() {
+        let name = variant.name()?;
+        let variant_name = make::ext::path_from_idents(["Self", &format!("{name}")])?;
+        let pat = match variant.field_list() {
+            Some(ast::FieldList::RecordFieldList(_)) => {
+                make::record_pat(variant_name, Some(make::rest_pat().into())).into()
+            }
+            Some(ast::FieldList::TupleFieldList(_)) => {
+                make::tuple_struct_pat(variant_name, Some(make::rest_pat().into())).into()
+            }
+            None => make::path_pat(variant_name),
+        };
+
+        let target = make::expr_path(make::ext::ident_path("f"));
+        let fmt_string = make::expr_literal(&(format!("\"{name}\""))).into();
+        let args = make::arg_list([target, fmt_string]);
+        let macro_name = make::expr_path(make::ext::ident_path("write"));
+        let macro_call = make::expr_macro_call(macro_name, args);
+        arms.push(make::match_arm(Some(pat), None, macro_call));
+    }
+
+    let match_target = make::expr_path(make::ext::ident_path("self"));
+    let list = make::match_arm_list(arms).indent(ast::edit::IndentLevel(1));
+    let match_expr = make::expr_match(match_target, list);
+
+    let body = make::block_expr(None, Some(match_expr));
+    let body = body.indent(ast::edit::IndentLevel(1));
+    ted::replace(func.body()?.syntax(), body.clone_for_update().syntax());
+    Some(())
+}
+
 /// Generate a `Debug` impl based on the fields and members of the target type.
 fn gen_default_impl(adt: &ast::Adt, func: &ast::Fn) -> Option<()> {
     fn gen_default_call() -> Option<ast::Expr> {
@@ -78,6 +78,7 @@ pub(crate) fn for_generic_parameter(ty: &ast::ImplTraitType) -> SmolStr {
 ///
 /// * if expr is an argument to function/method, use parameter name
 /// * if expr is a function/method call, use function name
+/// * if expr is a field access, use field name
 /// * expression type name if it exists (E.g. `()`, `fn() -> ()` or `!` do not have names)
 /// * fallback: `var_name`
 ///
@@ -96,7 +97,7 @@ pub(crate) fn for_variable(expr: &ast::Expr, sema: &Semantics<'_, RootDatabase>)
     let mut next_expr = Some(expr.clone());
     while let Some(expr) = next_expr {
         let name =
-            from_call(&expr).or_else(|| from_type(&expr, sema)).or_else(|| from_field_name(&expr));
+            from_call(&expr).or_else(|| from_field_name(&expr)).or_else(|| from_type(&expr, sema));
         if let Some(name) = name {
             return name;
         }
@@ -773,4 +774,18 @@ fn foo<T>(some_struct: S<T>) { $0some_struct.some_field$0 }
             "some_field",
         );
     }
+
+    #[test]
+    fn field_name_is_preferred_over_type() {
+        check(
+            r#"
+struct Config;
+struct S {
+    settings: Config,
+}
+fn foo(s: S) { $0s.settings$0 }
+"#,
+            "settings",
+        );
+    }
 }
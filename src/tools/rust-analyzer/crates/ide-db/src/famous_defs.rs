@@ -1,7 +1,7 @@
 //! See [`FamousDefs`].
 
 use base_db::{CrateOrigin, LangCrateOrigin, SourceDatabase};
-use hir::{Crate, Enum, Macro, Module, ScopeDef, Semantics, Trait};
+use hir::{Crate, Enum, Macro, Module, ScopeDef, Semantics, Struct, Trait};
 
 use crate::RootDatabase;
 
@@ -42,10 +42,22 @@ pub fn proc_macro(&self) -> Option<Crate> {
         self.find_lang_crate(LangCrateOrigin::ProcMacro)
     }
 
+    pub fn core_clone_Clone(&self) -> Option<Trait> {
+        self.find_trait("core:clone:Clone")
+    }
+
     pub fn core_cmp_Ord(&self) -> Option<Trait> {
         self.find_trait("core:cmp:Ord")
     }
 
+    pub fn core_cmp_PartialEq(&self) -> Option<Trait> {
+        self.find_trait("core:cmp:PartialEq")
+    }
+
+    pub fn core_cmp_Eq(&self) -> Option<Trait> {
+        self.find_trait("core:cmp:Eq")
+    }
+
     pub fn core_convert_From(&self) -> Option<Trait> {
         self.find_trait("core:convert:From")
     }
@@ -66,6 +78,27 @@ pub fn core_default_Default(&self) -> Option<Trait> {
         self.find_trait("core:default:Default")
     }
 
+    pub fn core_error_Error(&self) -> Option<Trait> {
+        // `core::error` is unstable, so the trait is usually reached through `std`.
+        self.find_trait("core:error:Error").or_else(|| self.find_trait("std:error:Error"))
+    }
+
+    pub fn core_fmt_Debug(&self) -> Option<Trait> {
+        self.find_trait("core:fmt:Debug")
+    }
+
+    pub fn core_fmt_Display(&self) -> Option<Trait> {
+        self.find_trait("core:fmt:Display")
+    }
+
+    pub fn core_hash_Hash(&self) -> Option<Trait> {
+        self.find_trait("core:hash:Hash")
+    }
+
+    pub fn core_future_Future(&self) -> Option<Trait> {
+        self.find_trait("core:future:Future")
+    }
+
     pub fn core_iter_Iterator(&self) -> Option<Trait> {
         self.find_trait("core:iter:traits:iterator:Iterator")
     }
@@ -90,6 +123,27 @@ pub fn core_convert_AsRef(&self) -> Option<Trait> {
         self.find_trait("core:convert:AsRef")
     }
 
+    pub fn core_convert_AsMut(&self) -> Option<Trait> {
+        self.find_trait("core:convert:AsMut")
+    }
+
+    pub fn alloc_string_String(&self) -> Option<Struct> {
+        // Crates that don't depend on `alloc` directly see it through `std`, and test fixtures
+        // only have `minicore`.
+        self.find_struct("alloc:string:String")
+            .or_else(|| self.find_struct("std:string:String"))
+            .or_else(|| self.find_struct("core:string:String"))
+    }
+
+    pub fn alloc_boxed_Box(&self) -> Option<Struct> {
+        // Test fixtures only have `minicore`.
+        self.find_struct("alloc:boxed:Box").or_else(|| self.find_struct("core:boxed:Box"))
+    }
+
+    pub fn alloc_rc_Rc(&self) -> Option<Struct> {
+        self.find_struct("alloc:rc:Rc").or_else(|| self.find_struct("core:rc:Rc"))
+    }
+
     pub fn core_ops_ControlFlow(&self) -> Option<Enum> {
         self.find_enum("core:ops:ControlFlow")
     }
@@ -138,6 +192,13 @@ fn find_enum(&self, path: &str) -> Option<Enum> {
         }
     }
 
+    fn find_struct(&self, path: &str) -> Option<Struct> {
+        match self.find_def(path)? {
+            hir::ScopeDef::ModuleDef(hir::ModuleDef::Adt(hir::Adt::Struct(it))) => Some(it),
+            _ => None,
+        }
+    }
+
     fn find_module(&self, path: &str) -> Option<Module> {
         match self.find_def(path)? {
             hir::ScopeDef::ModuleDef(hir::ModuleDef::Module(it)) => Some(it),
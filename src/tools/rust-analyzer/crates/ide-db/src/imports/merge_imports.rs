@@ -78,6 +78,11 @@ fn try_merge_trees_mut(lhs: &ast::UseTree, rhs: &ast::UseTree, merge: MergeBehav
     {
         lhs.split_prefix(&lhs_prefix);
         rhs.split_prefix(&rhs_prefix);
+    } else if !eq_rename(lhs.rename(), rhs.rename()) {
+        // The same item imported under different names, split off the last segment so that both
+        // names end up in the merged tree.
+        lhs.split_prefix(&lhs_prefix.qualifier()?);
+        rhs.split_prefix(&rhs_prefix.qualifier()?);
     }
     recursive_merge(lhs, rhs, merge)
 }
@@ -134,6 +139,21 @@ fn recursive_merge(lhs: &ast::UseTree, rhs: &ast::UseTree, merge: MergeBehavior)
                     }
 
                     if lhs_t.is_simple_path() && rhs_t.is_simple_path() {
+                        if !eq_rename(lhs_t.rename(), rhs_t.rename()) {
+                            // Both names are kept, next to each other in the list of the last
+                            // module of the path.
+                            match lhs_prefix.qualifier().zip(rhs_prefix.qualifier()) {
+                                Some((lhs_qualifier, rhs_qualifier)) => {
+                                    lhs_t.split_prefix(&lhs_qualifier);
+                                    rhs_t.split_prefix(&rhs_qualifier);
+                                    recursive_merge(lhs_t, &rhs_t, merge)?;
+                                }
+                                None => {
+                                    use_trees.insert(idx + 1, rhs_t.clone());
+                                    lhs.get_or_create_use_tree_list().add_use_tree(rhs_t);
+                                }
+                            }
+                        }
                         continue;
                     }
                 }
@@ -272,6 +292,14 @@ pub fn eq_visibility(vis0: Option<ast::Visibility>, vis1: Option<ast::Visibility
     }
 }
 
+fn eq_rename(rename0: Option<ast::Rename>, rename1: Option<ast::Rename>) -> bool {
+    match (rename0, rename1) {
+        (None, None) => true,
+        (Some(rename0), Some(rename1)) => rename0.syntax().text() == rename1.syntax().text(),
+        _ => false,
+    }
+}
+
 pub fn eq_attrs(
     attrs0: impl Iterator<Item = ast::Attr>,
     attrs1: impl Iterator<Item = ast::Attr>,
@@ -124,7 +124,7 @@ fn krate(db: &RootDatabase, of: hir::Crate) -> SearchScope {
     }
 
     /// Build a search scope spanning the given module and all its submodules.
-    fn module_and_children(db: &RootDatabase, module: hir::Module) -> SearchScope {
+    pub fn module_and_children(db: &RootDatabase, module: hir::Module) -> SearchScope {
         let mut entries = NoHashHashMap::default();
 
         let (file_id, range) = {
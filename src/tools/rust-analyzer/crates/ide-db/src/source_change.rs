@@ -126,6 +126,9 @@ pub fn new(file_id: FileId) -> SourceChangeBuilder {
         }
     }
 
+    /// Directs all following edits to `file_id`, committing the ones made so far to the file
+    /// that was edited before. Switching back to a file that already has edits merges the new
+    /// ones into them, so an assist can freely alternate between the files it changes.
     pub fn edit_file(&mut self, file_id: FileId) {
         self.commit();
         self.file_id = file_id;
@@ -181,6 +184,41 @@ pub fn insert_snippet(
     pub fn replace(&mut self, range: TextRange, replace_with: impl Into<String>) {
         self.edit.replace(range, replace_with.into())
     }
+    /// Remove specified `range` of text in `file_id`, leaving the file the other edits go to as it
+    /// is.
+    pub fn delete_in_file(&mut self, file_id: FileId, range: TextRange) {
+        self.in_file(file_id, |builder| builder.delete(range))
+    }
+    /// Append specified `text` at the given `offset` in `file_id`, leaving the file the other
+    /// edits go to as it is.
+    pub fn insert_in_file(&mut self, file_id: FileId, offset: TextSize, text: impl Into<String>) {
+        self.in_file(file_id, |builder| builder.insert(offset, text))
+    }
+    /// Replaces specified `range` of text in `file_id` with a given string, leaving the file the
+    /// other edits go to as it is.
+    pub fn replace_in_file(
+        &mut self,
+        file_id: FileId,
+        range: TextRange,
+        replace_with: impl Into<String>,
+    ) {
+        self.in_file(file_id, |builder| builder.replace(range, replace_with))
+    }
+    fn in_file(&mut self, file_id: FileId, f: impl FnOnce(&mut Self)) {
+        if file_id == self.file_id {
+            return f(self);
+        }
+        // The edits of the current file are set aside rather than committed, so that trees
+        // handed out by `make_mut` can still be mutated afterwards.
+        let edit = mem::take(&mut self.edit);
+        let mutated_tree = self.mutated_tree.take();
+        let file_id = mem::replace(&mut self.file_id, file_id);
+        f(self);
+        self.commit();
+        self.file_id = file_id;
+        self.edit = edit;
+        self.mutated_tree = mutated_tree;
+    }
     /// Replaces specified `range` of text with a given `snippet`.
     pub fn replace_snippet(
         &mut self,
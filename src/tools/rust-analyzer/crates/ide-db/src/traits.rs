@@ -6,10 +6,19 @@
 use syntax::{ast, AstNode};
 
 /// Given the `impl` block, attempts to find the trait this `impl` corresponds to.
+///
+/// Traits can only be aliased by renaming imports (`use Foo as Alias;`), which path resolution
+/// already sees through; type aliases can't name traits, not even through trait objects.
 pub fn resolve_target_trait(
     sema: &Semantics<'_, RootDatabase>,
     impl_def: &ast::Impl,
 ) -> Option<hir::Trait> {
+    // `impl_trait` is a salsa query keyed by the impl's `ImplId`, so repeated lookups for the
+    // same impl are memoized, unlike resolving the path, which builds a resolver every time.
+    if let Some(trait_) = sema.to_def(impl_def).and_then(|imp| imp.trait_(sema.db)) {
+        return Some(trait_);
+    }
+
     let ast_path =
         impl_def.trait_().map(|it| it.syntax().clone()).and_then(ast::PathType::cast)?.path()?;
 
@@ -21,6 +30,9 @@ pub fn resolve_target_trait(
 
 /// Given the `impl` block, returns the list of associated items (e.g. functions or types) that are
 /// missing in this `impl` block.
+///
+/// Items of supertraits are not included, as they can only be defined in an `impl` of the
+/// supertrait itself.
 pub fn get_missing_assoc_items(
     sema: &Semantics<'_, RootDatabase>,
     impl_def: &ast::Impl,
@@ -201,6 +213,41 @@ impl Bar {
         );
     }
 
+    #[test]
+    fn resolve_trait_not_through_alias() {
+        // Type aliases can't name traits, not even through trait objects (E0404).
+        check_trait(
+            r#"
+pub trait Foo {
+    fn bar();
+}
+type Alias = dyn Foo;
+impl Alias for u8 {
+    $0
+}
+            "#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn resolve_trait_through_renamed_import() {
+        check_trait(
+            r#"
+mod m {
+    pub trait Foo {
+        fn bar();
+    }
+}
+use m::Foo as Alias;
+impl Alias for u8 {
+    $0
+}
+            "#,
+            expect![["Foo"]],
+        );
+    }
+
     #[test]
     fn missing_assoc_items() {
         check_missing_assoc(
@@ -270,4 +317,28 @@ impl Tr for () {
             expect![[r#""#]],
         );
     }
+
+    #[test]
+    fn missing_assoc_items_ignore_supertraits() {
+        check_missing_assoc(
+            r#"
+trait Base {
+    fn base();
+}
+trait Left: Base {
+    fn left();
+}
+trait Right: Base {
+    fn right();
+}
+trait Foo: Left + Right {
+    fn foo();
+}
+impl Foo for () {
+    $0
+}"#,
+            expect![[r#"
+                foo"#]],
+        );
+    }
 }
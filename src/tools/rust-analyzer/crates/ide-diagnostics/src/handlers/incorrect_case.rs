@@ -1,9 +1,14 @@
 use hir::{db::AstDatabase, InFile};
-use ide_db::{assists::Assist, defs::NameClass};
-use syntax::AstNode;
+use ide_db::{assists::Assist, defs::NameClass, source_change::SourceChange};
+use syntax::{
+    ast::{self, edit::IndentLevel},
+    AstNode, NodeOrToken, SyntaxNode, TextRange,
+};
+use text_edit::TextEdit;
 
 use crate::{
     // references::rename::rename_with_semantics,
+    fix,
     unresolved_fix,
     Diagnostic,
     DiagnosticsContext,
@@ -41,12 +46,44 @@ fn fixes(ctx: &DiagnosticsContext<'_>, d: &hir::IncorrectCase) -> Option<Vec<Ass
         res.source_change = Some(source_change.ok().unwrap_or_default());
     }
 
-    Some(vec![res])
+    let mut fixes = vec![res];
+    fixes.extend(allow_lint_fix(ctx, d, name_node.value, frange.range));
+    Some(fixes)
+}
+
+/// Allows the lint through an attribute on the closest item, below its doc comments and above
+/// any other attributes.
+fn allow_lint_fix(
+    ctx: &DiagnosticsContext<'_>,
+    d: &hir::IncorrectCase,
+    name: &SyntaxNode,
+    target: TextRange,
+) -> Option<Assist> {
+    if d.file.is_macro() {
+        return None;
+    }
+    let item = name.ancestors().find_map(ast::Item::cast)?;
+    let offset = item
+        .syntax()
+        .children_with_tokens()
+        .find(|it| match it {
+            NodeOrToken::Token(it) => !it.kind().is_trivia(),
+            NodeOrToken::Node(it) => ast::Attr::cast(it.clone())
+                .map_or(true, |attr| attr.simple_name().map_or(true, |it| it != "doc")),
+        })?
+        .text_range()
+        .start();
+
+    let lint = d.expected_case.lint_name();
+    let indent = IndentLevel::from_node(item.syntax());
+    let edit = TextEdit::insert(offset, format!("#[allow({lint})]\n{indent}"));
+    let source_change = SourceChange::from_text_edit(d.file.original_file(ctx.sema.db), edit);
+    Some(fix("allow_lint", &format!("Allow `{lint}`"), source_change, target))
 }
 
 #[cfg(test)]
 mod change_case {
-    use crate::tests::{check_diagnostics, check_fix};
+    use crate::tests::{check_diagnostics, check_fix, check_fixes};
 
     #[test]
     fn test_rename_incorrect_case() {
@@ -109,6 +146,76 @@ fn some_fn() {
         );
     }
 
+    #[test]
+    fn test_allow_incorrect_case() {
+        check_fixes(
+            r#"
+/// Docs.
+#[inline]
+pub fn some_fn() {
+    let whatAWeird_Formatting$0 = 10;
+}
+"#,
+            vec![
+                r#"
+/// Docs.
+#[inline]
+pub fn some_fn() {
+    let what_aweird_formatting = 10;
+}
+"#,
+                r#"
+/// Docs.
+#[allow(non_snake_case)]
+#[inline]
+pub fn some_fn() {
+    let whatAWeird_Formatting = 10;
+}
+"#,
+            ],
+        );
+        check_fixes(
+            r#"
+mod m {
+    pub struct some_struct$0;
+}
+"#,
+            vec![
+                r#"
+mod m {
+    pub struct SomeStruct;
+}
+"#,
+                r#"
+mod m {
+    #[allow(non_camel_case_types)]
+    pub struct some_struct;
+}
+"#,
+            ],
+        );
+        check_fixes(
+            r#"
+#[doc(hidden)]
+#[derive(Clone)]
+pub struct some_struct$0;
+"#,
+            vec![
+                r#"
+#[doc(hidden)]
+#[derive(Clone)]
+pub struct SomeStruct;
+"#,
+                r#"
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+#[derive(Clone)]
+pub struct some_struct;
+"#,
+            ],
+        );
+    }
+
     #[test]
     fn test_uppercase_const_no_diagnostics() {
         check_diagnostics(
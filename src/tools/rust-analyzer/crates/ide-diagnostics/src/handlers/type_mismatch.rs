@@ -1,8 +1,8 @@
-use hir::{db::AstDatabase, HirDisplay, Type};
+use hir::{db::AstDatabase, known, HirDisplay, Type};
 use ide_db::{famous_defs::FamousDefs, source_change::SourceChange};
 use syntax::{
     ast::{self, BlockExpr, ExprStmt},
-    AstNode,
+    match_ast, AstNode,
 };
 use text_edit::TextEdit;
 
@@ -46,6 +46,7 @@ fn fixes(ctx: &DiagnosticsContext<'_>, d: &hir::TypeMismatch) -> Option<Vec<Assi
     add_missing_ok_or_some(ctx, d, &mut fixes);
     remove_semicolon(ctx, d, &mut fixes);
     str_ref_to_owned(ctx, d, &mut fixes);
+    add_await(ctx, d, &mut fixes);
 
     if fixes.is_empty() {
         None
@@ -168,6 +169,87 @@ fn str_ref_to_owned(
     Some(())
 }
 
+fn add_await(
+    ctx: &DiagnosticsContext<'_>,
+    d: &hir::TypeMismatch,
+    acc: &mut Vec<Assist>,
+) -> Option<()> {
+    let root = ctx.sema.db.parse_or_expand(d.expr.file_id)?;
+    let expr = d.expr.value.to_node(&root);
+    if d.expr.file_id.is_macro() || !is_in_async_context(&expr) {
+        return None;
+    }
+
+    let scope = ctx.sema.scope(expr.syntax())?;
+    let future_trait = FamousDefs(&ctx.sema, scope.krate()).core_future_Future()?;
+    if !d.actual.impls_trait(ctx.sema.db, future_trait, &[]) {
+        return None;
+    }
+    let output = future_trait.items(ctx.sema.db).into_iter().find_map(|item| match item {
+        hir::AssocItem::TypeAlias(alias) if alias.name(ctx.sema.db) == known::Output => Some(alias),
+        _ => None,
+    })?;
+    let output_ty = d.actual.normalize_trait_assoc_type(ctx.sema.db, &[], output)?;
+    if !output_ty.could_coerce_to(ctx.sema.db, &d.expected) {
+        return None;
+    }
+
+    // `.await` binds tighter than any operator.
+    let needs_parens = !matches!(
+        expr,
+        ast::Expr::PathExpr(_)
+            | ast::Expr::CallExpr(_)
+            | ast::Expr::MethodCallExpr(_)
+            | ast::Expr::FieldExpr(_)
+            | ast::Expr::IndexExpr(_)
+            | ast::Expr::AwaitExpr(_)
+            | ast::Expr::TryExpr(_)
+            | ast::Expr::ParenExpr(_)
+            | ast::Expr::MacroExpr(_)
+            | ast::Expr::Literal(_)
+            | ast::Expr::TupleExpr(_)
+            | ast::Expr::ArrayExpr(_)
+            | ast::Expr::RecordExpr(_)
+            | ast::Expr::BlockExpr(_)
+    );
+    let expr_range = expr.syntax().text_range();
+    let mut builder = TextEdit::builder();
+    if needs_parens {
+        builder.insert(expr_range.start(), "(".to_owned());
+        builder.insert(expr_range.end(), ").await".to_owned());
+    } else {
+        builder.insert(expr_range.end(), ".await".to_owned());
+    }
+    let source_change =
+        SourceChange::from_text_edit(d.expr.file_id.original_file(ctx.sema.db), builder.finish());
+    acc.push(fix("add_await", "Add `.await`", source_change, expr_range));
+    Some(())
+}
+
+/// Checks whether `.await` can be used at `expr`, that is, inside of an async function, closure
+/// or block.
+fn is_in_async_context(expr: &ast::Expr) -> bool {
+    for node in expr.syntax().ancestors() {
+        match_ast! {
+            match node {
+                ast::Fn(it) => return it.async_token().is_some(),
+                ast::ClosureExpr(it) => return it.async_token().is_some(),
+                ast::BlockExpr(it) => {
+                    if it.async_token().is_some() {
+                        return true;
+                    }
+                },
+                _ => {
+                    if ast::Item::can_cast(node.kind()) {
+                        return false;
+                    }
+                },
+            }
+        }
+    }
+    false
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tests::{check_diagnostics, check_fix, check_no_fix};
@@ -592,6 +674,79 @@ fn f() -> i32 {
     let _ = x + y;
   }
 //^ error: expected i32, found ()
+"#,
+        );
+    }
+
+    #[test]
+    fn add_await() {
+        check_fix(
+            r#"
+//- minicore: future
+async fn foo() -> u32 { 0 }
+async fn bar() {
+    let x: u32 = foo()$0;
+}
+"#,
+            r#"
+async fn foo() -> u32 { 0 }
+async fn bar() {
+    let x: u32 = foo().await;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn add_await_with_parens() {
+        check_fix(
+            r#"
+//- minicore: future, copy, derive
+// `Fut` is `Copy`, so the deref gives an owned future to await, not a move out of `fut`.
+#[derive(Clone, Copy)]
+struct Fut;
+impl core::future::Future for Fut {
+    type Output = u32;
+    fn poll(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<u32> {
+        loop {}
+    }
+}
+async fn bar(fut: &Fut) -> u32 {
+    *fut$0
+}
+"#,
+            r#"
+// `Fut` is `Copy`, so the deref gives an owned future to await, not a move out of `fut`.
+#[derive(Clone, Copy)]
+struct Fut;
+impl core::future::Future for Fut {
+    type Output = u32;
+    fn poll(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<u32> {
+        loop {}
+    }
+}
+async fn bar(fut: &Fut) -> u32 {
+    (*fut).await
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_await_outside_async() {
+        check_no_fix(
+            r#"
+//- minicore: future
+async fn foo() -> u32 { 0 }
+fn bar() {
+    let x: u32 = foo()$0;
+}
 "#,
         );
     }
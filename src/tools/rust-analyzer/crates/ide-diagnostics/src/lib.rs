@@ -135,6 +135,7 @@ pub enum Severity {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ExprFillDefaultMode {
     Todo,
+    Unimplemented,
     Default,
 }
 impl Default for ExprFillDefaultMode {
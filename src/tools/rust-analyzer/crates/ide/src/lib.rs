@@ -102,7 +102,8 @@ macro_rules! eprintln {
 };
 pub use hir::{Documentation, Semantics};
 pub use ide_assists::{
-    Assist, AssistConfig, AssistId, AssistKind, AssistResolveStrategy, SingleResolve,
+    Assist, AssistConfig, AssistId, AssistKind, AssistResolveStrategy, PlaceholderMacro,
+    SingleResolve,
 };
 pub use ide_completion::{
     CallableSnippets, CompletionConfig, CompletionItem, CompletionItemKind, CompletionRelevance,
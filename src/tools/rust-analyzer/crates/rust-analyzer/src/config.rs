@@ -13,7 +13,7 @@
 use ide::{
     AssistConfig, CallableSnippets, CompletionConfig, DiagnosticsConfig, ExprFillDefaultMode,
     HighlightConfig, HighlightRelatedConfig, HoverConfig, HoverDocFormat, InlayHintsConfig,
-    JoinLinesConfig, Snippet, SnippetScope,
+    JoinLinesConfig, PlaceholderMacro, Snippet, SnippetScope,
 };
 use ide_db::{
     imports::insert_use::{ImportGranularity, InsertUseConfig, PrefixKind},
@@ -61,6 +61,9 @@ struct ConfigData {
         assist_emitMustUse: bool               = "false",
         /// Placeholder expression to use for missing expressions in assists.
         assist_expressionFillDefault: ExprFillDefaultDef              = "\"todo\"",
+        /// Maximum width of the lines of code generated by assists, like
+        /// rustfmt's `max_width`.
+        assist_maxWidth: usize = "100",
 
         /// Warm up caches on project load.
         cachePriming_enable: bool = "true",
@@ -1025,6 +1028,7 @@ pub fn diagnostics(&self) -> DiagnosticsConfig {
             disabled: self.data.diagnostics_disabled.clone(),
             expr_fill_default: match self.data.assist_expressionFillDefault {
                 ExprFillDefaultDef::Todo => ExprFillDefaultMode::Todo,
+                ExprFillDefaultDef::Unimplemented => ExprFillDefaultMode::Unimplemented,
                 ExprFillDefaultDef::Default => ExprFillDefaultMode::Default,
             },
             insert_use: self.insert_use_config(),
@@ -1347,6 +1351,11 @@ pub fn assist(&self) -> AssistConfig {
             insert_use: self.insert_use_config(),
             prefer_no_std: self.data.imports_prefer_no_std,
             assist_emit_must_use: self.data.assist_emitMustUse,
+            placeholder_macro: match self.data.assist_expressionFillDefault {
+                ExprFillDefaultDef::Unimplemented => PlaceholderMacro::Unimplemented,
+                ExprFillDefaultDef::Todo | ExprFillDefaultDef::Default => PlaceholderMacro::Todo,
+            },
+            max_width: self.data.assist_maxWidth,
         }
     }
 
@@ -1685,6 +1694,7 @@ enum ManifestOrProjectJson {
 #[serde(rename_all = "snake_case")]
 enum ExprFillDefaultDef {
     Todo,
+    Unimplemented,
     Default,
 }
 
@@ -1994,9 +2004,10 @@ macro_rules! set {
         },
         "ExprFillDefaultDef" => set! {
             "type": "string",
-            "enum": ["todo", "default"],
+            "enum": ["todo", "unimplemented", "default"],
             "enumDescriptions": [
                 "Fill missing expressions with the `todo` macro",
+                "Fill missing expressions with the `unimplemented` macro",
                 "Fill missing expressions with reasonable defaults, `new` or `default` constructors."
             ],
         },
@@ -61,14 +61,26 @@ pub fn from_node(node: &SyntaxNode) -> IndentLevel {
     }
 
     pub fn from_token(token: &SyntaxToken) -> IndentLevel {
-        for ws in prev_tokens(token.clone()).filter_map(ast::Whitespace::cast) {
-            let text = ws.syntax().text();
-            if let Some(pos) = text.rfind('\n') {
-                let level = text[pos + 1..].chars().count() / 4;
-                return IndentLevel(level as u8);
+        let mut first = token.clone();
+        for prev in prev_tokens(token.clone()) {
+            if let Some(ws) = ast::Whitespace::cast(prev.clone()) {
+                let text = ws.syntax().text();
+                if let Some(pos) = text.rfind('\n') {
+                    return IndentLevel::of_line(&text[pos + 1..]);
+                }
             }
+            first = prev;
         }
-        IndentLevel(0)
+        // `token` is on the first line of the file, which is only indented if the
+        // file starts with whitespace.
+        match ast::Whitespace::cast(first) {
+            Some(ws) if ws.syntax() != token => IndentLevel::of_line(ws.text()),
+            _ => IndentLevel(0),
+        }
+    }
+
+    fn of_line(indent: &str) -> IndentLevel {
+        IndentLevel((indent.chars().count() / 4) as u8)
     }
 
     /// XXX: this intentionally doesn't change the indent of the very first token.
@@ -172,3 +184,16 @@ fn test_increase_indent() {
         }"
     );
 }
+
+#[test]
+fn test_indent_level_on_first_line() {
+    let indent_of_fn = |text: &str| {
+        let file = crate::SourceFile::parse(text).tree();
+        let fn_ = file.syntax().descendants().find_map(ast::Fn::cast).unwrap();
+        IndentLevel::from_node(fn_.syntax())
+    };
+    assert_eq!(indent_of_fn("fn foo() {}").0, 0);
+    assert_eq!(indent_of_fn("        fn foo() {}").0, 2);
+    assert_eq!(indent_of_fn("// comment\n\n    fn foo() {}").0, 1);
+    assert_eq!(indent_of_fn("    /* comment */ fn foo() {}").0, 1);
+}
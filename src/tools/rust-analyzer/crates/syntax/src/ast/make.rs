@@ -59,6 +59,9 @@ pub fn expr_unreachable() -> ast::Expr {
     pub fn expr_todo() -> ast::Expr {
         expr_from_text("todo!()")
     }
+    pub fn expr_unimplemented() -> ast::Expr {
+        expr_from_text("unimplemented!()")
+    }
     pub fn expr_ty_default(ty: &ast::Type) -> ast::Expr {
         expr_from_text(&format!("{ty}::default()"))
     }
@@ -434,6 +437,9 @@ pub fn expr_if(
     };
     expr_from_text(&format!("if {condition} {then_branch} {else_branch}"))
 }
+pub fn expr_while_loop(condition: ast::Expr, block: ast::BlockExpr) -> ast::Expr {
+    expr_from_text(&format!("while {condition} {block}"))
+}
 pub fn expr_for_loop(pat: ast::Pat, expr: ast::Expr, block: ast::BlockExpr) -> ast::Expr {
     expr_from_text(&format!("for {pat} in {expr} {block}"))
 }
@@ -512,6 +518,10 @@ fn from_text(text: &str) -> ast::WildcardPat {
     }
 }
 
+pub fn rest_pat() -> ast::RestPat {
+    ast_from_text("fn f(S(..): ())")
+}
+
 pub fn literal_pat(lit: &str) -> ast::LiteralPat {
     return from_text(lit);
 
@@ -642,6 +652,14 @@ fn from_text(text: &str) -> ast::WherePred {
     }
 }
 
+pub fn lifetime_where_pred(
+    lifetime: ast::Lifetime,
+    bounds: impl IntoIterator<Item = ast::Lifetime>,
+) -> ast::WherePred {
+    let bounds = bounds.into_iter().join(" + ");
+    ast_from_text(&format!("fn f() where {lifetime}: {bounds} {{ }}"))
+}
+
 pub fn where_clause(preds: impl IntoIterator<Item = ast::WherePred>) -> ast::WhereClause {
     let preds = preds.into_iter().join(", ");
     return from_text(preds.as_str());
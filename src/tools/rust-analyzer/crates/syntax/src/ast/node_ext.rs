@@ -711,12 +711,12 @@ pub fn kind(&self) -> VisibilityKind {
 }
 
 impl ast::LifetimeParam {
-    pub fn lifetime_bounds(&self) -> impl Iterator<Item = SyntaxToken> {
+    pub fn lifetime_bounds(&self) -> impl Iterator<Item = ast::Lifetime> {
         self.syntax()
             .children_with_tokens()
-            .filter_map(|it| it.into_token())
             .skip_while(|x| x.kind() != T![:])
-            .filter(|it| it.kind() == T![lifetime_ident])
+            .filter_map(|it| it.into_node())
+            .filter_map(ast::Lifetime::cast)
     }
 }
 
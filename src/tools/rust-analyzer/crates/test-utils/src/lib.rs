@@ -144,6 +144,15 @@ pub fn extract_range_or_offset(text: &str) -> (RangeOrOffset, String) {
     (RangeOrOffset::Offset(offset), text)
 }
 
+#[test]
+fn test_extract_range_or_offset() {
+    let (selection, text) = extract_range_or_offset("fn $0main$0() {}");
+    assert_eq!(&text[selection.expect_range()], "main");
+    let (selection, text) = extract_range_or_offset("fn ma$0in() {}");
+    assert_eq!(text, "fn main() {}");
+    assert_eq!(selection.expect_offset(), TextSize::from(5));
+}
+
 /// Extracts ranges, marked with `<tag> </tag>` pairs from the `text`
 pub fn extract_tags(mut text: &str, tag: &str) -> (Vec<(TextRange, Option<String>)>, String) {
     let open = format!("<{tag}");
@@ -9,8 +9,10 @@
 //!
 //! Available flags:
 //!     add:
+//!     as_mut: sized
 //!     as_ref: sized
 //!     bool_impl: option, fn
+//!     box:
 //!     clone: sized
 //!     coerce_unsized: unsize
 //!     copy: clone
@@ -35,13 +37,16 @@
 //!     ord: eq, option
 //!     pin:
 //!     range:
+//!     rc:
 //!     result:
 //!     send: sized
 //!     sized:
 //!     slice:
+//!     string:
 //!     sync: sized
 //!     try:
 //!     unsize: sized
+//!     vec:
 
 pub mod marker {
     // region:sized
@@ -172,6 +177,12 @@ pub trait AsRef<T: ?Sized> {
         fn as_ref(&self) -> &T;
     }
     // endregion:as_ref
+
+    // region:as_mut
+    pub trait AsMut<T: ?Sized> {
+        fn as_mut(&mut self) -> &mut T;
+    }
+    // endregion:as_mut
 }
 
 pub mod ops {
@@ -511,6 +522,22 @@ pub enum Result<T, E> {
         #[lang = "Err"]
         Err(E),
     }
+
+    impl<T, E> Result<T, E> {
+        pub fn unwrap(self) -> T {
+            match self {
+                Ok(val) => val,
+                Err(_) => panic!("called `Result::unwrap()` on an `Err` value"),
+            }
+        }
+
+        pub fn expect(self, msg: &str) -> T {
+            match self {
+                Ok(val) => val,
+                Err(_) => panic!("{}", msg),
+            }
+        }
+    }
 }
 // endregion:result
 
@@ -614,8 +641,24 @@ fn next(&mut self) -> Option<B> {
                 loop {}
             }
         }
+
+        pub struct Map<I, F> {
+            iter: I,
+            f: F,
+        }
+        impl<B, I: Iterator, F> Iterator for Map<I, F>
+        where
+            F: FnMut(I::Item) -> B,
+        {
+            type Item = B;
+
+            #[inline]
+            fn next(&mut self) -> Option<B> {
+                loop {}
+            }
+        }
     }
-    pub use self::adapters::{FilterMap, Take};
+    pub use self::adapters::{FilterMap, Map, Take};
 
     mod sources {
         mod repeat {
@@ -668,6 +711,19 @@ fn filter_map<B, F>(self, f: F) -> crate::iter::FilterMap<Self, F>
                 {
                     loop {}
                 }
+                fn map<B, F>(self, f: F) -> crate::iter::Map<Self, F>
+                where
+                    Self: Sized,
+                    F: FnMut(Self::Item) -> B,
+                {
+                    loop {}
+                }
+                fn collect<B: crate::iter::FromIterator<Self::Item>>(self) -> B
+                where
+                    Self: Sized,
+                {
+                    loop {}
+                }
                 // endregion:iterators
             }
             impl<I: Iterator + ?Sized> Iterator for &mut I {
@@ -693,10 +749,21 @@ fn into_iter(self) -> I {
                     self
                 }
             }
+            // region:iterators
+            pub trait FromIterator<A> {
+                fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self;
+            }
+            // endregion:iterators
         }
         pub use self::collect::IntoIterator;
+        // region:iterators
+        pub use self::collect::FromIterator;
+        // endregion:iterators
     }
     pub use self::traits::{IntoIterator, Iterator};
+    // region:iterators
+    pub use self::traits::FromIterator;
+    // endregion:iterators
 }
 // endregion:iterator
 
@@ -744,12 +811,68 @@ fn source(&self) -> Option<&(dyn Error + 'static)> {
 }
 // endregion:error
 
+// region:box
+pub mod boxed {
+    pub struct Box<T: ?Sized>(*mut T);
+}
+// endregion:box
+
+// region:rc
+pub mod rc {
+    pub struct Rc<T: ?Sized>(*mut T);
+}
+// endregion:rc
+
+// region:vec
+pub mod vec {
+    pub struct Vec<T> {
+        buf: *mut T,
+        len: usize,
+    }
+
+    impl<T> Vec<T> {
+        pub fn new() -> Self {
+            loop {}
+        }
+        pub fn push(&mut self, value: T) {}
+        pub fn len(&self) -> usize {
+            loop {}
+        }
+    }
+
+    // region:iterators
+    impl<T> crate::iter::FromIterator<T> for Vec<T> {
+        fn from_iter<I: crate::iter::IntoIterator<Item = T>>(iter: I) -> Self {
+            loop {}
+        }
+    }
+    // endregion:iterators
+}
+// endregion:vec
+
+// region:string
+pub mod string {
+    pub struct String;
+
+    // region:add
+    impl crate::ops::Add<&str> for String {
+        type Output = String;
+        fn add(self, rhs: &str) -> String {
+            loop {}
+        }
+    }
+    // endregion:add
+}
+// endregion:string
+
 pub mod prelude {
     pub mod v1 {
         pub use crate::{
+            boxed::Box,                         // :box
             clone::Clone,                       // :clone
             cmp::{Eq, PartialEq},               // :eq
             cmp::{Ord, PartialOrd},             // :ord
+            convert::AsMut,                     // :as_mut
             convert::AsRef,                     // :as_ref
             convert::{From, Into},              // :from
             default::Default,                   // :default
@@ -764,6 +887,8 @@ pub mod v1 {
             ops::{Fn, FnMut, FnOnce},           // :fn
             option::Option::{self, None, Some}, // :option
             result::Result::{self, Err, Ok},    // :result
+            string::String,                     // :string
+            vec::Vec,                           // :vec
         };
     }
 